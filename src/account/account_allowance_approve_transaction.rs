@@ -31,6 +31,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -56,6 +57,8 @@ use crate::{
 pub type AccountAllowanceApproveTransaction = Transaction<AccountAllowanceApproveTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountAllowanceApproveTransactionData {
     /// List of hbar allowances approved by the account owner.
     hbar_allowances: Vec<HbarAllowance>,
@@ -168,6 +171,8 @@ impl AccountAllowanceApproveTransaction {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub struct HbarAllowance {
     /// The account ID of the hbar owner (ie. the grantor of the allowance).
@@ -181,6 +186,8 @@ pub struct HbarAllowance {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub struct TokenAllowance {
     /// The token that the allowance pertains to.
@@ -193,10 +200,13 @@ pub struct TokenAllowance {
     pub spender_account_id: AccountId,
 
     /// The amount of the spender's token allowance.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     pub amount: u64,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub struct NftAllowance {
     /// The token that the allowance pertains to.
@@ -220,7 +230,13 @@ pub struct NftAllowance {
     pub delegating_spender_account_id: Option<AccountId>,
 }
 
-impl TransactionData for AccountAllowanceApproveTransactionData {}
+impl TransactionData for AccountAllowanceApproveTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.allowances(
+            self.hbar_allowances.len() + self.token_allowances.len() + self.nft_allowances.len(),
+        );
+    }
+}
 
 impl TransactionExecute for AccountAllowanceApproveTransactionData {
     fn execute(
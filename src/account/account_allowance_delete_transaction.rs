@@ -34,6 +34,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -53,11 +54,15 @@ use crate::{
 pub type AccountAllowanceDeleteTransaction = Transaction<AccountAllowanceDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountAllowanceDeleteTransactionData {
     nft_allowances: Vec<NftRemoveAllowance>,
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct NftRemoveAllowance {
     /// token that the allowance pertains to
     pub token_id: TokenId,
@@ -100,7 +105,11 @@ impl AccountAllowanceDeleteTransaction {
     }
 }
 
-impl TransactionData for AccountAllowanceDeleteTransactionData {}
+impl TransactionData for AccountAllowanceDeleteTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.allowances(self.nft_allowances.len());
+    }
+}
 
 impl TransactionExecute for AccountAllowanceDeleteTransactionData {
     fn execute(
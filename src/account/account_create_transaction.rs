@@ -36,6 +36,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -56,6 +57,8 @@ pub type AccountCreateTransaction = Transaction<AccountCreateTransactionData>;
 // TODO: new_realm_admin_key: Option<Key>,
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountCreateTransactionData {
     /// The key that must sign each transfer out of the account.
     ///
@@ -272,7 +275,12 @@ impl AccountCreateTransaction {
     }
 }
 
-impl TransactionData for AccountCreateTransactionData {}
+impl TransactionData for AccountCreateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.memo("account memo", &self.account_memo);
+        violations.auto_renew_period(self.auto_renew_period);
+    }
+}
 
 impl TransactionExecute for AccountCreateTransactionData {
     fn execute(
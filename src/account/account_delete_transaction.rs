@@ -51,6 +51,8 @@ use crate::{
 pub type AccountDeleteTransaction = Transaction<AccountDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountDeleteTransactionData {
     /// The account ID which will receive all remaining hbars.
     transfer_account_id: Option<AccountId>,
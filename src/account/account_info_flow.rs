@@ -29,14 +29,16 @@ use crate::{
     Transaction,
 };
 
+async fn query_key(client: &Client, account_id: AccountId) -> crate::Result<Key> {
+    Ok(AccountInfoQuery::new().account_id(account_id).execute(client).await?.key)
+}
+
 async fn query_pk(client: &Client, account_id: AccountId) -> crate::Result<PublicKey> {
-    let key = AccountInfoQuery::new().account_id(account_id).execute(client).await?.key;
+    let key = query_key(client, account_id).await?;
 
     match key {
         Key::Single(it) => Ok(it),
-        _ => {
-            Err(Error::signature_verify("`{account_id}`: unsupported key kind: {key:?}".to_owned()))
-        }
+        _ => Err(Error::signature_verify(format!("`{account_id}`: unsupported key kind: {key:?}"))),
     }
 }
 
@@ -57,17 +59,28 @@ pub async fn verify_signature(
     key.verify(msg, signature)
 }
 
-/// Returns `Ok(())` if the given account's public key has signed the given transaction.
+/// Returns `Ok(())` if the given account's key has signed the given transaction.
+///
+/// Key lists and threshold keys are supported, see [`Key::verify_transaction`].
+///
 /// # Errors
-/// - [`Error::SignatureVerify`] if the private key associated with the account's public key did _not_ sign this transaction,
-///   or the signature associated was invalid.
+/// - [`Error::SignatureVerify`] if the account's key is not satisfied by the signatures on this transaction.
+/// - See [`Key::verify_transaction`]
 /// - See [`AccountInfoQuery::execute`]
 pub async fn verify_transaction_signature<D: TransactionExecute>(
     client: &Client,
     account_id: AccountId,
     transaction: &mut Transaction<D>,
 ) -> crate::Result<()> {
-    let key = query_pk(client, account_id).await?;
+    let key = query_key(client, account_id).await?;
+
+    let report = key.verify_transaction(transaction)?;
+
+    if !report.is_satisfied() {
+        return Err(Error::signature_verify(format!(
+            "`{account_id}`: key not satisfied by transaction signatures: {report:?}"
+        )));
+    }
 
-    key.verify_transaction(transaction)
+    Ok(())
 }
@@ -39,6 +39,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -63,6 +64,8 @@ pub type AccountUpdateTransaction = Transaction<AccountUpdateTransactionData>;
 // TODO: new_realm_admin_key: Option<Key>,
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AccountUpdateTransactionData {
     /// The account ID which is being updated in this transaction.
     account_id: Option<AccountId>,
@@ -290,7 +293,15 @@ impl AccountUpdateTransaction {
     }
 }
 
-impl TransactionData for AccountUpdateTransactionData {}
+impl TransactionData for AccountUpdateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        if let Some(memo) = &self.account_memo {
+            violations.memo("account memo", memo);
+        }
+
+        violations.auto_renew_period(self.auto_renew_period);
+    }
+}
 
 impl TransactionExecute for AccountUpdateTransactionData {
     fn execute(
@@ -31,6 +31,8 @@ pub type AllProxyStakers = Vec<ProxyStaker>;
 
 /// Information about a single account that is proxy staking.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ProxyStaker {
     /// The Account ID that is proxy staking.
     pub account_id: AccountId,
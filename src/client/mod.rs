@@ -35,6 +35,7 @@ pub(crate) use network::{
 };
 pub(crate) use operator::Operator;
 use parking_lot::RwLock;
+use time::OffsetDateTime;
 use tokio::sync::watch;
 use triomphe::Arc;
 
@@ -47,8 +48,10 @@ use crate::{
     AccountId,
     ArcSwapOption,
     Error,
+    FeeEstimator,
     Hbar,
     LedgerId,
+    MaxTransactionFeePolicy,
     NodeAddressBook,
     PrivateKey,
     PublicKey,
@@ -90,7 +93,9 @@ struct ClientBuilder {
     max_query_payment: Option<NonZeroU64>,
     ledger_id: Option<LedgerId>,
     auto_validate_checksums: bool,
+    auto_validate_transactions: bool,
     regenerate_transaction_ids: bool,
+    lazy_signing: bool,
     update_network: bool,
     backoff: ClientBackoff,
 }
@@ -105,7 +110,9 @@ impl ClientBuilder {
             max_query_payment: None,
             ledger_id: None,
             auto_validate_checksums: false,
+            auto_validate_transactions: false,
             regenerate_transaction_ids: true,
+            lazy_signing: false,
             update_network: true,
             backoff: ClientBackoff::default(),
         }
@@ -127,7 +134,9 @@ impl ClientBuilder {
             max_query_payment,
             ledger_id,
             auto_validate_checksums,
+            auto_validate_transactions,
             regenerate_transaction_ids,
+            lazy_signing,
             update_network,
             backoff,
         } = self;
@@ -150,7 +159,11 @@ impl ClientBuilder {
             max_query_payment_tinybar: AtomicU64::new(max_query_payment.map_or(0, NonZeroU64::get)),
             ledger_id: ArcSwapOption::new(ledger_id.map(Arc::new)),
             auto_validate_checksums: AtomicBool::new(auto_validate_checksums),
+            auto_validate_transactions: AtomicBool::new(auto_validate_transactions),
             regenerate_transaction_ids: AtomicBool::new(regenerate_transaction_ids),
+            lazy_signing: AtomicBool::new(lazy_signing),
+            max_transaction_fee_policy: ArcSwapOption::empty(),
+            fee_estimator: ArcSwapOption::empty(),
             network_update_tx,
             backoff: RwLock::new(backoff),
         }))
@@ -164,7 +177,11 @@ struct ClientInner {
     max_query_payment_tinybar: AtomicU64,
     ledger_id: ArcSwapOption<LedgerId>,
     auto_validate_checksums: AtomicBool,
+    auto_validate_transactions: AtomicBool,
     regenerate_transaction_ids: AtomicBool,
+    lazy_signing: AtomicBool,
+    max_transaction_fee_policy: ArcSwapOption<MaxTransactionFeePolicy>,
+    fee_estimator: ArcSwapOption<FeeEstimator>,
     network_update_tx: watch::Sender<Option<Duration>>,
     backoff: RwLock<ClientBackoff>,
 }
@@ -378,6 +395,22 @@ impl Client {
         self.0.auto_validate_checksums.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if transactions should be automatically validated before they're submitted.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn auto_validate_transactions(&self) -> bool {
+        self.0.auto_validate_transactions.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable automatic client side validation of transactions.
+    ///
+    /// When enabled, [`Transaction::validate`](crate::Transaction::validate) is run on `freeze_with` and `execute`,
+    /// so that transactions the network would reject for exceeding a documented limit fail without a round trip.
+    pub fn set_auto_validate_transactions(&self, value: bool) {
+        self.0.auto_validate_transactions.store(value, Ordering::Relaxed);
+    }
+
     /// Returns true if transaction IDs should be automatically regenerated.
     ///
     /// This is `true` by default.
@@ -391,6 +424,24 @@ impl Client {
         self.0.regenerate_transaction_ids.store(value, Ordering::Relaxed);
     }
 
+    /// Returns true if pre-built transactions are only signed for the nodes they're actually submitted to.
+    ///
+    /// This is `false` by default.
+    #[must_use]
+    pub fn lazy_signing(&self) -> bool {
+        self.0.lazy_signing.load(Ordering::Relaxed)
+    }
+
+    /// Enable or disable lazy signing.
+    ///
+    /// Transactions with pre-built bodies for every node (IE, from [`AnyTransaction::from_bytes`](crate::AnyTransaction::from_bytes))
+    /// are normally signed by every pending signer for every node and chunk before the first one is submitted.
+    /// With lazy signing, each body is only signed right before it's submitted to its node,
+    /// which matters when every signature is expensive, such as with a remote signer.
+    pub fn set_lazy_signing(&self, value: bool) {
+        self.0.lazy_signing.store(value, Ordering::Relaxed);
+    }
+
     /// Sets the account that will, by default, be paying for transactions and queries built with
     /// this client.
     ///
@@ -451,6 +502,42 @@ impl Client {
         (val > 0).then(|| Hbar::from_tinybars(val as i64))
     }
 
+    /// Sets the policy used to set the max transaction fee of transactions that don't have one.
+    ///
+    /// When a policy is set, the network's current fee schedule and exchange rate are fetched and cached
+    /// (until the exchange rate expires), and transactions without a [`max_transaction_fee`](crate::Transaction::max_transaction_fee)
+    /// have it set from their estimated fee when they're executed, see [`FeeEstimator`].
+    ///
+    /// Transactions that are frozen before they're executed keep the max transaction fee they were frozen with.
+    ///
+    /// `None` (the default) uses [`default_max_transaction_fee`](Self::default_max_transaction_fee) instead.
+    pub fn set_max_transaction_fee_policy(&self, policy: Option<MaxTransactionFeePolicy>) {
+        self.0.max_transaction_fee_policy.store(policy.map(Arc::new));
+    }
+
+    /// Gets the policy used to set the max transaction fee of transactions that don't have one.
+    #[must_use]
+    pub fn max_transaction_fee_policy(&self) -> Option<MaxTransactionFeePolicy> {
+        self.0.max_transaction_fee_policy.load().as_deref().copied()
+    }
+
+    /// Returns a [`FeeEstimator`] for the network's current fee schedule and exchange rate.
+    ///
+    /// These are cached, and fetched again once the cached exchange rate expires.
+    pub(crate) async fn fee_estimator(&self) -> crate::Result<Arc<FeeEstimator>> {
+        if let Some(estimator) = self.0.fee_estimator.load_full() {
+            if OffsetDateTime::now_utc() < estimator.exchange_rate().expiration_time {
+                return Ok(estimator);
+            }
+        }
+
+        let estimator = Arc::new(FeeEstimator::fetch(self).await?);
+
+        self.0.fee_estimator.store(Some(estimator.clone()));
+
+        Ok(estimator)
+    }
+
     /// Gets the maximum query fee the paying account is willing to pay.
     #[must_use]
     pub fn default_max_query_payment(&self) -> Option<Hbar> {
@@ -33,6 +33,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -50,7 +51,10 @@ use crate::{
 pub type ContractCreateTransaction = Transaction<ContractCreateTransactionData>;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractCreateTransactionData {
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_opt"))]
     bytecode: Option<Vec<u8>>,
 
     bytecode_file_id: Option<FileId>,
@@ -63,6 +67,7 @@ pub struct ContractCreateTransactionData {
 
     auto_renew_period: Duration,
 
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     constructor_parameters: Vec<u8>,
 
     contract_memo: String,
@@ -264,6 +269,11 @@ impl TransactionData for ContractCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(20)
     }
+
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.memo("contract memo", &self.contract_memo);
+        violations.auto_renew_period(Some(self.auto_renew_period));
+    }
 }
 
 impl TransactionExecute for ContractCreateTransactionData {
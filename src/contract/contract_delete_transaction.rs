@@ -50,6 +50,8 @@ use crate::{
 pub type ContractDeleteTransaction = Transaction<ContractDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractDeleteTransactionData {
     contract_id: Option<ContractId>,
 
@@ -56,6 +56,8 @@ use crate::{
 pub type ContractExecuteTransaction = Transaction<ContractExecuteTransactionData>;
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractExecuteTransactionData {
     /// The contract instance to call.
     contract_id: Option<ContractId>,
@@ -67,6 +69,7 @@ pub struct ContractExecuteTransactionData {
     payable_amount: Hbar,
 
     /// The function parameters as their raw bytes.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     function_parameters: Vec<u8>,
 }
 
@@ -38,6 +38,8 @@ use crate::{
 
 /// The result returned by a call to a smart contract function.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractFunctionResult {
     /// The smart contract instance whose function was called.
     pub contract_id: ContractId,
@@ -46,12 +48,14 @@ pub struct ContractFunctionResult {
     pub evm_address: Option<ContractId>,
 
     /// The raw bytes returned by the function.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bytes: Vec<u8>,
 
     /// Message if there was an error during smart contract execution.
     pub error_message: Option<String>,
 
     /// Bloom filter for record.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub bloom: Vec<u8>,
 
     /// Units of gas used to execute contract.
@@ -61,9 +65,11 @@ pub struct ContractFunctionResult {
     pub gas: u64,
 
     /// Number of HBAR sent (the function must be payable if this is nonzero).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     pub hbar_amount: u64,
 
     /// The parameters passed into the contract call.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub contract_function_parameters_bytes: Vec<u8>,
 
     /// The account that is the "sender." If not present it is the accountId from the transactionId.
@@ -36,6 +36,8 @@ use crate::{
 
 /// Current information on a smart contract instance.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractInfo {
     /// ID of the contract instance, in the format used by transactions.
     pub contract_id: ContractId,
@@ -64,6 +66,7 @@ pub struct ContractInfo {
     pub contract_memo: String,
 
     /// The current balance, in tinybars.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     pub balance: u64,
 
     /// Whether the contract has been deleted.
@@ -36,6 +36,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -52,6 +53,8 @@ use crate::{
 pub type ContractUpdateTransaction = Transaction<ContractUpdateTransactionData>;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ContractUpdateTransactionData {
     contract_id: Option<ContractId>,
 
@@ -215,7 +218,15 @@ impl ContractUpdateTransaction {
     }
 }
 
-impl TransactionData for ContractUpdateTransactionData {}
+impl TransactionData for ContractUpdateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        if let Some(memo) = &self.contract_memo {
+            violations.memo("contract memo", memo);
+        }
+
+        violations.auto_renew_period(self.auto_renew_period);
+    }
+}
 
 impl TransactionExecute for ContractUpdateTransactionData {
     fn execute(
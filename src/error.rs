@@ -24,9 +24,15 @@ use std::result::Result as StdResult;
 use crate::entity_id::Checksum;
 use crate::{
     AccountId,
+    ChunkProgress,
+    FileId,
     Hbar,
+    RequestType,
     Status,
+    TokenAmount,
+    TokenId,
     TransactionId,
+    TransactionViolation,
 };
 
 /// `Result<T, Error>`
@@ -183,6 +189,161 @@ pub enum Error {
     #[error("node account `{0}` was not found in the configured network")]
     NodeAccountUnknown(Box<AccountId>),
 
+    /// A [`Transaction`](crate::Transaction) failed client side validation.
+    ///
+    /// See [`Transaction::validate`](crate::Transaction::validate).
+    #[error(
+        "transaction failed validation with status `{:?}`: {}",
+        .violations[0].status,
+        .violations[0].message
+    )]
+    TransactionValidation {
+        /// Every limit the transaction violates, never empty.
+        violations: Vec<TransactionViolation>,
+    },
+
+    /// A chunk of a chunked transaction failed, see [`Transaction::execute_chunks`](crate::Transaction::execute_chunks).
+    ///
+    /// Every chunk before `progress.next_chunk()` has finished,
+    /// and `progress` can be given to [`Transaction::resume_chunks`](crate::Transaction::resume_chunks) to continue from there.
+    #[error("chunk {} of {} failed: {source}", .progress.next_chunk() + 1, .progress.total_chunks())]
+    ChunkedExecution {
+        /// The error the chunk failed with.
+        #[source]
+        source: Box<Error>,
+
+        /// How far the transaction got.
+        progress: Box<ChunkProgress>,
+    },
+
+    /// A chunked transaction was resumed from a [`ChunkProgress`] with a different number of chunks.
+    #[error(
+        "resumed a chunked transaction with {actual} chunks from progress for {expected} chunks"
+    )]
+    ChunkCountMismatch {
+        /// The number of chunks the progress is for.
+        expected: usize,
+
+        /// The number of chunks the transaction has.
+        actual: usize,
+    },
+
+    /// A chunk was requested that a chunked transaction doesn't have.
+    #[error("requested chunk {index} of a transaction with {chunks} chunks")]
+    ChunkIndexOutOfRange {
+        /// The index of the requested chunk.
+        index: usize,
+
+        /// The number of chunks the transaction has.
+        chunks: usize,
+    },
+
+    /// A transaction is larger than the network accepts.
+    #[error("transaction is {size} bytes, over the network's limit of {limit} bytes")]
+    TransactionTooLarge {
+        /// The size of the transaction, in bytes.
+        size: usize,
+
+        /// The largest transaction the network accepts, in bytes.
+        limit: usize,
+    },
+
+    /// The contents of a file uploaded by a [`FileUploadFlow`](crate::FileUploadFlow) don't match what was uploaded.
+    #[error("contents of file `{file_id}` have SHA-384 hash `{actual_hash}`, expected `{expected_hash}`")]
+    FileUploadVerification {
+        /// The ID of the uploaded file.
+        file_id: Box<FileId>,
+
+        /// The hex encoded SHA-384 hash of the contents that were uploaded.
+        expected_hash: String,
+
+        /// The hex encoded SHA-384 hash of the file's contents on the network.
+        actual_hash: String,
+    },
+
+    /// Bytes given to [`Transaction::from_bytes`](crate::Transaction::from_bytes) (or similar) are for a different kind of transaction than requested.
+    #[error("bytes are for a transaction of type `{0:?}`, not the requested type")]
+    TransactionTypeMismatch(RequestType),
+
+    /// A [`CustomFeeSimulator`](crate::CustomFeeSimulator) predicted that the network would reject a transfer because of the custom fees of a token.
+    #[error("custom fees of token `{token_id}` can't be charged: {reason}")]
+    CustomFeeSimulation {
+        /// The token whose custom fees can't be charged.
+        token_id: Box<TokenId>,
+
+        /// Why the custom fees can't be charged.
+        reason: &'static str,
+    },
+
+    /// An amount couldn't be converted to [`Hbar`] without overflowing or losing precision.
+    #[error("`{amount}` can't be converted to hbar: {reason}")]
+    HbarConversion {
+        /// The amount that couldn't be converted, with its unit.
+        amount: String,
+
+        /// Why the amount couldn't be converted.
+        reason: &'static str,
+    },
+
+    /// A negative [`TokenAmount`] was given where only non-negative amounts are meaningful, such as the amount to mint.
+    #[error("token amount `{0}` must not be negative")]
+    NegativeTokenAmount(TokenAmount),
+
+    /// A payment given to a [`TransferDistribution`](crate::TransferDistribution) can't be planned.
+    #[error("payment to `{recipient}` can't be planned: {reason}")]
+    TransferDistribution {
+        /// The recipient of the payment.
+        recipient: Box<AccountId>,
+
+        /// Why the payment can't be planned.
+        reason: &'static str,
+    },
+
+    /// An [`NftMintFlow`](crate::NftMintFlow) couldn't find out whether some of its transactions minted NFTs.
+    #[error("the outcome of mint transactions {0:?} is unknown")]
+    NftMintUnconfirmed(Vec<TransactionId>),
+
+    /// The progress given to an [`NftMintFlow`](crate::NftMintFlow) is for a different number of NFTs than the flow mints.
+    #[error("the progress is for {expected} NFTs, but the flow mints {actual}")]
+    NftMintProgressMismatch {
+        /// The number of NFTs the progress is for.
+        expected: usize,
+
+        /// The number of NFTs the flow mints.
+        actual: usize,
+    },
+
+    /// NFT metadata isn't valid [HIP-412](https://hips.hedera.com/hip/hip-412) metadata.
+    #[cfg(feature = "hip412")]
+    #[error("invalid HIP-412 metadata: {0}")]
+    Hip412(#[source] BoxStdError),
+
+    /// A [`TokenLaunchFlow`](crate::TokenLaunchFlow) can't be launched as given.
+    #[error("token launch can't be planned: {reason}")]
+    TokenLaunch {
+        /// The recipient the problem is with, if any.
+        account_id: Option<Box<AccountId>>,
+
+        /// Why the launch can't be planned.
+        reason: &'static str,
+    },
+
+    /// A [`TokenLaunchFlow`](crate::TokenLaunchFlow) couldn't find out whether some of its transactions reached consensus.
+    #[error("the outcome of token launch transactions {0:?} is unknown")]
+    TokenLaunchUnconfirmed(Vec<TransactionId>),
+
+    /// A [`TokenAdminBatch`](crate::TokenAdminBatch) that wipes a token wasn't given the amount to wipe from an account.
+    #[error("no amount to wipe from `{0}` was given")]
+    TokenWipeAmountMissing(Box<AccountId>),
+
+    /// An ethereum transaction couldn't be signed, for instance because the key isn't an ECDSA(secp256k1) key.
+    #[error("failed to sign ethereum transaction: {0}")]
+    EthereumSign(#[source] BoxStdError),
+
+    /// The fee schedule given to a [`FeeEstimator`](crate::FeeEstimator) has no prices for a transaction.
+    #[error("fee schedule has no prices for `{0:?}`")]
+    FeeScheduleMissing(RequestType),
+
     /// Received an unrecognized status code from the Hedera Network.
     ///
     /// This can happen when the SDK is outdated, try updating your SDK.
@@ -224,6 +385,24 @@ impl Error {
     pub(crate) fn signature_verify(error: impl Into<BoxStdError>) -> Self {
         Self::SignatureVerify(error.into())
     }
+
+    pub(crate) fn ethereum_sign(error: impl Into<BoxStdError>) -> Self {
+        Self::EthereumSign(error.into())
+    }
+
+    #[cfg(feature = "hip412")]
+    pub(crate) fn hip412(error: impl Into<BoxStdError>) -> Self {
+        Self::Hip412(error.into())
+    }
+
+    /// Returns `true` if `self` is from a receipt that couldn't be found, even after retrying.
+    pub(crate) fn is_receipt_not_found(&self) -> bool {
+        match self {
+            Self::TimedOut(error) => error.is_receipt_not_found(),
+            Self::QueryNoPaymentPreCheckStatus { status } => *status == Status::ReceiptNotFound,
+            _ => false,
+        }
+    }
 }
 
 /// Failed to parse a mnemonic.
@@ -35,6 +35,9 @@ pub enum EthereumData {
     /// Data for a legacy ethereum transaction.
     Legacy(LegacyEthereumData),
 
+    /// Data for an Eip 2930 ethereum transaction.
+    Eip2930(Eip2930EthereumData),
+
     /// Data for an Eip 1559 ethereum transaction.
     Eip1559(Eip1559EthereumData),
 }
@@ -43,6 +46,7 @@ impl EthereumData {
     pub(super) fn call_data_mut(&mut self) -> &mut Vec<u8> {
         match self {
             EthereumData::Legacy(it) => &mut it.call_data,
+            EthereumData::Eip2930(it) => &mut it.call_data,
             EthereumData::Eip1559(it) => &mut it.call_data,
         }
     }
@@ -50,6 +54,10 @@ impl EthereumData {
     pub(crate) fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         match bytes.split_first() {
             // note: eating the 2 here involves a bit of extra work.
+            Some((1, bytes)) => Eip2930EthereumData::decode_rlp(&Rlp::new(bytes))
+                .map(Self::Eip2930)
+                .map_err(Error::basic_parse),
+
             Some((2, bytes)) => Eip1559EthereumData::decode_rlp(&Rlp::new(bytes))
                 .map(Self::Eip1559)
                 .map_err(Error::basic_parse),
@@ -64,11 +72,30 @@ impl EthereumData {
     pub fn to_bytes(&self) -> Vec<u8> {
         match self {
             EthereumData::Legacy(it) => it.to_bytes(),
+            EthereumData::Eip2930(it) => it.to_bytes(),
             EthereumData::Eip1559(it) => it.to_bytes(),
         }
     }
 }
 
+impl From<LegacyEthereumData> for EthereumData {
+    fn from(value: LegacyEthereumData) -> Self {
+        Self::Legacy(value)
+    }
+}
+
+impl From<Eip2930EthereumData> for EthereumData {
+    fn from(value: Eip2930EthereumData) -> Self {
+        Self::Eip2930(value)
+    }
+}
+
+impl From<Eip1559EthereumData> for EthereumData {
+    fn from(value: Eip1559EthereumData) -> Self {
+        Self::Eip1559(value)
+    }
+}
+
 /// Data for a legacy ethereum transaction.
 #[derive(Clone)]
 #[non_exhaustive]
@@ -167,6 +194,137 @@ impl LegacyEthereumData {
     }
 }
 
+/// Data for an Eip 2930 ethereum transaction.
+#[derive(Clone)]
+#[non_exhaustive]
+pub struct Eip2930EthereumData {
+    /// ID of the chain.
+    pub chain_id: Vec<u8>,
+
+    /// Transaction's nonce.
+    pub nonce: Vec<u8>,
+
+    /// Price for 1 gas.
+    pub gas_price: Vec<u8>,
+
+    /// The amount of gas available for the transaction.
+    pub gas_limit: Vec<u8>,
+
+    /// The receiver of the transaction.
+    pub to: Vec<u8>,
+
+    /// The transaction value.
+    pub value: Vec<u8>,
+
+    /// The raw call data.
+    pub call_data: Vec<u8>,
+
+    /// Specifies an array of addresses and storage keys that the transaction plans to access.
+    pub access_list: Vec<Vec<u8>>,
+
+    /// Recovery parameter used to ease the signature verification.
+    pub recovery_id: Vec<u8>,
+
+    /// The R value of the signature.
+    pub r: Vec<u8>,
+
+    /// The S value of the signature.
+    pub s: Vec<u8>,
+}
+
+// manual impl of debug for the hex encoding of everything.
+impl fmt::Debug for Eip2930EthereumData {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let Self {
+            chain_id,
+            nonce,
+            gas_price,
+            gas_limit,
+            to,
+            value,
+            call_data,
+            access_list,
+            recovery_id,
+            r,
+            s,
+        } = self;
+
+        f.debug_struct("Eip2930EthereumData")
+            .field("chain_id", &hex::encode(chain_id))
+            .field("nonce", &hex::encode(nonce))
+            .field("gas_price", &hex::encode(gas_price))
+            .field("gas_limit", &hex::encode(gas_limit))
+            .field("to", &hex::encode(to))
+            .field("value", &hex::encode(value))
+            .field("call_data", &hex::encode(call_data))
+            .field("access_list", &access_list.iter().map(hex::encode).collect::<Vec<_>>())
+            .field("recovery_id", &hex::encode(recovery_id))
+            .field("r", &hex::encode(r))
+            .field("s", &hex::encode(s))
+            .finish()
+    }
+}
+
+impl Eip2930EthereumData {
+    fn decode_rlp(rlp: &Rlp) -> Result<Self, rlp::DecoderError> {
+        if rlp.item_count()? != 11 {
+            return Err(rlp::DecoderError::RlpIncorrectListLen);
+        }
+
+        Ok(Self {
+            chain_id: rlp.val_at(0)?,
+            nonce: rlp.val_at(1)?,
+            gas_price: rlp.val_at(2)?,
+            gas_limit: rlp.val_at(3)?,
+            to: rlp.val_at(4)?,
+            value: rlp.val_at(5)?,
+            call_data: rlp.val_at(6)?,
+            access_list: rlp.list_at(7)?,
+            recovery_id: rlp.val_at(8)?,
+            r: rlp.val_at(9)?,
+            s: rlp.val_at(10)?,
+        })
+    }
+
+    /// Deserialize this data from rlp encoded bytes.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if decoding the bytes fails.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        let (&first, bytes) = bytes
+            .split_first()
+            .ok_or_else(|| Error::basic_parse("Empty ethereum transaction data"))?;
+
+        if first != 1 {
+            return Err(Error::basic_parse(rlp::DecoderError::Custom("Invalid kind")));
+        }
+
+        Self::decode_rlp(&Rlp::new(bytes)).map_err(Error::basic_parse)
+    }
+
+    /// Convert this data to rlp encoded bytes.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut buffer = BytesMut::new();
+        buffer.put_u8(0x01);
+        let mut rlp = rlp::RlpStream::new_list_with_buffer(buffer, 11);
+
+        rlp.append(&self.chain_id)
+            .append(&self.nonce)
+            .append(&self.gas_price)
+            .append(&self.gas_limit)
+            .append(&self.to)
+            .append(&self.value)
+            .append(&self.call_data)
+            .append_list::<Vec<_>, _>(self.access_list.as_slice())
+            .append(&self.recovery_id)
+            .append(&self.r)
+            .append(&self.s);
+
+        rlp.out().to_vec()
+    }
+}
+
 /// Data for an Eip 1559 ethereum transaction.
 #[derive(Clone)]
 #[non_exhaustive]
@@ -0,0 +1,523 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use bytes::{
+    BufMut,
+    BytesMut,
+};
+use rlp::RlpStream;
+use sha3::Digest;
+
+use super::ethereum_data::{
+    Eip1559EthereumData,
+    Eip2930EthereumData,
+    EthereumData,
+    LegacyEthereumData,
+};
+use super::evm_address::SolidityAddress;
+use crate::{
+    ContractId,
+    EntityId,
+    EvmAddress,
+    PrivateKey,
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Kind {
+    Legacy,
+    Eip2930,
+    Eip1559,
+}
+
+/// Build and sign the data for an [`EthereumTransaction`](crate::EthereumTransaction).
+///
+/// The signed data can be given to [`EthereumFlow::ethereum_data`](crate::EthereumFlow::ethereum_data)
+/// via [`EthereumData::to_bytes`].
+///
+/// Hedera doesn't use access lists, so the access list of EIP-2930 and EIP-1559 transactions is always empty.
+#[derive(Debug, Clone)]
+pub struct EthereumDataBuilder {
+    kind: Kind,
+    chain_id: u64,
+    nonce: u64,
+    gas_price: u128,
+    max_priority_gas: u128,
+    max_gas: u128,
+    gas_limit: u64,
+    to: Option<EvmAddress>,
+    value: u128,
+    call_data: Vec<u8>,
+}
+
+impl EthereumDataBuilder {
+    fn new(kind: Kind, chain_id: u64) -> Self {
+        Self {
+            kind,
+            chain_id,
+            nonce: 0,
+            gas_price: 0,
+            max_priority_gas: 0,
+            max_gas: 0,
+            gas_limit: 0,
+            to: None,
+            value: 0,
+            call_data: Vec::new(),
+        }
+    }
+
+    /// Create a builder for a legacy ethereum transaction, replay protected by `chain_id` as per EIP-155.
+    #[must_use]
+    pub fn legacy(chain_id: u64) -> Self {
+        Self::new(Kind::Legacy, chain_id)
+    }
+
+    /// Create a builder for an EIP-2930 ethereum transaction on `chain_id`.
+    #[must_use]
+    pub fn eip2930(chain_id: u64) -> Self {
+        Self::new(Kind::Eip2930, chain_id)
+    }
+
+    /// Create a builder for an EIP-1559 ethereum transaction on `chain_id`.
+    #[must_use]
+    pub fn eip1559(chain_id: u64) -> Self {
+        Self::new(Kind::Eip1559, chain_id)
+    }
+
+    /// Returns the ID of the chain the transaction is for.
+    #[must_use]
+    pub fn get_chain_id(&self) -> u64 {
+        self.chain_id
+    }
+
+    /// Returns the transaction's nonce.
+    #[must_use]
+    pub fn get_nonce(&self) -> u64 {
+        self.nonce
+    }
+
+    /// Sets the transaction's nonce, which is the number of transactions sent by the signer before it.
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = nonce;
+
+        self
+    }
+
+    /// Returns the price for 1 gas, in weibars.
+    #[must_use]
+    pub fn get_gas_price(&self) -> u128 {
+        self.gas_price
+    }
+
+    /// Sets the price for 1 gas, in weibars.
+    ///
+    /// Only used by legacy and EIP-2930 transactions.
+    pub fn gas_price(&mut self, gas_price: u128) -> &mut Self {
+        self.gas_price = gas_price;
+
+        self
+    }
+
+    /// Returns the maximum priority fee per gas, in weibars.
+    #[must_use]
+    pub fn get_max_priority_gas(&self) -> u128 {
+        self.max_priority_gas
+    }
+
+    /// Sets the maximum priority fee per gas, in weibars.
+    ///
+    /// Only used by EIP-1559 transactions.
+    pub fn max_priority_gas(&mut self, max_priority_gas: u128) -> &mut Self {
+        self.max_priority_gas = max_priority_gas;
+
+        self
+    }
+
+    /// Returns the maximum fee per gas, in weibars.
+    #[must_use]
+    pub fn get_max_gas(&self) -> u128 {
+        self.max_gas
+    }
+
+    /// Sets the maximum fee per gas, in weibars.
+    ///
+    /// Only used by EIP-1559 transactions.
+    pub fn max_gas(&mut self, max_gas: u128) -> &mut Self {
+        self.max_gas = max_gas;
+
+        self
+    }
+
+    /// Returns the amount of gas available for the transaction.
+    #[must_use]
+    pub fn get_gas_limit(&self) -> u64 {
+        self.gas_limit
+    }
+
+    /// Sets the amount of gas available for the transaction.
+    pub fn gas_limit(&mut self, gas_limit: u64) -> &mut Self {
+        self.gas_limit = gas_limit;
+
+        self
+    }
+
+    /// Returns the receiver of the transaction, or `None` if the transaction creates a contract.
+    #[must_use]
+    pub fn get_to(&self) -> Option<EvmAddress> {
+        self.to
+    }
+
+    /// Sets the receiver of the transaction.
+    ///
+    /// Leave this unset for a transaction that creates a contract.
+    pub fn to(&mut self, to: EvmAddress) -> &mut Self {
+        self.to = Some(to);
+
+        self
+    }
+
+    /// Sets the receiver of the transaction to the contract `contract_id`.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`](crate::Error::BasicParse) if `contract_id` has no EVM address and its shard is larger than `u32::MAX`.
+    pub fn to_contract(&mut self, contract_id: ContractId) -> crate::Result<&mut Self> {
+        let address = match contract_id.evm_address {
+            Some(address) => EvmAddress(address),
+            None => {
+                let ContractId { shard, realm, num, .. } = contract_id;
+                SolidityAddress::try_from(EntityId { shard, realm, num, checksum: None })?.0
+            }
+        };
+
+        self.to = Some(address);
+
+        Ok(self)
+    }
+
+    /// Returns the transaction value, in weibars.
+    #[must_use]
+    pub fn get_value(&self) -> u128 {
+        self.value
+    }
+
+    /// Sets the transaction value, in weibars.
+    pub fn value(&mut self, value: u128) -> &mut Self {
+        self.value = value;
+
+        self
+    }
+
+    /// Returns the raw call data.
+    #[must_use]
+    pub fn get_call_data(&self) -> &[u8] {
+        &self.call_data
+    }
+
+    /// Sets the raw call data.
+    pub fn call_data(&mut self, call_data: impl Into<Vec<u8>>) -> &mut Self {
+        self.call_data = call_data.into();
+
+        self
+    }
+
+    /// Returns the hash that is signed by [`sign`](Self::sign).
+    #[must_use]
+    pub fn signing_hash(&self) -> [u8; 32] {
+        sha3::Keccak256::digest(self.signing_payload()).into()
+    }
+
+    /// Signs the transaction with `key`, returning its data.
+    ///
+    /// # Errors
+    /// - [`Error::EthereumSign`](crate::Error::EthereumSign) if `key` isn't an ECDSA(secp256k1) key.
+    pub fn sign(&self, key: &PrivateKey) -> crate::Result<EthereumData> {
+        let (signature, recovery_id) = key.sign_recoverable(&self.signing_payload())?;
+
+        let (r, s) = signature.split_bytes();
+        let r = trim_leading_zeros(&r);
+        let s = trim_leading_zeros(&s);
+        let recovery_id = recovery_id.to_byte();
+
+        let data = match self.kind {
+            Kind::Legacy => {
+                let v = u128::from(self.chain_id) * 2 + 35 + u128::from(recovery_id);
+
+                LegacyEthereumData {
+                    nonce: encode_uint(self.nonce),
+                    gas_price: encode_uint(self.gas_price),
+                    gas_limit: encode_uint(self.gas_limit),
+                    to: self.encode_to(),
+                    value: encode_uint(self.value),
+                    v: encode_uint(v),
+                    call_data: self.call_data.clone(),
+                    r,
+                    s,
+                }
+                .into()
+            }
+
+            Kind::Eip2930 => Eip2930EthereumData {
+                chain_id: encode_uint(self.chain_id),
+                nonce: encode_uint(self.nonce),
+                gas_price: encode_uint(self.gas_price),
+                gas_limit: encode_uint(self.gas_limit),
+                to: self.encode_to(),
+                value: encode_uint(self.value),
+                call_data: self.call_data.clone(),
+                access_list: Vec::new(),
+                recovery_id: encode_uint(recovery_id),
+                r,
+                s,
+            }
+            .into(),
+
+            Kind::Eip1559 => Eip1559EthereumData {
+                chain_id: encode_uint(self.chain_id),
+                nonce: encode_uint(self.nonce),
+                max_priority_gas: encode_uint(self.max_priority_gas),
+                max_gas: encode_uint(self.max_gas),
+                gas_limit: encode_uint(self.gas_limit),
+                to: self.encode_to(),
+                value: encode_uint(self.value),
+                call_data: self.call_data.clone(),
+                access_list: Vec::new(),
+                recovery_id: encode_uint(recovery_id),
+                r,
+                s,
+            }
+            .into(),
+        };
+
+        Ok(data)
+    }
+
+    fn encode_to(&self) -> Vec<u8> {
+        self.to.map(|it| it.to_bytes().to_vec()).unwrap_or_default()
+    }
+
+    /// Returns the bytes whose keccak256 hash is signed.
+    fn signing_payload(&self) -> Vec<u8> {
+        match self.kind {
+            Kind::Legacy => {
+                let mut rlp = RlpStream::new_list(9);
+
+                // EIP-155: the chain ID takes the place of `v`, and `r` and `s` are empty.
+                rlp.append(&encode_uint(self.nonce))
+                    .append(&encode_uint(self.gas_price))
+                    .append(&encode_uint(self.gas_limit))
+                    .append(&self.encode_to())
+                    .append(&encode_uint(self.value))
+                    .append(&self.call_data)
+                    .append(&encode_uint(self.chain_id))
+                    .append_empty_data()
+                    .append_empty_data();
+
+                rlp.out().to_vec()
+            }
+
+            Kind::Eip2930 => {
+                let mut buffer = BytesMut::new();
+                buffer.put_u8(0x01);
+                let mut rlp = RlpStream::new_list_with_buffer(buffer, 8);
+
+                rlp.append(&encode_uint(self.chain_id))
+                    .append(&encode_uint(self.nonce))
+                    .append(&encode_uint(self.gas_price))
+                    .append(&encode_uint(self.gas_limit))
+                    .append(&self.encode_to())
+                    .append(&encode_uint(self.value))
+                    .append(&self.call_data)
+                    .begin_list(0);
+
+                rlp.out().to_vec()
+            }
+
+            Kind::Eip1559 => {
+                let mut buffer = BytesMut::new();
+                buffer.put_u8(0x02);
+                let mut rlp = RlpStream::new_list_with_buffer(buffer, 9);
+
+                rlp.append(&encode_uint(self.chain_id))
+                    .append(&encode_uint(self.nonce))
+                    .append(&encode_uint(self.max_priority_gas))
+                    .append(&encode_uint(self.max_gas))
+                    .append(&encode_uint(self.gas_limit))
+                    .append(&self.encode_to())
+                    .append(&encode_uint(self.value))
+                    .append(&self.call_data)
+                    .begin_list(0);
+
+                rlp.out().to_vec()
+            }
+        }
+    }
+}
+
+/// Encodes `value` as an rlp integer: big endian, without leading zeros.
+fn encode_uint(value: impl Into<u128>) -> Vec<u8> {
+    trim_leading_zeros(&value.into().to_be_bytes())
+}
+
+fn trim_leading_zeros(bytes: &[u8]) -> Vec<u8> {
+    let start = bytes.iter().position(|&it| it != 0).unwrap_or(bytes.len());
+
+    bytes[start..].to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use hex_literal::hex;
+    use sha3::Digest;
+
+    use crate::{
+        ContractId,
+        EthereumData,
+        EthereumDataBuilder,
+        EvmAddress,
+        PrivateKey,
+    };
+
+    const ONE_ETHER: u128 = 1_000_000_000_000_000_000;
+
+    fn key() -> PrivateKey {
+        PrivateKey::from_bytes_ecdsa(&[0x46; 32]).unwrap()
+    }
+
+    // https://eips.ethereum.org/EIPS/eip-155#example
+    fn eip155_example() -> EthereumDataBuilder {
+        let mut builder = EthereumDataBuilder::legacy(1);
+        builder
+            .nonce(9)
+            .gas_price(20_000_000_000)
+            .gas_limit(21000)
+            .to(EvmAddress::from([0x35; 20]))
+            .value(ONE_ETHER);
+
+        builder
+    }
+
+    /// Checks that `r` and `s` are `key()`'s signature of `payload`, and that `recovery_id` recovers `key()`.
+    fn assert_recoverable(payload: &[u8], r: &[u8], s: &[u8], recovery_id: u8) {
+        let mut bytes = [0; 64];
+        bytes[32 - r.len()..32].copy_from_slice(r);
+        bytes[64 - s.len()..].copy_from_slice(s);
+
+        let signature = k256::ecdsa::Signature::from_slice(&bytes).unwrap();
+        assert!(signature.normalize_s().is_none(), "signature must be low-S");
+
+        let recovered = k256::ecdsa::VerifyingKey::recover_from_digest(
+            sha3::Keccak256::new_with_prefix(payload),
+            &signature,
+            k256::ecdsa::RecoveryId::from_byte(recovery_id).unwrap(),
+        )
+        .unwrap();
+
+        assert_eq!(
+            recovered.to_encoded_point(true).as_bytes(),
+            key().public_key().to_bytes_raw().as_slice()
+        );
+    }
+
+    #[test]
+    fn legacy_eip155_example() {
+        let builder = eip155_example();
+
+        assert_eq!(
+            builder.signing_payload(),
+            hex!("ec098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a764000080018080")
+        );
+
+        assert_eq!(
+            builder.signing_hash(),
+            hex!("daf5a779ae972f972197303d7b574746c7ef83eadac0f2791ad23db92e4c8e53")
+        );
+
+        let data = builder.sign(&key()).unwrap();
+
+        assert_eq!(
+            hex::encode(data.to_bytes()),
+            "f86c098504a817c800825208943535353535353535353535353535353535353535880de0b6b3a76400008025a028ef61340bd939bc2195fe537567866003e1a15d3c71ff63e1590620aa636276a067cbe9d8997f761aecb703304b3800ccf555c9f3dc64214b297fb1966a3b6d83"
+        );
+    }
+
+    #[test]
+    fn eip2930() {
+        let mut builder = EthereumDataBuilder::eip2930(296);
+        builder.nonce(2).gas_price(710_000_000_000).gas_limit(300_000).call_data([0x12, 0x34]);
+
+        let data = builder.sign(&key()).unwrap();
+        let data = assert_matches!(EthereumData::from_bytes(&data.to_bytes()).unwrap(), EthereumData::Eip2930(it) => it);
+
+        assert_eq!(data.chain_id, hex!("0128"));
+        assert_eq!(data.nonce, [2]);
+        assert!(data.to.is_empty());
+        assert!(data.value.is_empty());
+        assert_eq!(data.call_data, [0x12, 0x34]);
+        assert!(data.access_list.is_empty());
+
+        assert_recoverable(
+            &builder.signing_payload(),
+            &data.r,
+            &data.s,
+            data.recovery_id.first().copied().unwrap_or(0),
+        );
+    }
+
+    #[test]
+    fn eip1559() {
+        let mut builder = EthereumDataBuilder::eip1559(298);
+        builder
+            .nonce(1)
+            .max_priority_gas(0)
+            .max_gas(710_000_000_000)
+            .gas_limit(300_000)
+            .to_contract(ContractId::new(0, 0, 1001))
+            .unwrap()
+            .value(ONE_ETHER);
+
+        assert_eq!(
+            builder.get_to(),
+            Some(EvmAddress::from(hex!("00000000000000000000000000000000000003e9")))
+        );
+
+        let data = builder.sign(&key()).unwrap();
+        let data = assert_matches!(EthereumData::from_bytes(&data.to_bytes()).unwrap(), EthereumData::Eip1559(it) => it);
+
+        assert_eq!(data.chain_id, hex!("012a"));
+        assert!(data.max_priority_gas.is_empty());
+        assert_eq!(data.to, hex!("00000000000000000000000000000000000003e9"));
+        assert_eq!(data.value, hex!("0de0b6b3a7640000"));
+
+        assert_recoverable(
+            &builder.signing_payload(),
+            &data.r,
+            &data.s,
+            data.recovery_id.first().copied().unwrap_or(0),
+        );
+    }
+
+    #[test]
+    fn sign_ed25519() {
+        assert_matches!(
+            eip155_example().sign(&PrivateKey::generate_ed25519()),
+            Err(crate::Error::EthereumSign(_))
+        );
+    }
+}
@@ -45,8 +45,11 @@ use crate::{
 pub type EthereumTransaction = Transaction<EthereumTransactionData>;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct EthereumTransactionData {
     /// The raw Ethereum transaction (RLP encoded type 0, 1, and 2).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     ethereum_data: Vec<u8>,
 
     /// For large transactions (for example contract create) this should be used to
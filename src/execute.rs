@@ -18,6 +18,7 @@
  * ‍
  */
 
+use std::collections::HashMap;
 use std::error::Error as StdError;
 use std::ops::ControlFlow;
 use std::time::{
@@ -31,6 +32,7 @@ use backoff::{
 };
 use futures_core::future::BoxFuture;
 use futures_util::StreamExt;
+use parking_lot::Mutex;
 use prost::Message;
 use rand::seq::SliceRandom;
 use rand::thread_rng;
@@ -60,7 +62,7 @@ pub(crate) trait Execute: ValidateChecksums {
     /// Additional context returned from each call to `make_request`. Upon
     /// a successful request, the associated response context is passed to
     /// `make_response`.
-    type Context: Send;
+    type Context: Clone + Send;
 
     type Response;
 
@@ -98,8 +100,8 @@ pub(crate) trait Execute: ValidateChecksums {
 
     /// Create a new request for execution.
     ///
-    /// A created request is cached per node until any request returns
-    /// `TransactionExpired`; in which case, the request cache is cleared.
+    /// A created request is cached per node until the transaction ID is regenerated
+    /// (IE, when a request returns `TransactionExpired`); in which case, the request cache is cleared.
     fn make_request(
         &self,
         transaction_id: Option<&TransactionId>,
@@ -134,6 +136,43 @@ pub(crate) trait Execute: ValidateChecksums {
     fn response_pre_check_status(response: &Self::GrpcResponse) -> crate::Result<i32>;
 }
 
+/// Requests made by [`Execute::make_request`], per node.
+///
+/// A request is only valid for the transaction ID it was made with,
+/// so the whole cache is cleared whenever the transaction ID is regenerated.
+struct RequestCache<E: Execute> {
+    transaction_id: Option<TransactionId>,
+    requests: HashMap<AccountId, (E::GrpcRequest, E::Context)>,
+}
+
+impl<E: Execute> RequestCache<E> {
+    fn new() -> Self {
+        Self { transaction_id: None, requests: HashMap::new() }
+    }
+
+    fn get_or_make(
+        &mut self,
+        executable: &E,
+        transaction_id: Option<&TransactionId>,
+        node_account_id: AccountId,
+    ) -> crate::Result<(E::GrpcRequest, E::Context)> {
+        if self.transaction_id.as_ref() != transaction_id {
+            self.requests.clear();
+            self.transaction_id = transaction_id.copied();
+        }
+
+        if let Some(request) = self.requests.get(&node_account_id) {
+            return Ok(request.clone());
+        }
+
+        let request = executable.make_request(transaction_id, node_account_id)?;
+
+        self.requests.insert(node_account_id, request.clone());
+
+        Ok(request)
+    }
+}
+
 struct ExecuteContext {
     // When `Some` the `transaction_id` will be regenerated when expired.
     operator_account_id: Option<AccountId>,
@@ -222,7 +261,9 @@ where
     // the overall timeout for the backoff starts measuring from here
     let backoff = ctx.backoff_config.clone();
 
-    // TODO: cache requests to avoid signing a new request for every node in a delayed back-off
+    // requests are only made for nodes that are actually tried, and reused across retries and back-offs.
+    let request_cache = Mutex::new(RequestCache::new());
+    let request_cache = &request_cache;
 
     // if we need to generate a transaction ID for this request (and one was not provided),
     // generate one now
@@ -265,7 +306,9 @@ where
             let mut random_node_indexes = std::pin::pin!(random_node_indexes);
 
             while let Some(node_index) = random_node_indexes.next().await {
-                let tmp = execute_single(ctx, executable, node_index, &mut transaction_id).await;
+                let tmp =
+                    execute_single(ctx, executable, request_cache, node_index, &mut transaction_id)
+                        .await;
 
                 ctx.network.mark_node_used(node_index, Instant::now());
 
@@ -352,13 +395,15 @@ fn map_tonic_error(
 async fn execute_single<E: Execute + Sync>(
     ctx: &ExecuteContext,
     executable: &E,
+    request_cache: &Mutex<RequestCache<E>>,
     node_index: usize,
     transaction_id: &mut Option<TransactionId>,
 ) -> retry::Result<ControlFlow<E::Response, Error>> {
     let (node_account_id, channel) = ctx.network.channel(node_index);
 
-    let (request, context) = executable
-        .make_request(transaction_id.as_ref(), node_account_id)
+    let (request, context) = request_cache
+        .lock()
+        .get_or_make(executable, transaction_id.as_ref(), node_account_id)
         .map_err(crate::retry::Error::Permanent)?;
 
     let fut = executable.execute(channel, request);
@@ -484,3 +529,59 @@ fn random_node_indexes(
         Some(shuffled.to_vec())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{
+        AtomicUsize,
+        Ordering,
+    };
+
+    use super::RequestCache;
+    use crate::{
+        AccountId,
+        Hbar,
+        PrivateKey,
+        TransactionId,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn request_cache_signs_once_per_node_and_transaction_id() -> crate::Result<()> {
+        let signatures = std::sync::Arc::new(AtomicUsize::new(0));
+        let key = PrivateKey::generate_ed25519();
+
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(2.into(), Hbar::new(2))
+            .hbar_transfer(101.into(), Hbar::new(-2))
+            .node_account_ids([AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)])
+            .freeze()?
+            .sign_with(key.public_key(), {
+                let signatures = std::sync::Arc::clone(&signatures);
+                move |message| {
+                    signatures.fetch_add(1, Ordering::Relaxed);
+                    key.sign(message)
+                }
+            });
+
+        let mut cache = RequestCache::new();
+
+        let first_id = TransactionId::generate(101.into());
+        let second_id = TransactionId::generate(101.into());
+
+        cache.get_or_make(&tx, Some(&first_id), AccountId::new(0, 0, 3))?;
+        cache.get_or_make(&tx, Some(&first_id), AccountId::new(0, 0, 3))?;
+        assert_eq!(signatures.load(Ordering::Relaxed), 1);
+
+        cache.get_or_make(&tx, Some(&first_id), AccountId::new(0, 0, 4))?;
+        assert_eq!(signatures.load(Ordering::Relaxed), 2);
+
+        // a new transaction ID invalidates every request.
+        cache.get_or_make(&tx, Some(&second_id), AccountId::new(0, 0, 3))?;
+        cache.get_or_make(&tx, Some(&second_id), AccountId::new(0, 0, 4))?;
+        assert_eq!(signatures.load(Ordering::Relaxed), 4);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,788 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use hedera_proto::services::transaction_body::Data;
+use prost::Message;
+
+use crate::transaction::TransactionExecute;
+use crate::{
+    Client,
+    Error,
+    ExchangeRate,
+    ExchangeRates,
+    FeeComponents,
+    FeeDataType,
+    FeeSchedule,
+    FeeSchedules,
+    FileContentsQuery,
+    FileId,
+    Hbar,
+    RequestType,
+    Transaction,
+};
+
+// sizes and constants used by the network to estimate usage.
+const FEE_DIVISOR_FACTOR: u64 = 1000;
+const HRS_DIVISOR: u64 = 3600;
+const INT_SIZE: u64 = 4;
+const LONG_SIZE: u64 = 8;
+const BOOL_SIZE: u64 = 4;
+const TX_HASH_SIZE: u64 = 48;
+const BASIC_ENTITY_ID_SIZE: u64 = 3 * LONG_SIZE;
+const BASIC_TX_ID_SIZE: u64 = BASIC_ENTITY_ID_SIZE + LONG_SIZE;
+const EXCHANGE_RATE_SIZE: u64 = 2 * INT_SIZE + LONG_SIZE;
+const BASIC_RECEIPT_SIZE: u64 = INT_SIZE + 2 * EXCHANGE_RATE_SIZE;
+const BASIC_ACCOUNT_SIZE: u64 = 8 * LONG_SIZE + BOOL_SIZE;
+const BASIC_ACCOUNT_AMT_SIZE: u64 = BASIC_ENTITY_ID_SIZE + LONG_SIZE;
+const BASIC_TX_RECORD_SIZE: u64 =
+    BASIC_RECEIPT_SIZE + TX_HASH_SIZE + LONG_SIZE + BASIC_TX_ID_SIZE + LONG_SIZE;
+const RECEIPT_STORAGE_TIME_SEC: u64 = 180;
+const RUNNING_HASH_SIZE: u64 = 48;
+const DEFAULT_AUTO_RENEW_PERIOD_SECS: u64 = 7_776_000;
+
+impl FeeSchedules {
+    /// Fetch the current and next fee schedules from the network (file `0.0.111`).
+    ///
+    /// # Errors
+    /// - If the [`FileContentsQuery`] fails.
+    /// - [`Error::FromProtobuf`] if the file's contents aren't valid fee schedules.
+    pub async fn fetch(client: &Client) -> crate::Result<Self> {
+        let response =
+            FileContentsQuery::new().file_id(FileId::FEE_SCHEDULE).execute(client).await?;
+
+        Self::from_bytes(&response.contents)
+    }
+}
+
+impl ExchangeRates {
+    /// Fetch the current and next exchange rates from the network (file `0.0.112`).
+    ///
+    /// # Errors
+    /// - If the [`FileContentsQuery`] fails.
+    /// - [`Error::FromProtobuf`] if the file's contents aren't valid exchange rates.
+    pub async fn fetch(client: &Client) -> crate::Result<Self> {
+        let response =
+            FileContentsQuery::new().file_id(FileId::EXCHANGE_RATES).execute(client).await?;
+
+        Self::from_bytes(&response.contents)
+    }
+}
+
+/// The fees a transaction is expected to be charged, see [`FeeEstimator`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    /// The fee paid to the node that submits the transaction.
+    pub node: Hbar,
+
+    /// The fee for gossip, consensus, and receipt storage.
+    pub network: Hbar,
+
+    /// The fee for the work done (and state used) by the transaction itself.
+    pub service: Hbar,
+}
+
+impl FeeEstimate {
+    /// Returns the total fee, which is what the payer is charged.
+    #[must_use]
+    pub fn total(&self) -> Hbar {
+        self.node + self.network + self.service
+    }
+}
+
+/// Sets the max transaction fee of transactions from an estimate of their fee.
+///
+/// See [`Client::set_max_transaction_fee_policy`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MaxTransactionFeePolicy {
+    multiplier: f64,
+    cap: Hbar,
+}
+
+impl MaxTransactionFeePolicy {
+    /// Create a new `MaxTransactionFeePolicy`.
+    ///
+    /// The max transaction fee is set to the estimated fee times `multiplier`, but never more than `cap`.
+    ///
+    /// # Panics
+    /// - If `multiplier` isn't a finite, positive number.
+    /// - If `cap` is negative.
+    #[must_use]
+    pub fn new(multiplier: f64, cap: Hbar) -> Self {
+        assert!(multiplier.is_finite() && multiplier > 0.0, "invalid multiplier: {multiplier}");
+        assert!(cap >= Hbar::ZERO);
+
+        Self { multiplier, cap }
+    }
+
+    /// Returns the amount estimated fees are multiplied by.
+    #[must_use]
+    pub fn multiplier(&self) -> f64 {
+        self.multiplier
+    }
+
+    /// Returns the highest max transaction fee this policy will set.
+    #[must_use]
+    pub fn cap(&self) -> Hbar {
+        self.cap
+    }
+
+    /// Returns the max transaction fee this policy sets for a transaction with the given `estimate`.
+    #[must_use]
+    pub fn max_transaction_fee(&self, estimate: &FeeEstimate) -> Hbar {
+        let tinybars = (estimate.total().to_tinybars() as f64 * self.multiplier).ceil() as i64;
+
+        Hbar::from_tinybars(tinybars).min(self.cap)
+    }
+}
+
+/// Estimates transaction fees offline, from a [`FeeSchedule`] and an [`ExchangeRate`].
+///
+/// Estimates follow the network's resource based pricing: the size of the transaction,
+/// the number of signatures, the memo, and storage used by created entities for their lifetime.
+///
+/// The network also charges for things that can't be known offline,
+/// such as congestion pricing, custom fees and automatic token associations,
+/// so an estimate is a quote and not a guarantee.
+#[derive(Debug, Clone)]
+pub struct FeeEstimator {
+    fee_schedule: FeeSchedule,
+    exchange_rate: ExchangeRate,
+}
+
+impl FeeEstimator {
+    /// Create a new `FeeEstimator` that uses the given fee schedule and exchange rate.
+    #[must_use]
+    pub fn new(fee_schedule: FeeSchedule, exchange_rate: ExchangeRate) -> Self {
+        Self { fee_schedule, exchange_rate }
+    }
+
+    /// Create a new `FeeEstimator` with the network's current fee schedule and exchange rate.
+    ///
+    /// # Errors
+    /// - If either [`FeeSchedules::fetch`] or [`ExchangeRates::fetch`] fail.
+    /// - [`Error::FromProtobuf`] if the network has no current fee schedule.
+    pub async fn fetch(client: &Client) -> crate::Result<Self> {
+        let fee_schedule = FeeSchedules::fetch(client)
+            .await?
+            .current
+            .ok_or_else(|| Error::from_protobuf("network has no current fee schedule"))?;
+
+        let exchange_rate = ExchangeRates::fetch(client).await?.current_rate;
+
+        Ok(Self::new(fee_schedule, exchange_rate))
+    }
+
+    /// Returns the fee schedule used for estimates.
+    #[must_use]
+    pub fn fee_schedule(&self) -> &FeeSchedule {
+        &self.fee_schedule
+    }
+
+    /// Returns the exchange rate used for estimates.
+    #[must_use]
+    pub fn exchange_rate(&self) -> &ExchangeRate {
+        &self.exchange_rate
+    }
+
+    /// Estimate the fees `transaction` would be charged.
+    ///
+    /// The transaction may be frozen or not, anything that hasn't been set yet is assumed to be of a typical size.
+    /// Signatures are counted from the transaction's signers, plus the payer's if it isn't known.
+    ///
+    /// The [`FeeDataType`] is picked from the transaction itself,
+    /// use [`estimate_with_fee_data_type`](Self::estimate_with_fee_data_type) when it depends on the state of the network
+    /// (such as a transfer of a token with custom fees).
+    ///
+    /// For chunked transactions the estimate covers every chunk.
+    ///
+    /// # Errors
+    /// - [`Error::FeeScheduleMissing`] if the fee schedule has no prices for the transaction.
+    pub fn estimate<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<FeeEstimate> {
+        self.estimate_inner(transaction, None, true)
+    }
+
+    /// Estimate the fees `transaction` would be charged, using the prices for `fee_data_type`.
+    ///
+    /// See [`estimate`](Self::estimate).
+    ///
+    /// # Errors
+    /// - [`Error::FeeScheduleMissing`] if the fee schedule has no prices for the transaction.
+    pub fn estimate_with_fee_data_type<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+        fee_data_type: FeeDataType,
+    ) -> crate::Result<FeeEstimate> {
+        self.estimate_inner(transaction, Some(fee_data_type), true)
+    }
+
+    /// Estimate the fees a single chunk of `transaction` would be charged.
+    ///
+    /// This is what a max transaction fee applies to, since every chunk is its own transaction.
+    pub(crate) fn estimate_chunk<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+    ) -> crate::Result<FeeEstimate> {
+        self.estimate_inner(transaction, None, false)
+    }
+
+    fn estimate_inner<D: TransactionExecute>(
+        &self,
+        transaction: &Transaction<D>,
+        fee_data_type: Option<FeeDataType>,
+        all_chunks: bool,
+    ) -> crate::Result<FeeEstimate> {
+        let signed_transaction = transaction.estimate_signed_transaction()?;
+
+        // note: the body is always valid, it was either created by us or has already been parsed.
+        let body = services::TransactionBody::decode(&*signed_transaction.body_bytes)
+            .map_err(Error::from_protobuf)?;
+
+        let data = body.data.as_ref().ok_or_else(|| Error::from_protobuf("missing `data`"))?;
+
+        let request_type = request_type(data);
+        let fee_data_type = fee_data_type.unwrap_or_else(|| fee_data_type_of(data));
+
+        let prices = self
+            .fee_schedule
+            .transaction_fee_schedules
+            .iter()
+            .find(|it| it.request_type == request_type)
+            .and_then(|it| {
+                let fees = &it.fees;
+                fees.iter()
+                    .find(|it| it.kind == fee_data_type)
+                    .or_else(|| fees.iter().find(|it| it.kind == FeeDataType::Default))
+            })
+            .ok_or(Error::FeeScheduleMissing(request_type))?;
+
+        let signatures = signed_transaction.sig_map.as_ref().map_or(0, |it| it.sig_pair.len());
+        let usage =
+            Usage::new(&body, data, signed_transaction.encoded_len() as u64, signatures as u64);
+
+        let chunks = if all_chunks {
+            transaction.data().maybe_chunk_data().map_or(1, |it| it.used_chunks()) as u64
+        } else {
+            1
+        };
+
+        let fee = |prices: &FeeComponents, usage: &ComponentUsage| {
+            let tinycents = component_fee(prices, usage);
+            let tinybars = u128::from(tinycents) * u128::from(self.exchange_rate.hbars)
+                / u128::from(self.exchange_rate.cents.max(1));
+
+            Hbar::from_tinybars(
+                i64::try_from(tinybars.saturating_mul(u128::from(chunks))).unwrap_or(i64::MAX),
+            )
+        };
+
+        Ok(FeeEstimate {
+            node: fee(&prices.node, &usage.node),
+            network: fee(&prices.network, &usage.network),
+            service: fee(&prices.service, &usage.service),
+        })
+    }
+}
+
+/// Resources used by a transaction for a single [`FeeComponents`].
+#[derive(Default)]
+struct ComponentUsage {
+    bandwidth_bytes: u64,
+    verifications: u64,
+    ram_byte_hours: u64,
+    storage_byte_hours: u64,
+    gas: u64,
+    response_memory_bytes: u64,
+}
+
+struct Usage {
+    node: ComponentUsage,
+    network: ComponentUsage,
+    service: ComponentUsage,
+}
+
+impl Usage {
+    fn new(body: &services::TransactionBody, data: &Data, bytes: u64, signatures: u64) -> Self {
+        let memo_bytes = body.memo.len() as u64;
+
+        let transfers = match data {
+            Data::CryptoTransfer(it) => {
+                let hbar = it.transfers.as_ref().map_or(0, |it| it.account_amounts.len());
+                let token: usize = it
+                    .token_transfers
+                    .iter()
+                    .map(|it| it.transfers.len() + it.nft_transfers.len())
+                    .sum();
+
+                (hbar + token) as u64
+            }
+            _ => 0,
+        };
+
+        let record_bytes = BASIC_TX_RECORD_SIZE + memo_bytes + transfers * BASIC_ACCOUNT_AMT_SIZE;
+        let (entity_ram_byte_seconds, entity_storage_byte_seconds) = entity_usage(body, data);
+
+        Self {
+            node: ComponentUsage {
+                bandwidth_bytes: bytes,
+                // nodes only verify the payer's signature.
+                verifications: 1,
+                response_memory_bytes: INT_SIZE,
+                ..ComponentUsage::default()
+            },
+            network: ComponentUsage {
+                bandwidth_bytes: bytes,
+                verifications: signatures,
+                ram_byte_hours: non_degenerate_div(
+                    BASIC_RECEIPT_SIZE * RECEIPT_STORAGE_TIME_SEC,
+                    HRS_DIVISOR,
+                ),
+                ..ComponentUsage::default()
+            },
+            service: ComponentUsage {
+                ram_byte_hours: non_degenerate_div(
+                    (record_bytes * RECEIPT_STORAGE_TIME_SEC)
+                        .saturating_add(entity_ram_byte_seconds),
+                    HRS_DIVISOR,
+                ),
+                storage_byte_hours: non_degenerate_div(entity_storage_byte_seconds, HRS_DIVISOR),
+                gas: gas(data),
+                ..ComponentUsage::default()
+            },
+        }
+    }
+}
+
+fn non_degenerate_div(dividend: u64, divisor: u64) -> u64 {
+    match dividend {
+        0 => 0,
+        _ => (dividend / divisor).max(1),
+    }
+}
+
+fn component_fee(prices: &FeeComponents, usage: &ComponentUsage) -> u64 {
+    let total = [
+        (prices.constant, 1),
+        (prices.bandwidth_byte, usage.bandwidth_bytes),
+        (prices.verification, usage.verifications),
+        (prices.ram_byte_hour, usage.ram_byte_hours),
+        (prices.storage_byte_hour, usage.storage_byte_hours),
+        (prices.contract_transaction_gas, usage.gas),
+        (prices.response_memory_byte, usage.response_memory_bytes),
+    ]
+    .into_iter()
+    .fold(0_u64, |total, (price, usage)| total.saturating_add(price.saturating_mul(usage)));
+
+    // the network clamps like this rather than with `clamp`, which matters when `min > max`.
+    let total = if total < prices.min {
+        prices.min
+    } else if total > prices.max {
+        prices.max
+    } else {
+        total
+    };
+
+    (total / FEE_DIVISOR_FACTOR).max(u64::from(total > 0))
+}
+
+fn key_bytes(key: Option<&services::Key>) -> u64 {
+    key.map_or(0, |it| it.encoded_len() as u64)
+}
+
+fn seconds(duration: Option<&services::Duration>) -> Option<u64> {
+    duration.and_then(|it| u64::try_from(it.seconds).ok())
+}
+
+/// Returns the `(ram, storage)` byte-seconds used by entities that `data` creates (or grows).
+fn entity_usage(body: &services::TransactionBody, data: &Data) -> (u64, u64) {
+    let auto_renew_period = |period: Option<&services::Duration>| {
+        seconds(period).unwrap_or(DEFAULT_AUTO_RENEW_PERIOD_SECS)
+    };
+
+    match data {
+        Data::CryptoCreateAccount(it) => {
+            let bytes = BASIC_ACCOUNT_SIZE + key_bytes(it.key.as_ref()) + it.memo.len() as u64;
+
+            (bytes.saturating_mul(auto_renew_period(it.auto_renew_period.as_ref())), 0)
+        }
+
+        Data::ConsensusCreateTopic(it) => {
+            let bytes = BASIC_ENTITY_ID_SIZE
+                + RUNNING_HASH_SIZE
+                + LONG_SIZE
+                + key_bytes(it.admin_key.as_ref())
+                + key_bytes(it.submit_key.as_ref())
+                + it.memo.len() as u64;
+
+            (bytes.saturating_mul(auto_renew_period(it.auto_renew_period.as_ref())), 0)
+        }
+
+        Data::TokenCreation(it) => {
+            let keys = [
+                &it.admin_key,
+                &it.kyc_key,
+                &it.freeze_key,
+                &it.wipe_key,
+                &it.supply_key,
+                &it.fee_schedule_key,
+                &it.pause_key,
+            ]
+            .into_iter()
+            .map(|it| key_bytes(it.as_ref()))
+            .sum::<u64>();
+
+            let bytes = BASIC_ENTITY_ID_SIZE
+                + keys
+                + (it.name.len() + it.symbol.len() + it.memo.len()) as u64
+                + it.custom_fees.iter().map(|it| it.encoded_len() as u64).sum::<u64>();
+
+            (bytes.saturating_mul(auto_renew_period(it.auto_renew_period.as_ref())), 0)
+        }
+
+        Data::ContractCreateInstance(it) => {
+            let bytes =
+                BASIC_ENTITY_ID_SIZE + key_bytes(it.admin_key.as_ref()) + it.memo.len() as u64;
+
+            (bytes.saturating_mul(auto_renew_period(it.auto_renew_period.as_ref())), 0)
+        }
+
+        Data::FileCreate(it) => {
+            let bytes = BASIC_ENTITY_ID_SIZE
+                + it.contents.len() as u64
+                + it.memo.len() as u64
+                + it.keys.as_ref().map_or(0, |it| it.encoded_len() as u64);
+
+            (0, bytes.saturating_mul(file_lifetime(body, it.expiration_time.as_ref())))
+        }
+
+        Data::FileUpdate(it) => {
+            // only new contents are charged, the rest of the file is already paid for.
+            (
+                0,
+                (it.contents.len() as u64)
+                    .saturating_mul(file_lifetime(body, it.expiration_time.as_ref())),
+            )
+        }
+
+        _ => (0, 0),
+    }
+}
+
+/// Returns how long a file will exist for, in seconds.
+fn file_lifetime(
+    body: &services::TransactionBody,
+    expiration_time: Option<&services::Timestamp>,
+) -> u64 {
+    let valid_start = body
+        .transaction_id
+        .as_ref()
+        .and_then(|it| it.transaction_valid_start.as_ref())
+        .map_or(0, |it| it.seconds);
+
+    expiration_time
+        .and_then(|it| u64::try_from(it.seconds.saturating_sub(valid_start)).ok())
+        .unwrap_or(DEFAULT_AUTO_RENEW_PERIOD_SECS)
+}
+
+fn gas(data: &Data) -> u64 {
+    let gas = match data {
+        Data::ContractCall(it) => it.gas,
+        Data::ContractCreateInstance(it) => it.gas,
+        _ => 0,
+    };
+
+    u64::try_from(gas).unwrap_or_default()
+}
+
+fn fee_data_type_of(data: &Data) -> FeeDataType {
+    match data {
+        Data::TokenCreation(it) => {
+            let non_fungible = it.token_type == services::TokenType::NonFungibleUnique as i32;
+
+            match (non_fungible, it.custom_fees.is_empty()) {
+                (false, true) => FeeDataType::TokenFungibleCommon,
+                (false, false) => FeeDataType::TokenFungibleCommonWithCustomFees,
+                (true, true) => FeeDataType::TokenNonFungibleUnique,
+                (true, false) => FeeDataType::TokenNonFungibleUniqueWithCustomFees,
+            }
+        }
+
+        Data::TokenMint(it) if !it.metadata.is_empty() => FeeDataType::TokenNonFungibleUnique,
+        Data::TokenBurn(it) if !it.serial_numbers.is_empty() => FeeDataType::TokenNonFungibleUnique,
+        Data::TokenWipe(it) if !it.serial_numbers.is_empty() => FeeDataType::TokenNonFungibleUnique,
+        Data::TokenMint(_) | Data::TokenBurn(_) | Data::TokenWipe(_) => {
+            FeeDataType::TokenFungibleCommon
+        }
+
+        Data::CryptoTransfer(it)
+            if it.token_transfers.iter().any(|it| !it.nft_transfers.is_empty()) =>
+        {
+            FeeDataType::TokenNonFungibleUnique
+        }
+        Data::CryptoTransfer(it) if !it.token_transfers.is_empty() => {
+            FeeDataType::TokenFungibleCommon
+        }
+
+        Data::ScheduleCreate(it)
+            if matches!(
+                it.scheduled_transaction_body.as_ref().and_then(|it| it.data.as_ref()),
+                Some(services::schedulable_transaction_body::Data::ContractCall(_))
+            ) =>
+        {
+            FeeDataType::ScheduleCreateContractCall
+        }
+
+        _ => FeeDataType::Default,
+    }
+}
+
+pub(crate) fn request_type(data: &Data) -> RequestType {
+    match data {
+        Data::ConsensusCreateTopic(_) => RequestType::ConsensusCreateTopic,
+        Data::ConsensusDeleteTopic(_) => RequestType::ConsensusDeleteTopic,
+        Data::ConsensusSubmitMessage(_) => RequestType::ConsensusSubmitMessage,
+        Data::ConsensusUpdateTopic(_) => RequestType::ConsensusUpdateTopic,
+        Data::ContractCall(_) => RequestType::ContractCall,
+        Data::ContractCreateInstance(_) => RequestType::ContractCreate,
+        Data::ContractDeleteInstance(_) => RequestType::ContractDelete,
+        Data::ContractUpdateInstance(_) => RequestType::ContractUpdate,
+        Data::CryptoApproveAllowance(_) => RequestType::CryptoApproveAllowance,
+        Data::CryptoCreateAccount(_) => RequestType::CryptoCreate,
+        Data::CryptoDelete(_) => RequestType::CryptoDelete,
+        Data::CryptoDeleteAllowance(_) => RequestType::CryptoDeleteAllowance,
+        Data::CryptoTransfer(_) => RequestType::CryptoTransfer,
+        Data::CryptoUpdateAccount(_) => RequestType::CryptoUpdate,
+        Data::EthereumTransaction(_) => RequestType::EthereumTransaction,
+        Data::FileAppend(_) => RequestType::FileAppend,
+        Data::FileCreate(_) => RequestType::FileCreate,
+        Data::FileDelete(_) => RequestType::FileDelete,
+        Data::FileUpdate(_) => RequestType::FileUpdate,
+        Data::Freeze(_) => RequestType::Freeze,
+        Data::ScheduleCreate(_) => RequestType::ScheduleCreate,
+        Data::ScheduleDelete(_) => RequestType::ScheduleDelete,
+        Data::ScheduleSign(_) => RequestType::ScheduleSign,
+        Data::SystemDelete(_) => RequestType::SystemDelete,
+        Data::SystemUndelete(_) => RequestType::SystemUndelete,
+        Data::TokenAssociate(_) => RequestType::TokenAssociateToAccount,
+        Data::TokenBurn(_) => RequestType::TokenBurn,
+        Data::TokenCreation(_) => RequestType::TokenCreate,
+        Data::TokenDeletion(_) => RequestType::TokenDelete,
+        Data::TokenDissociate(_) => RequestType::TokenDissociateFromAccount,
+        Data::TokenFeeScheduleUpdate(_) => RequestType::TokenFeeScheduleUpdate,
+        Data::TokenFreeze(_) => RequestType::TokenFreezeAccount,
+        Data::TokenGrantKyc(_) => RequestType::TokenGrantKycToAccount,
+        Data::TokenMint(_) => RequestType::TokenMint,
+        Data::TokenPause(_) => RequestType::TokenPause,
+        Data::TokenRevokeKyc(_) => RequestType::TokenRevokeKycFromAccount,
+        Data::TokenUnfreeze(_) => RequestType::TokenUnfreezeAccount,
+        Data::TokenUnpause(_) => RequestType::TokenUnpause,
+        Data::TokenUpdate(_) => RequestType::TokenUpdate,
+        Data::TokenWipe(_) => RequestType::TokenAccountWipe,
+        Data::UtilPrng(_) => RequestType::UtilPrng,
+        _ => RequestType::None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use super::{
+        FeeEstimator,
+        MaxTransactionFeePolicy,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        ExchangeRate,
+        FeeComponents,
+        FeeData,
+        FeeDataType,
+        FeeSchedule,
+        Hbar,
+        RequestType,
+        TokenBurnTransaction,
+        TokenId,
+        TokenMintTransaction,
+        TopicCreateTransaction,
+        TopicMessageSubmitTransaction,
+        TransactionFeeSchedule,
+        TransferTransaction,
+    };
+
+    fn prices(constant: u64, verification: u64) -> FeeComponents {
+        FeeComponents {
+            min: 0,
+            max: 1_000_000_000_000_000,
+            constant,
+            bandwidth_byte: 0,
+            verification,
+            storage_byte_hour: 0,
+            ram_byte_hour: 0,
+            contract_transaction_gas: 0,
+            transfer_volume_hbar: 0,
+            response_memory_byte: 0,
+            response_disk_byte: 0,
+        }
+    }
+
+    // all prices are in thousandths of a tinycent, with 12 tinycents to the tinybar.
+    fn fee_data(kind: FeeDataType, service_constant: u64) -> FeeData {
+        FeeData {
+            node: prices(12_000_000_000, 0),
+            network: prices(0, 6_000_000_000),
+            service: prices(service_constant, 0),
+            kind,
+        }
+    }
+
+    fn estimator() -> FeeEstimator {
+        #[allow(deprecated)]
+        let schedule =
+            |request_type, fees| TransactionFeeSchedule { request_type, fee_data: None, fees };
+
+        FeeEstimator::new(
+            FeeSchedule {
+                transaction_fee_schedules: Vec::from([
+                    schedule(
+                        RequestType::CryptoTransfer,
+                        Vec::from([fee_data(FeeDataType::Default, 24_000_000_000)]),
+                    ),
+                    schedule(
+                        RequestType::TokenMint,
+                        Vec::from([
+                            fee_data(FeeDataType::Default, 12_000_000_000),
+                            fee_data(FeeDataType::TokenNonFungibleUnique, 240_000_000_000),
+                        ]),
+                    ),
+                    schedule(
+                        RequestType::ConsensusCreateTopic,
+                        Vec::from([fee_data(FeeDataType::Default, 0)]),
+                    ),
+                    schedule(
+                        RequestType::ConsensusSubmitMessage,
+                        Vec::from([fee_data(FeeDataType::Default, 0)]),
+                    ),
+                ]),
+                expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+            },
+            ExchangeRate {
+                hbars: 1,
+                cents: 12,
+                expiration_time: OffsetDateTime::from_unix_timestamp(1554158542).unwrap(),
+            },
+        )
+    }
+
+    #[test]
+    fn transfer() {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::from_tinybars(-1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::from_tinybars(1));
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(1_000_000));
+        // one signature from the signer and a placeholder for the payer.
+        assert_eq!(estimate.network, Hbar::from_tinybars(1_000_000));
+        assert_eq!(estimate.service, Hbar::from_tinybars(2_000_000));
+        assert_eq!(estimate.total(), Hbar::from_tinybars(4_000_000));
+    }
+
+    #[test]
+    fn fee_data_type() {
+        let estimator = estimator();
+
+        let mut tx = TokenMintTransaction::new_for_tests();
+        tx.token_id(TokenId::new(0, 0, 5)).metadata([vec![1]]);
+
+        let estimate = estimator.estimate(&tx).unwrap();
+        assert_eq!(estimate.service, Hbar::from_tinybars(20_000_000));
+
+        let estimate = estimator.estimate_with_fee_data_type(&tx, FeeDataType::Default).unwrap();
+        assert_eq!(estimate.service, Hbar::from_tinybars(1_000_000));
+
+        // there are no prices for fungible mints, so the default prices are used.
+        let mut tx = TokenMintTransaction::new_for_tests();
+        tx.token_id(TokenId::new(0, 0, 5)).amount(10);
+
+        let estimate = estimator.estimate(&tx).unwrap();
+        assert_eq!(estimate.service, Hbar::from_tinybars(1_000_000));
+    }
+
+    #[test]
+    fn chunked() {
+        let mut tx = TopicMessageSubmitTransaction::new_for_tests();
+        tx.message([0; 2500]);
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(3_000_000));
+
+        let estimate = estimator().estimate_chunk(&tx).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(1_000_000));
+    }
+
+    #[test]
+    fn huge_auto_renew_period() {
+        let mut tx = TopicCreateTransaction::new_for_tests();
+        tx.auto_renew_period(Duration::seconds(i64::MAX));
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        assert_eq!(estimate.node, Hbar::from_tinybars(1_000_000));
+    }
+
+    #[test]
+    fn policy() {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::from_tinybars(-1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::from_tinybars(1));
+
+        let estimate = estimator().estimate(&tx).unwrap();
+
+        let policy = MaxTransactionFeePolicy::new(1.5, Hbar::new(1));
+        assert_eq!(policy.max_transaction_fee(&estimate), Hbar::from_tinybars(6_000_000));
+
+        let policy = MaxTransactionFeePolicy::new(1.5, Hbar::from_tinybars(5_000_000));
+        assert_eq!(policy.max_transaction_fee(&estimate), Hbar::from_tinybars(5_000_000));
+    }
+
+    #[test]
+    fn missing_request_type() {
+        let mut tx = TokenBurnTransaction::new_for_tests();
+        tx.token_id(TokenId::new(0, 0, 5)).amount(10_u64);
+
+        assert_matches!(
+            estimator().estimate(&tx),
+            Err(Error::FeeScheduleMissing(RequestType::TokenBurn))
+        );
+    }
+}
@@ -54,6 +54,8 @@ use crate::{
 pub type FileAppendTransaction = Transaction<FileAppendTransactionData>;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileAppendTransactionData {
     /// The file to which the bytes will be appended.
     file_id: Option<FileId>,
@@ -106,6 +108,10 @@ impl TransactionData for FileAppendTransactionData {
         Some(self.chunk_data())
     }
 
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut ChunkData> {
+        Some(self.chunk_data_mut())
+    }
+
     fn wait_for_receipt(&self) -> bool {
         true
     }
@@ -203,6 +209,7 @@ impl FromProtobuf<Vec<services::FileAppendTransactionBody>> for FileAppendTransa
                 max_chunks: total_chunks,
                 chunk_size: NonZeroUsize::new(largest_chunk_size)
                     .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
+                auto_chunk_size_signers: None,
                 data: contents,
             },
         })
@@ -27,12 +27,15 @@ use crate::{
 
 /// Response from [`FileContentsQuery`][crate::FileContentsQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileContentsResponse {
     /// The file ID of the file whose contents are being returned.
     pub file_id: FileId,
 
     // TODO: .contents vs .bytes (?)
     /// The bytes contained in the file.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub contents: Vec<u8>,
 }
 
@@ -39,6 +39,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -52,6 +53,8 @@ use crate::{
 pub type FileCreateTransaction = Transaction<FileCreateTransactionData>;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileCreateTransactionData {
     /// The memo associated with the file.
     file_memo: String,
@@ -62,6 +65,7 @@ pub struct FileCreateTransactionData {
     keys: Option<KeyList>,
 
     /// The bytes that are to be the contents of the file.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_opt"))]
     contents: Option<Vec<u8>>,
 
     auto_renew_period: Option<Duration>,
@@ -182,6 +186,10 @@ impl TransactionData for FileCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::new(5)
     }
+
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.memo("file memo", &self.file_memo);
+    }
 }
 
 impl TransactionExecute for FileCreateTransactionData {
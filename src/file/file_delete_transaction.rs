@@ -51,6 +51,8 @@ use crate::{
 pub type FileDeleteTransaction = Transaction<FileDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileDeleteTransactionData {
     /// The file to delete. It will be marked as deleted until it expires.
     /// Then it will disappear.
@@ -35,6 +35,8 @@ use crate::{
 
 /// Response from [`FileInfoQuery`][crate::FileInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileInfo {
     /// The file ID of the file for which information is requested.
     pub file_id: FileId,
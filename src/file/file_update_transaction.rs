@@ -38,6 +38,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -58,6 +59,8 @@ use crate::{
 pub type FileUpdateTransaction = Transaction<FileUpdateTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FileUpdateTransactionData {
     /// The file ID which is being updated in this transaction.
     file_id: Option<FileId>,
@@ -71,6 +74,7 @@ pub struct FileUpdateTransactionData {
     keys: Option<KeyList>,
 
     /// The bytes that are to be the contents of the file.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_opt"))]
     contents: Option<Vec<u8>>,
 
     /// The time at which this file should expire.
@@ -185,7 +189,13 @@ impl FileUpdateTransaction {
     }
 }
 
-impl TransactionData for FileUpdateTransactionData {}
+impl TransactionData for FileUpdateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        if let Some(memo) = &self.file_memo {
+            violations.memo("file memo", memo);
+        }
+    }
+}
 
 impl TransactionExecute for FileUpdateTransactionData {
     fn execute(
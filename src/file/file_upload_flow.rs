@@ -0,0 +1,567 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::VecDeque;
+
+use sha2::{
+    Digest,
+    Sha384,
+};
+use time::OffsetDateTime;
+
+use crate::execute::execute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    FileAppendTransaction,
+    FileContentsQuery,
+    FileCreateTransaction,
+    FileId,
+    FileUpdateTransaction,
+    Key,
+    TransactionResponse,
+};
+
+/// Upload the contents of a file of any size.
+///
+/// The operation of this flow is as follows:
+/// 1. Create a file with the first chunk of the contents (via a [`FileCreateTransaction`]),
+///    or, if a file ID is set, replace the contents of that file with the first chunk (via a [`FileUpdateTransaction`]).
+/// 2. Append the remaining chunks, one [`FileAppendTransaction`] per chunk.
+/// 3. Verify the upload by comparing the SHA-384 hash of the file's contents (via a [`FileContentsQuery`])
+///    with the hash of the contents given to this flow.
+#[derive(Debug)]
+pub struct FileUploadFlow {
+    contents: Vec<u8>,
+    file_id: Option<FileId>,
+    keys: Option<Vec<Key>>,
+    file_memo: Option<String>,
+    expiration_time: Option<OffsetDateTime>,
+    chunk_size: usize,
+    max_in_flight: usize,
+    node_account_ids: Option<Vec<AccountId>>,
+}
+
+impl Default for FileUploadFlow {
+    fn default() -> Self {
+        Self {
+            contents: Vec::new(),
+            file_id: None,
+            keys: None,
+            file_memo: None,
+            expiration_time: None,
+            chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            max_in_flight: 1,
+            node_account_ids: None,
+        }
+    }
+}
+
+impl FileUploadFlow {
+    const DEFAULT_CHUNK_SIZE: usize = 4096;
+
+    /// Create a new `FileUploadFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the contents to upload.
+    #[must_use]
+    pub fn get_contents(&self) -> &[u8] {
+        &self.contents
+    }
+
+    /// Sets the contents to upload.
+    pub fn contents(&mut self, contents: impl Into<Vec<u8>>) -> &mut Self {
+        self.contents = contents.into();
+
+        self
+    }
+
+    /// Returns the existing file to replace the contents of, if any.
+    #[must_use]
+    pub fn get_file_id(&self) -> Option<FileId> {
+        self.file_id
+    }
+
+    /// Sets an existing file to replace the contents of, instead of creating a new file.
+    pub fn file_id(&mut self, file_id: impl Into<FileId>) -> &mut Self {
+        self.file_id = Some(file_id.into());
+
+        self
+    }
+
+    /// Returns the keys for the file.
+    #[must_use]
+    pub fn get_keys(&self) -> Option<&[Key]> {
+        self.keys.as_deref()
+    }
+
+    /// Sets the keys for the file.
+    ///
+    /// When creating a file, this defaults to the client's operator key.
+    /// When updating a file, the file's keys are left as is unless this is set.
+    pub fn keys<K: Into<Key>>(&mut self, keys: impl IntoIterator<Item = K>) -> &mut Self {
+        self.keys = Some(keys.into_iter().map(Into::into).collect());
+
+        self
+    }
+
+    /// Returns the memo for the file.
+    #[must_use]
+    pub fn get_file_memo(&self) -> Option<&str> {
+        self.file_memo.as_deref()
+    }
+
+    /// Sets the memo for the file.
+    pub fn file_memo(&mut self, file_memo: impl Into<String>) -> &mut Self {
+        self.file_memo = Some(file_memo.into());
+
+        self
+    }
+
+    /// Returns the time at which the file will expire.
+    #[must_use]
+    pub fn get_expiration_time(&self) -> Option<OffsetDateTime> {
+        self.expiration_time
+    }
+
+    /// Sets the time at which the file will expire.
+    pub fn expiration_time(&mut self, at: OffsetDateTime) -> &mut Self {
+        self.expiration_time = Some(at);
+
+        self
+    }
+
+    /// Returns the size of each chunk of the contents.
+    #[must_use]
+    pub fn get_chunk_size(&self) -> usize {
+        self.chunk_size
+    }
+
+    /// Sets the size of each chunk of the contents.
+    ///
+    /// Defaults to 4096 bytes.
+    ///
+    /// # Panics
+    /// If `size` == 0
+    pub fn chunk_size(&mut self, size: usize) -> &mut Self {
+        assert!(size > 0, "Cannot set chunk-size to zero");
+
+        self.chunk_size = size;
+
+        self
+    }
+
+    /// Returns the maximum number of appends that may be waiting on their receipt at once.
+    #[must_use]
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Sets the maximum number of appends that may be waiting on their receipt at once.
+    ///
+    /// Defaults to 1, which submits every append only after the previous one has reached consensus.
+    ///
+    /// Appends reach consensus in the order they're submitted only when they're submitted to the same node,
+    /// so with more than one in flight every append is submitted to a single node
+    /// (the first of [`node_account_ids`](Self::node_account_ids) if set, otherwise the node that created or updated the file).
+    /// Either way, the upload is verified once it's done.
+    ///
+    /// # Panics
+    /// If `max_in_flight` == 0
+    pub fn max_in_flight(&mut self, max_in_flight: usize) -> &mut Self {
+        assert!(max_in_flight > 0, "Cannot set max-in-flight to zero");
+
+        self.max_in_flight = max_in_flight;
+
+        self
+    }
+
+    /// Returns the account IDs of the nodes the transactions may be submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the account IDs of the nodes the transactions may be submitted to.
+    ///
+    /// Defaults to the full list of nodes configured on the client.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Generates the required transactions and executes them all, returning the ID of the uploaded file.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if a file is created without keys and the client has no operator.
+    /// - [`Error::FileUploadVerification`] if the file's contents don't match once everything is uploaded.
+    pub async fn execute(&self, client: &Client) -> crate::Result<FileId> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Generates the required transactions and executes them all, returning the ID of the uploaded file.
+    ///
+    /// # Errors
+    /// - [`Error::NoPayerAccountOrTransactionId`] if a file is created without keys and the client has no operator.
+    /// - [`Error::FileUploadVerification`] if the file's contents don't match once everything is uploaded.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<FileId> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<FileId> {
+        let (first_chunk, chunks) = self.split_contents();
+
+        let (file_id, node_account_id) = match self.file_id {
+            Some(file_id) => {
+                let response = self
+                    .make_file_update_transaction(file_id, first_chunk.to_vec())
+                    .execute_with_optional_timeout(client, timeout_per_transaction)
+                    .await?;
+
+                response
+                    .get_receipt_query()
+                    .execute_with_optional_timeout(client, timeout_per_transaction)
+                    .await?;
+
+                (file_id, response.node_account_id)
+            }
+
+            None => {
+                let response = self
+                    .make_file_create_transaction(client, first_chunk.to_vec())?
+                    .execute_with_optional_timeout(client, timeout_per_transaction)
+                    .await?;
+
+                let file_id = response
+                    .get_receipt_query()
+                    .execute_with_optional_timeout(client, timeout_per_transaction)
+                    .await?
+                    .file_id
+                    .expect("Creating a file means there's a file ID");
+
+                (file_id, response.node_account_id)
+            }
+        };
+
+        let append_node_account_ids = self.append_node_account_ids(node_account_id);
+
+        let mut in_flight: VecDeque<TransactionResponse> =
+            VecDeque::with_capacity(self.max_in_flight);
+
+        for chunk in chunks {
+            if in_flight.len() >= self.max_in_flight {
+                let response = in_flight.pop_front().unwrap();
+                response
+                    .get_receipt_query()
+                    .execute_with_optional_timeout(client, timeout_per_transaction)
+                    .await?;
+            }
+
+            let mut transaction = FileAppendTransaction::new();
+
+            transaction.file_id(file_id).contents(chunk.to_vec()).chunk_size(chunk.len());
+
+            if let Some(node_account_ids) = &append_node_account_ids {
+                transaction.node_account_ids(node_account_ids.iter().copied());
+            }
+
+            // applies the client's fee policy and validation, and freezes the transaction, just like `execute` would.
+            transaction.prepare_execute(client).await?;
+
+            // note: this submits just the one chunk, *without* waiting for its receipt like `execute` would.
+            in_flight.push_back(execute(client, &transaction, timeout_per_transaction).await?);
+        }
+
+        for response in in_flight {
+            response
+                .get_receipt_query()
+                .execute_with_optional_timeout(client, timeout_per_transaction)
+                .await?;
+        }
+
+        let uploaded = FileContentsQuery::new()
+            .file_id(file_id)
+            .execute_with_optional_timeout(client, timeout_per_transaction)
+            .await?
+            .contents;
+
+        self.verify(file_id, &uploaded)?;
+
+        Ok(file_id)
+    }
+
+    /// Splits the contents into the chunk the file is created (or updated) with, and the chunks appended after it.
+    fn split_contents(&self) -> (&[u8], std::slice::Chunks<'_, u8>) {
+        let mut chunks = self.contents.chunks(self.chunk_size);
+        let first_chunk = chunks.next().unwrap_or_default();
+
+        (first_chunk, chunks)
+    }
+
+    /// Returns the nodes to submit appends to, pinned to a single node when more than one may be in flight.
+    ///
+    /// `node_account_id` is the node that the file was created (or updated) by.
+    fn append_node_account_ids(&self, node_account_id: AccountId) -> Option<Vec<AccountId>> {
+        match (self.max_in_flight, self.node_account_ids.as_deref()) {
+            (1, _) => self.node_account_ids.clone(),
+            (_, Some([first, ..])) => Some(Vec::from([*first])),
+            (_, _) => Some(Vec::from([node_account_id])),
+        }
+    }
+
+    /// Errors with [`Error::FileUploadVerification`] if `uploaded` isn't the same as the contents given to this flow.
+    fn verify(&self, file_id: FileId, uploaded: &[u8]) -> crate::Result<()> {
+        let expected_hash = Sha384::digest(&self.contents);
+        let actual_hash = Sha384::digest(uploaded);
+
+        if expected_hash != actual_hash {
+            return Err(Error::FileUploadVerification {
+                file_id: Box::new(file_id),
+                expected_hash: hex::encode(expected_hash),
+                actual_hash: hex::encode(actual_hash),
+            });
+        }
+
+        Ok(())
+    }
+
+    fn make_file_create_transaction(
+        &self,
+        client: &Client,
+        contents: Vec<u8>,
+    ) -> crate::Result<FileCreateTransaction> {
+        let mut tmp = FileCreateTransaction::new();
+
+        tmp.contents(contents);
+
+        match &self.keys {
+            Some(keys) => tmp.keys(keys.iter().cloned()),
+            None => tmp.keys([client
+                .load_operator()
+                .as_deref()
+                .map(|it| it.signer.public_key())
+                .ok_or(Error::NoPayerAccountOrTransactionId)?]),
+        };
+
+        if let Some(file_memo) = &self.file_memo {
+            tmp.file_memo(file_memo);
+        }
+
+        if let Some(expiration_time) = self.expiration_time {
+            tmp.expiration_time(expiration_time);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        Ok(tmp)
+    }
+
+    fn make_file_update_transaction(
+        &self,
+        file_id: FileId,
+        contents: Vec<u8>,
+    ) -> FileUpdateTransaction {
+        let mut tmp = FileUpdateTransaction::new();
+
+        // note: setting the contents replaces them, so this truncates the file to just the first chunk.
+        tmp.file_id(file_id).contents(contents);
+
+        if let Some(keys) = &self.keys {
+            tmp.keys(keys.iter().cloned());
+        }
+
+        if let Some(file_memo) = &self.file_memo {
+            tmp.file_memo(file_memo);
+        }
+
+        if let Some(expiration_time) = self.expiration_time {
+            tmp.expiration_time(expiration_time);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            tmp.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        tmp
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::{
+        AccountId,
+        Client,
+        Error,
+        FileId,
+        FileUploadFlow,
+        PrivateKey,
+    };
+
+    #[test]
+    fn defaults() {
+        let flow = FileUploadFlow::new();
+
+        assert_eq!(flow.get_chunk_size(), 4096);
+        assert_eq!(flow.get_max_in_flight(), 1);
+        assert_eq!(flow.get_file_id(), None);
+    }
+
+    #[test]
+    fn get_set_contents() {
+        let mut flow = FileUploadFlow::new();
+        flow.contents([1, 2, 3]);
+
+        assert_eq!(flow.get_contents(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn get_set_file_id() {
+        let mut flow = FileUploadFlow::new();
+        flow.file_id(FileId::new(0, 0, 150));
+
+        assert_eq!(flow.get_file_id(), Some(FileId::new(0, 0, 150)));
+    }
+
+    #[test]
+    fn get_set_keys() {
+        let key = PrivateKey::generate_ed25519().public_key();
+        let mut flow = FileUploadFlow::new();
+        flow.keys([key]);
+
+        assert_eq!(flow.get_keys(), Some([key.into()].as_slice()));
+    }
+
+    #[test]
+    fn get_set_chunk_size() {
+        let mut flow = FileUploadFlow::new();
+        flow.chunk_size(1024);
+
+        assert_eq!(flow.get_chunk_size(), 1024);
+    }
+
+    #[test]
+    #[should_panic]
+    fn chunk_size_zero() {
+        FileUploadFlow::new().chunk_size(0);
+    }
+
+    #[test]
+    fn get_set_max_in_flight() {
+        let mut flow = FileUploadFlow::new();
+        flow.max_in_flight(4);
+
+        assert_eq!(flow.get_max_in_flight(), 4);
+    }
+
+    #[test]
+    fn get_set_node_account_ids() {
+        const ACCOUNT_IDS: [AccountId; 2] = [AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)];
+        let mut flow = FileUploadFlow::new();
+        flow.node_account_ids(ACCOUNT_IDS);
+
+        assert_eq!(flow.get_node_account_ids(), Some(ACCOUNT_IDS.as_slice()));
+    }
+
+    #[test]
+    fn split_contents() {
+        let mut flow = FileUploadFlow::new();
+        flow.contents(vec![1, 2, 3, 4, 5]).chunk_size(2);
+
+        let (first, rest) = flow.split_contents();
+
+        assert_eq!(first, [1, 2]);
+        assert_eq!(rest.collect::<Vec<_>>(), [&[3, 4][..], &[5]]);
+    }
+
+    #[test]
+    fn split_empty_contents() {
+        let flow = FileUploadFlow::new();
+
+        let (first, mut rest) = flow.split_contents();
+
+        assert!(first.is_empty());
+        assert_eq!(rest.next(), None);
+    }
+
+    #[test]
+    fn append_node_account_ids() {
+        const CREATE_NODE: AccountId = AccountId::new(0, 0, 5);
+        const ACCOUNT_IDS: [AccountId; 2] = [AccountId::new(0, 0, 3), AccountId::new(0, 0, 4)];
+
+        let mut flow = FileUploadFlow::new();
+
+        assert_eq!(flow.append_node_account_ids(CREATE_NODE), None);
+
+        flow.max_in_flight(4);
+        assert_eq!(flow.append_node_account_ids(CREATE_NODE), Some(Vec::from([CREATE_NODE])));
+
+        flow.node_account_ids(ACCOUNT_IDS);
+        assert_eq!(flow.append_node_account_ids(CREATE_NODE), Some(Vec::from([ACCOUNT_IDS[0]])));
+
+        flow.max_in_flight(1);
+        assert_eq!(flow.append_node_account_ids(CREATE_NODE), Some(Vec::from(ACCOUNT_IDS)));
+    }
+
+    #[test]
+    fn verify() {
+        let mut flow = FileUploadFlow::new();
+        flow.contents([1, 2, 3]);
+
+        flow.verify(FileId::new(0, 0, 150), &[1, 2, 3]).unwrap();
+
+        assert_matches!(
+            flow.verify(FileId::new(0, 0, 150), &[1, 2]),
+            Err(Error::FileUploadVerification { file_id, expected_hash, actual_hash })
+                if *file_id == FileId::new(0, 0, 150)
+                    && expected_hash.len() == 96
+                    && expected_hash != actual_hash
+        );
+    }
+
+    #[tokio::test]
+    async fn create_without_keys_or_operator() {
+        let client = Client::for_testnet();
+
+        assert_matches!(
+            FileUploadFlow::new().make_file_create_transaction(&client, Vec::new()),
+            Err(Error::NoPayerAccountOrTransactionId)
+        );
+    }
+}
@@ -173,10 +173,66 @@ impl Hbar {
     where
         T: Into<Decimal>,
     {
+        match Self::from_unit_truncated(amount.into(), unit) {
+            Ok(it) => it,
+            Err(e) => panic!("{e}"),
+        }
+    }
+
+    /// Convert from `amount` in `unit` to `Hbar`, without panicking or truncating.
+    ///
+    /// # Errors
+    /// - [`Error::HbarConversion`] if `amount * unit.tinybars()` would overflow an `i64`.
+    /// - [`Error::HbarConversion`] if `amount` isn't a whole number of tinybars.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use hedera::{Hbar, HbarUnit};
+    /// let value = Hbar::try_from_unit(20, HbarUnit::Millibar).unwrap();
+    /// assert_eq!(value.to_string(), "0.02 ℏ");
+    ///
+    /// assert!(Hbar::try_from_unit(i64::MAX, HbarUnit::Hbar).is_err());
+    /// ```
+    pub fn try_from_unit<T>(amount: T, unit: HbarUnit) -> crate::Result<Self>
+    where
+        T: Into<Decimal>,
+    {
+        let amount = amount.into();
+        let hbar = Self::from_unit_truncated(amount, unit)?;
+
+        // `from_unit_truncated` succeeded, so this multiplication can't overflow.
+        if !(amount * Decimal::from(unit.tinybars())).fract().is_zero() {
+            return Err(Error::HbarConversion {
+                amount: format!("{amount} {unit}"),
+                reason: "it isn't a whole number of tinybars",
+            });
+        }
+
+        Ok(hbar)
+    }
+
+    /// Convert from `hbars` to `Hbar`, without panicking or truncating.
+    ///
+    /// Unlike `Hbar::from(hbars)`, this fails instead of truncating amounts smaller than a tinybar.
+    ///
+    /// # Errors
+    /// - [`Error::HbarConversion`] if `hbars` is out of range.
+    /// - [`Error::HbarConversion`] if `hbars` isn't a whole number of tinybars.
+    pub fn try_from_decimal(hbars: Decimal) -> crate::Result<Self> {
+        Self::try_from_unit(hbars, HbarUnit::Hbar)
+    }
+
+    fn from_unit_truncated(amount: Decimal, unit: HbarUnit) -> crate::Result<Self> {
+        let overflow = || Error::HbarConversion {
+            amount: format!("{amount} {unit}"),
+            reason: "it's out of range for an `i64` of tinybars",
+        };
+
         let unit_tinybars: Decimal = unit.tinybars().into();
-        let amount_tinybars = amount.into().checked_mul(unit_tinybars).unwrap();
+        let amount_tinybars = amount.checked_mul(unit_tinybars).ok_or_else(overflow)?;
 
-        Hbar::from_tinybars(amount_tinybars.to_i64().unwrap())
+        Ok(Hbar::from_tinybars(amount_tinybars.trunc().to_i64().ok_or_else(overflow)?))
     }
 
     /// Returns the value of `self` in `Tinybar`s.
@@ -214,6 +270,69 @@ impl Hbar {
     pub fn negated(self) -> Self {
         -self
     }
+
+    /// Returns `self + rhs`, or `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_add(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_add(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self - rhs`, or `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_sub(self, rhs: Self) -> Option<Self> {
+        match self.0.checked_sub(rhs.0) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self * rhs`, or `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_mul(self, rhs: i64) -> Option<Self> {
+        match self.0.checked_mul(rhs) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self / rhs`, or `None` if `rhs` is zero or the result would overflow.
+    #[must_use]
+    pub const fn checked_div(self, rhs: i64) -> Option<Self> {
+        match self.0.checked_div(rhs) {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `-self`, or `None` if the result would overflow.
+    #[must_use]
+    pub const fn checked_neg(self) -> Option<Self> {
+        match self.0.checked_neg() {
+            Some(it) => Some(Self(it)),
+            None => None,
+        }
+    }
+
+    /// Returns `self + rhs`, saturating at the numeric bounds of [`Tinybar`].
+    #[must_use]
+    pub const fn saturating_add(self, rhs: Self) -> Self {
+        Self(self.0.saturating_add(rhs.0))
+    }
+
+    /// Returns `self - rhs`, saturating at the numeric bounds of [`Tinybar`].
+    #[must_use]
+    pub const fn saturating_sub(self, rhs: Self) -> Self {
+        Self(self.0.saturating_sub(rhs.0))
+    }
+
+    /// Returns `self * rhs`, saturating at the numeric bounds of [`Tinybar`].
+    #[must_use]
+    pub const fn saturating_mul(self, rhs: i64) -> Self {
+        Self(self.0.saturating_mul(rhs))
+    }
 }
 
 impl From<Hbar> for Decimal {
@@ -251,7 +370,7 @@ impl FromStr for Hbar {
         let (amount, unit) = s.split_once(' ').unwrap_or((s, "ℏ"));
         let amount: Decimal = amount.parse().map_err(Error::basic_parse)?;
         let unit = HbarUnit::from_str(unit)?;
-        Ok(Hbar::from_unit(amount, unit))
+        Hbar::from_unit_truncated(amount, unit)
     }
 }
 
@@ -331,13 +450,27 @@ where
     }
 }
 
+impl std::iter::Sum for Hbar {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::ZERO, ops::Add::add)
+    }
+}
+
+impl<'a> std::iter::Sum<&'a Hbar> for Hbar {
+    fn sum<I: Iterator<Item = &'a Self>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::str::FromStr;
 
+    use assert_matches::assert_matches;
     use rust_decimal::Decimal;
 
     use crate::{
+        Error,
         Hbar,
         HbarUnit,
     };
@@ -383,4 +516,75 @@ mod tests {
         assert_eq!(m.to_tinybars(), 3);
         assert_eq!((-m).to_tinybars(), -3);
     }
+
+    #[test]
+    fn checked_arithmetic() {
+        let max = Hbar::from_tinybars(i64::MAX);
+        let min = Hbar::from_tinybars(i64::MIN);
+        let one = Hbar::from_tinybars(1);
+
+        assert_eq!(one.checked_add(one), Some(Hbar::from_tinybars(2)));
+        assert_eq!(max.checked_add(one), None);
+        assert_eq!(one.checked_sub(one), Some(Hbar::ZERO));
+        assert_eq!(min.checked_sub(one), None);
+        assert_eq!(Hbar::new(2).checked_mul(3), Some(Hbar::new(6)));
+        assert_eq!(max.checked_mul(2), None);
+        assert_eq!(Hbar::new(6).checked_div(3), Some(Hbar::new(2)));
+        assert_eq!(one.checked_div(0), None);
+        assert_eq!(min.checked_div(-1), None);
+        assert_eq!(min.checked_neg(), None);
+
+        assert_eq!(max.saturating_add(one), max);
+        assert_eq!(min.saturating_sub(one), min);
+        assert_eq!(min.saturating_mul(2), min);
+        assert_eq!(Hbar::new(2).saturating_mul(3), Hbar::new(6));
+    }
+
+    #[test]
+    fn try_from_unit() {
+        assert_eq!(
+            Hbar::try_from_unit(20, HbarUnit::Millibar).unwrap(),
+            Hbar::from_unit(20, HbarUnit::Millibar)
+        );
+        assert_eq!(
+            Hbar::try_from_unit(Decimal::from_str("0.5").unwrap(), HbarUnit::Microbar).unwrap(),
+            Hbar::from_tinybars(50)
+        );
+
+        assert_matches!(
+            Hbar::try_from_unit(i64::MAX, HbarUnit::Gigabar),
+            Err(Error::HbarConversion { .. })
+        );
+        assert_matches!(
+            Hbar::try_from_unit(Decimal::from_str("0.5").unwrap(), HbarUnit::Tinybar),
+            Err(Error::HbarConversion { .. })
+        );
+    }
+
+    #[test]
+    fn try_from_decimal() {
+        assert_eq!(
+            Hbar::try_from_decimal(Decimal::from_str("1.00000001").unwrap()).unwrap(),
+            Hbar::from_tinybars(100_000_001)
+        );
+
+        // `From<Decimal>` truncates, `try_from_decimal` doesn't.
+        let too_precise = Decimal::from_str("1.000000001").unwrap();
+        assert_eq!(Hbar::from(too_precise), Hbar::new(1));
+        assert_matches!(Hbar::try_from_decimal(too_precise), Err(Error::HbarConversion { .. }));
+    }
+
+    #[test]
+    fn parse_out_of_range() {
+        assert_matches!(Hbar::from_str("1000000000 Gℏ"), Err(Error::HbarConversion { .. }));
+    }
+
+    #[test]
+    fn sum() {
+        let amounts = [Hbar::new(1), Hbar::from_tinybars(5), Hbar::new(-2)];
+
+        assert_eq!(amounts.iter().sum::<Hbar>(), Hbar::from_tinybars(-100_000_000 + 5));
+        assert_eq!(amounts.into_iter().sum::<Hbar>(), Hbar::from_tinybars(-100_000_000 + 5));
+        assert_eq!(std::iter::empty::<Hbar>().sum::<Hbar>(), Hbar::ZERO);
+    }
 }
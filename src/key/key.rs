@@ -21,17 +21,27 @@
 use hedera_proto::services;
 
 use crate::contract::DelegateContractId;
+use crate::key::key_verification::has_valid_signature;
+use crate::signer::AnySigner;
+use crate::transaction::{
+    TransactionExecute,
+    TransactionSources,
+};
 use crate::{
     ContractId,
     Error,
     FromProtobuf,
     KeyList,
+    KeyVerification,
     PublicKey,
     ToProtobuf,
+    Transaction,
 };
 
 /// Any method that can be used to authorize an operation on Hedera.
 #[derive(Eq, PartialEq, Debug, Clone, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub enum Key {
     // todo(sr): not happy with any of these (fix before merge)
@@ -54,6 +64,88 @@ impl Key {
     pub fn to_bytes(&self) -> Vec<u8> {
         ToProtobuf::to_bytes(self)
     }
+
+    /// Evaluate this key against the given `signatures` of `msg`.
+    ///
+    /// Key lists are evaluated recursively, respecting their thresholds.
+    ///
+    /// Signatures that don't belong to any key in `self`, or that are invalid, are ignored.
+    #[must_use]
+    pub fn verify(&self, msg: &[u8], signatures: &[(PublicKey, Vec<u8>)]) -> KeyVerification {
+        self.verify_with(&|key| {
+            signatures.iter().any(|(pk, sig)| pk == key && key.verify(msg, sig).is_ok())
+        })
+    }
+
+    /// Evaluate this key as if every key in `keys` had signed.
+    ///
+    /// This answers whether `keys` are able to satisfy this key, without signing anything.
+    #[must_use]
+    pub fn verify_keys(&self, keys: &[PublicKey]) -> KeyVerification {
+        self.verify_with(&|key| keys.contains(key))
+    }
+
+    /// Evaluate this key against the signatures on the given transaction.
+    ///
+    /// Signers added via [`sign`](Transaction::sign) (and friends) are trusted to produce valid signatures,
+    /// other signatures (IE, ones that came from [`AnyTransaction::from_bytes`](crate::AnyTransaction::from_bytes)) are checked.
+    ///
+    /// A public key is only considered satisfied if it has a valid signature on *every* node's transaction body.
+    ///
+    /// # Errors
+    /// - If [`freeze`](Transaction::freeze) would error for this transaction.
+    pub fn verify_transaction<D: TransactionExecute>(
+        &self,
+        transaction: &mut Transaction<D>,
+    ) -> crate::Result<KeyVerification> {
+        transaction.freeze()?;
+
+        let signers: Vec<_> = transaction.signers().map(AnySigner::public_key).collect();
+
+        let report = match transaction.sources() {
+            Some(sources) => self.verify_transaction_sources(sources, &signers),
+            None => self.verify_with(&|key| signers.contains(key)),
+        };
+
+        Ok(report)
+    }
+
+    pub(crate) fn verify_transaction_sources(
+        &self,
+        sources: &TransactionSources,
+        signers: &[PublicKey],
+    ) -> KeyVerification {
+        let mut reports = sources.signed_transactions().iter().map(|signed_transaction| {
+            let sig_pairs = signed_transaction
+                .sig_map
+                .as_ref()
+                .map_or_else(|| [].as_slice(), |it| &it.sig_pair);
+
+            self.verify_with(&|key| {
+                signers.contains(key)
+                    || has_valid_signature(key, &signed_transaction.body_bytes, sig_pairs)
+            })
+        });
+
+        // `TransactionSources` always has at least one transaction, but we lose nothing by handling the empty case.
+        let Some(first) = reports.next() else {
+            return self.verify_with(&|key| signers.contains(key));
+        };
+
+        reports.fold(first, KeyVerification::merge)
+    }
+
+    fn verify_with(&self, is_signed: &dyn Fn(&PublicKey) -> bool) -> KeyVerification {
+        match self {
+            Self::Single(key) => KeyVerification::Single { key: *key, satisfied: is_signed(key) },
+            Self::ContractId(id) => KeyVerification::ContractId(*id),
+            Self::DelegateContractId(id) => KeyVerification::DelegateContractId(*id),
+            Self::KeyList(list) => KeyVerification::KeyList {
+                required: list.threshold.map_or(list.len(), |it| it as usize),
+                keys: list.iter().map(|it| it.verify_with(is_signed)).collect(),
+            },
+        }
+    }
 }
 
 impl ToProtobuf for Key {
@@ -135,10 +227,22 @@ mod tests {
 
     use crate::protobuf::FromProtobuf;
     use crate::{
+        AnyTransaction,
+        Hbar,
         Key,
+        KeyList,
+        PrivateKey,
         PublicKey,
+        TransferTransaction,
     };
 
+    fn threshold_key(keys: &[PrivateKey], threshold: u32) -> Key {
+        let mut key_list: KeyList = keys.iter().map(|it| Key::from(it.public_key())).collect();
+        key_list.threshold = Some(threshold);
+
+        Key::KeyList(key_list)
+    }
+
     #[test]
     fn from_proto_key_ed25519() {
         const KEY_BYTES: [u8; 32] =
@@ -229,4 +333,111 @@ mod tests {
 
         assert_matches!(Key::from_protobuf(key), Err(crate::Error::FromProtobuf(_)));
     }
+
+    #[test]
+    fn verify_threshold_key() {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+            PrivateKey::generate_ed25519(),
+        ];
+
+        let key = threshold_key(&keys, 2);
+
+        let msg = b"hello, world";
+
+        let one = [(keys[0].public_key(), keys[0].sign(msg))];
+        let two =
+            [(keys[0].public_key(), keys[0].sign(msg)), (keys[1].public_key(), keys[1].sign(msg))];
+
+        // the signature for `keys[2]` is for the wrong message, so it doesn't count.
+        let bad = [
+            (keys[0].public_key(), keys[0].sign(msg)),
+            (keys[2].public_key(), keys[2].sign(b"goodbye, world")),
+        ];
+
+        assert!(!key.verify(msg, &one).is_satisfied());
+        assert!(key.verify(msg, &two).is_satisfied());
+        assert!(!key.verify(msg, &bad).is_satisfied());
+
+        let report = key.verify(msg, &two);
+        assert_eq!(report.satisfied_keys(), [keys[0].public_key(), keys[1].public_key()]);
+        assert_eq!(report.unsatisfied_keys(), [keys[2].public_key()]);
+    }
+
+    #[test]
+    fn verify_nested_key_list() {
+        let keys = [PrivateKey::generate_ed25519(), PrivateKey::generate_ed25519()];
+        let inner = threshold_key(&keys, 1);
+        let other = PrivateKey::generate_ed25519();
+
+        let key = Key::KeyList(KeyList::from([inner, other.public_key().into()]));
+
+        let msg = b"hello, world";
+
+        let sigs =
+            [(keys[1].public_key(), keys[1].sign(msg)), (other.public_key(), other.sign(msg))];
+
+        assert!(key.verify(msg, &sigs).is_satisfied());
+        assert!(!key.verify(msg, &sigs[..1]).is_satisfied());
+    }
+
+    #[test]
+    fn verify_keys() {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+            PrivateKey::generate_ed25519(),
+        ];
+
+        let key = threshold_key(&keys, 2);
+
+        assert!(!key.verify_keys(&[keys[2].public_key()]).is_satisfied());
+        assert!(key.verify_keys(&[keys[2].public_key(), keys[0].public_key()]).is_satisfied());
+
+        let report = key.verify_keys(&[keys[1].public_key()]);
+        assert_eq!(report.unsatisfied_keys(), [keys[0].public_key(), keys[2].public_key()]);
+    }
+
+    #[test]
+    fn verify_empty_key_list() {
+        assert!(!Key::KeyList(KeyList::new()).verify(b"hello", &[]).is_satisfied());
+    }
+
+    #[test]
+    fn verify_transaction_from_bytes() -> crate::Result<()> {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+        ];
+
+        let key = threshold_key(&keys, 2);
+
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(2.into(), Hbar::new(2))
+            .hbar_transfer(101.into(), Hbar::new(-2))
+            .transaction_id(crate::transaction::test_helpers::TEST_TX_ID)
+            .node_account_ids([6.into(), 7.into()])
+            .freeze()?
+            .sign(keys[0].clone());
+
+        let bytes = tx.to_bytes()?;
+
+        let mut tx = AnyTransaction::from_bytes(&bytes)?;
+
+        assert!(!key.verify_transaction(&mut tx)?.is_satisfied());
+
+        let bytes = tx.sign(keys[2].clone()).to_bytes()?;
+
+        let mut tx = AnyTransaction::from_bytes(&bytes)?;
+
+        let report = key.verify_transaction(&mut tx)?;
+
+        assert!(report.is_satisfied());
+        assert_eq!(report.unsatisfied_keys(), [keys[1].public_key()]);
+
+        Ok(())
+    }
 }
@@ -0,0 +1,148 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+
+use crate::contract::DelegateContractId;
+use crate::{
+    ContractId,
+    PublicKey,
+};
+
+/// The result of evaluating a [`Key`](crate::Key) against a set of signatures.
+///
+/// The report mirrors the structure of the key it was created from,
+/// so that callers can see exactly which parts of a key were satisfied.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum KeyVerification {
+    /// A single public key.
+    Single {
+        /// The public key in question.
+        key: PublicKey,
+
+        /// `true` if a valid signature was found for `key`.
+        satisfied: bool,
+    },
+
+    /// A contract ID.
+    ///
+    /// Contract keys are authorized by contract execution rather than signatures,
+    /// and as such are never satisfied by local verification.
+    ContractId(ContractId),
+
+    /// A delegatable contract ID.
+    ///
+    /// Like [`ContractId`](Self::ContractId), this is never satisfied by local verification.
+    DelegateContractId(DelegateContractId),
+
+    /// A key list or threshold key.
+    KeyList {
+        /// How many of `keys` must be satisfied for the key list to be satisfied.
+        required: usize,
+
+        /// The results for each key in the key list, in the same order as the key list.
+        keys: Vec<KeyVerification>,
+    },
+}
+
+impl KeyVerification {
+    /// Returns `true` if this key has been satisfied.
+    ///
+    /// A key list is satisfied when at least `required` of its keys are satisfied,
+    /// an empty key list (or a threshold of zero) is never satisfied.
+    #[must_use]
+    pub fn is_satisfied(&self) -> bool {
+        match self {
+            Self::Single { satisfied, .. } => *satisfied,
+            Self::ContractId(_) | Self::DelegateContractId(_) => false,
+            Self::KeyList { required, keys } => {
+                *required > 0 && keys.iter().filter(|it| it.is_satisfied()).count() >= *required
+            }
+        }
+    }
+
+    /// Returns the public keys that have a valid signature.
+    #[must_use]
+    pub fn satisfied_keys(&self) -> Vec<PublicKey> {
+        let mut keys = Vec::new();
+        self.collect_keys(true, &mut keys);
+        keys
+    }
+
+    /// Returns the public keys that are missing a valid signature.
+    #[must_use]
+    pub fn unsatisfied_keys(&self) -> Vec<PublicKey> {
+        let mut keys = Vec::new();
+        self.collect_keys(false, &mut keys);
+        keys
+    }
+
+    fn collect_keys(&self, satisfied: bool, out: &mut Vec<PublicKey>) {
+        match self {
+            Self::Single { key, satisfied: it } if *it == satisfied => out.push(*key),
+            Self::Single { .. } | Self::ContractId(_) | Self::DelegateContractId(_) => {}
+            Self::KeyList { keys, .. } => {
+                for key in keys {
+                    key.collect_keys(satisfied, out);
+                }
+            }
+        }
+    }
+
+    /// Combines two reports for the same key such that a public key is only satisfied if it's satisfied in both.
+    ///
+    /// This is used to combine the reports for every node body of a transaction.
+    pub(crate) fn merge(self, other: Self) -> Self {
+        match (self, other) {
+            (Self::Single { key, satisfied: lhs }, Self::Single { satisfied: rhs, .. }) => {
+                Self::Single { key, satisfied: lhs && rhs }
+            }
+
+            (Self::KeyList { required, keys: lhs }, Self::KeyList { keys: rhs, .. }) => {
+                Self::KeyList {
+                    required,
+                    keys: lhs.into_iter().zip(rhs).map(|(lhs, rhs)| lhs.merge(rhs)).collect(),
+                }
+            }
+
+            (lhs, _) => lhs,
+        }
+    }
+}
+
+/// Returns `true` if `sig_pairs` contains a valid signature of `body_bytes` by `key`.
+pub(crate) fn has_valid_signature(
+    key: &PublicKey,
+    body_bytes: &[u8],
+    sig_pairs: &[services::SignaturePair],
+) -> bool {
+    use services::signature_pair::Signature;
+
+    let pk_bytes = key.to_bytes_raw();
+
+    sig_pairs.iter().filter(|it| pk_bytes.starts_with(&it.pub_key_prefix)).any(|it| {
+        matches!(
+            &it.signature,
+            Some(Signature::EcdsaSecp256k1(sig) | Signature::Ed25519(sig))
+                if key.verify(body_bytes, sig).is_ok()
+        )
+    })
+}
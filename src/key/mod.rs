@@ -21,11 +21,14 @@
 #[allow(clippy::module_inception)]
 mod key;
 mod key_list;
+mod key_verification;
 mod private_key;
 mod public_key;
 
 pub use key::Key;
 pub use key_list::KeyList;
+pub(crate) use key_verification::has_valid_signature;
+pub use key_verification::KeyVerification;
 pub use private_key::PrivateKey;
 pub use public_key::PublicKey;
 
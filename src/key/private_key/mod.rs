@@ -606,6 +606,33 @@ impl PrivateKey {
         }
     }
 
+    /// Signs the keccak256 hash of `message` the way ethereum transactions are signed,
+    /// returning the low-S signature and its recovery ID.
+    ///
+    /// # Errors
+    /// - [`Error::EthereumSign`] if `self` isn't an ECDSA(secp256k1) key.
+    pub(crate) fn sign_recoverable(
+        &self,
+        message: &[u8],
+    ) -> crate::Result<(k256::ecdsa::Signature, k256::ecdsa::RecoveryId)> {
+        let PrivateKeyData::Ecdsa(key) = &self.0.data else {
+            return Err(Error::ethereum_sign("only ECDSA(secp256k1) keys can sign"));
+        };
+
+        let (signature, recovery_id) = key
+            .sign_digest_recoverable(sha3::Keccak256::new_with_prefix(message))
+            .map_err(Error::ethereum_sign)?;
+
+        // ethereum only accepts low-S signatures, negating `s` flips the parity of `y`.
+        Ok(match signature.normalize_s() {
+            Some(signature) => (
+                signature,
+                k256::ecdsa::RecoveryId::new(!recovery_id.is_y_odd(), recovery_id.is_x_reduced()),
+            ),
+            None => (signature, recovery_id),
+        })
+    }
+
     // I question the reason for this function existing.
     /// Signs the given transaction.
     ///
@@ -378,7 +378,9 @@ impl PublicKey {
         let pk_bytes = self.to_bytes_raw();
 
         for signed_transaction in sources.signed_transactions() {
-            let mut found = false;
+            // note: with shortened prefixes another signer's pair may share our prefix,
+            // so only fail if *none* of the matching pairs verify.
+            let mut result = Err(Error::signature_verify("signer not in transaction"));
             for sig_pair in
                 signed_transaction.sig_map.as_ref().map_or_else(|| [].as_slice(), |it| &it.sig_pair)
             {
@@ -386,19 +388,20 @@ impl PublicKey {
                     continue;
                 }
 
-                found = true;
                 let Some(Signature::EcdsaSecp256k1(sig) | Signature::Ed25519(sig)) =
                     &sig_pair.signature
                 else {
-                    return Err(Error::signature_verify("Unsupported transaction signature type"));
+                    result = Err(Error::signature_verify("Unsupported transaction signature type"));
+                    continue;
                 };
 
-                self.verify(&signed_transaction.body_bytes, sig)?;
+                result = self.verify(&signed_transaction.body_bytes, sig);
+                if result.is_ok() {
+                    break;
+                }
             }
 
-            if !found {
-                return Err(Error::signature_verify("signer not in transaction"));
-            }
+            result?;
         }
 
         Ok(())
@@ -126,6 +126,7 @@ mod error;
 mod ethereum;
 mod exchange_rates;
 mod execute;
+mod fee_estimator;
 mod fee_schedules;
 mod file;
 mod hbar;
@@ -139,12 +140,15 @@ mod network_version_info_query;
 mod node_address;
 mod node_address_book;
 mod node_address_book_query;
+mod pending;
 mod ping_query;
 mod prng_transaction;
 mod query;
 mod retry;
 mod schedule;
 mod semantic_version;
+#[cfg(feature = "serde")]
+mod serde_impls;
 mod signer;
 mod staked_id;
 mod staking_info;
@@ -160,6 +164,7 @@ mod transaction_record;
 mod transaction_record_query;
 mod transaction_response;
 mod transfer;
+mod transfer_distribution;
 mod transfer_transaction;
 
 pub use account::{
@@ -211,7 +216,9 @@ pub use error::{
 };
 pub use ethereum::{
     Eip1559EthereumData,
+    Eip2930EthereumData,
     EthereumData,
+    EthereumDataBuilder,
     EthereumFlow,
     EthereumTransaction,
     EvmAddress,
@@ -221,6 +228,11 @@ pub use exchange_rates::{
     ExchangeRate,
     ExchangeRates,
 };
+pub use fee_estimator::{
+    FeeEstimate,
+    FeeEstimator,
+    MaxTransactionFeePolicy,
+};
 pub use fee_schedules::{
     FeeComponents,
     FeeData,
@@ -240,6 +252,7 @@ pub use file::{
     FileInfo,
     FileInfoQuery,
     FileUpdateTransaction,
+    FileUploadFlow,
 };
 pub use hbar::{
     Hbar,
@@ -250,6 +263,7 @@ pub use hedera_proto::services::ResponseCodeEnum as Status;
 pub use key::{
     Key,
     KeyList,
+    KeyVerification,
     PrivateKey,
     PublicKey,
 };
@@ -299,6 +313,8 @@ pub use token::{
     AnyCustomFee,
     AssessedCustomFee,
     CustomFee,
+    CustomFeeSimulation,
+    CustomFeeSimulator,
     Fee,
     FeeAssessmentMethod,
     FixedFee,
@@ -306,8 +322,15 @@ pub use token::{
     FractionalFee,
     FractionalFeeData,
     NftId,
+    NftMintFlow,
+    NftMintProgress,
     RoyaltyFee,
     RoyaltyFeeData,
+    TokenAdminBatch,
+    TokenAdminOperation,
+    TokenAdminOutcome,
+    TokenAdminResult,
+    TokenAmount,
     TokenAssociateTransaction,
     TokenAssociation,
     TokenBurnTransaction,
@@ -320,11 +343,19 @@ pub use token::{
     TokenId,
     TokenInfo,
     TokenInfoQuery,
+    TokenLaunchFlow,
+    TokenLaunchRecipient,
+    TokenLaunchRecipientReport,
+    TokenLaunchReport,
+    TokenLaunchStep,
     TokenMintTransaction,
     TokenNftInfo,
     TokenNftInfoQuery,
     TokenNftTransfer,
+    TokenOperation,
     TokenPauseTransaction,
+    TokenPermission,
+    TokenPermissions,
     TokenRevokeKycTransaction,
     TokenSupplyType,
     TokenType,
@@ -333,6 +364,14 @@ pub use token::{
     TokenUpdateTransaction,
     TokenWipeTransaction,
 };
+#[cfg(feature = "hip412")]
+pub use token::{
+    Hip412Attribute,
+    Hip412File,
+    Hip412Localization,
+    Hip412Metadata,
+    NftMetadataUri,
+};
 pub use topic::{
     TopicCreateTransaction,
     TopicDeleteTransaction,
@@ -346,7 +385,16 @@ pub use topic::{
 };
 pub use transaction::{
     AnyTransaction,
+    BalanceChange,
+    ChunkProgress,
+    ChunkReceipt,
+    ExplanationField,
+    SignaturePrefixMode,
     Transaction,
+    TransactionBodyBytes,
+    TransactionExplanation,
+    TransactionViolation,
+    TransactionWindows,
 };
 pub use transaction_hash::TransactionHash;
 pub use transaction_id::TransactionId;
@@ -357,6 +405,12 @@ pub use transaction_record_query::TransactionRecordQuery;
 pub(crate) use transaction_record_query::TransactionRecordQueryData;
 pub use transaction_response::TransactionResponse;
 pub use transfer::Transfer;
+pub use transfer_distribution::{
+    DistributionEntry,
+    DistributionLedger,
+    DistributionRecord,
+    TransferDistribution,
+};
 pub use transfer_transaction::TransferTransaction;
 
 /// Like [`arc_swap::ArcSwapOption`] but with a [`triomphe::Arc`].
@@ -10,6 +10,8 @@ use crate::NodeAddress;
 ///
 /// Response from [`NodeAddressBookQuery`](crate::NodeAddressBookQuery)
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct NodeAddressBook {
     /// all the nodes this address book contains.
     pub node_addresses: Vec<NodeAddress>,
@@ -0,0 +1,229 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::VecDeque;
+use std::time::Duration;
+
+use crate::execute::execute;
+use crate::transaction::TransactionExecute;
+use crate::{
+    Client,
+    Error,
+    Status,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransactionReceiptQuery,
+};
+
+/// A persistent record of the transactions a flow has submitted, which makes the flow resumable.
+///
+/// A transaction is *pending* from the moment it's submitted until its receipt is known.
+/// Whatever a pending transaction does is never submitted again automatically, since it may have reached consensus;
+/// the next execution looks up the receipts of pending transactions first.
+/// If a receipt can't be found (for instance because it has expired),
+/// the transaction stays pending until the user checks it by other means and clears it.
+pub(crate) trait PendingLedger {
+    /// Returns `true` if a transaction that reached consensus with `status` did what it was submitted for.
+    fn is_success(status: Status) -> bool {
+        status == Status::Success
+    }
+
+    /// Records that the pending transaction `transaction_id` succeeded.
+    fn confirm_pending(&mut self, transaction_id: TransactionId, receipt: &TransactionReceipt);
+
+    /// Forgets the pending transaction `transaction_id`, which is known not to have succeeded,
+    /// so that it's submitted again by the next execution.
+    fn forget_pending(&mut self, transaction_id: TransactionId);
+}
+
+/// Submits transactions on behalf of a [`PendingLedger`], keeping the ledger up to date.
+///
+/// At most `max_in_flight` transactions wait for their receipts at once.
+pub(crate) struct Submitter<'a, L> {
+    client: &'a Client,
+    ledger: &'a mut L,
+    max_in_flight: usize,
+    timeout: Option<Duration>,
+    in_flight: VecDeque<TransactionId>,
+}
+
+impl<'a, L: PendingLedger> Submitter<'a, L> {
+    pub(crate) fn new(
+        client: &'a Client,
+        ledger: &'a mut L,
+        max_in_flight: usize,
+        timeout: Option<Duration>,
+    ) -> Self {
+        Self { client, ledger, max_in_flight, timeout, in_flight: VecDeque::new() }
+    }
+
+    pub(crate) fn ledger(&self) -> &L {
+        self.ledger
+    }
+
+    /// Looks up the receipts of `transaction_ids`, the transactions left pending by an earlier execution.
+    pub(crate) async fn resolve(
+        &mut self,
+        transaction_ids: Vec<TransactionId>,
+    ) -> crate::Result<()> {
+        for transaction_id in transaction_ids {
+            let receipt = TransactionReceiptQuery::new()
+                .transaction_id(transaction_id)
+                .execute_with_optional_timeout(self.client, self.timeout)
+                .await;
+
+            match receipt {
+                Ok(receipt) if L::is_success(receipt.status) => {
+                    self.ledger.confirm_pending(transaction_id, &receipt);
+                }
+                Ok(_) => self.ledger.forget_pending(transaction_id),
+                // the transaction may or may not have happened, so leave it pending.
+                Err(e) if e.is_receipt_not_found() => {}
+                Err(e) => return Err(e),
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Submits `transaction` *without* waiting for its receipt.
+    ///
+    /// `transaction` is prepared (and frozen) just like `execute` would,
+    /// so it shouldn't be frozen beforehand, or the client's fee policy can't apply to it.
+    ///
+    /// `begin` records the transaction as pending in the ledger.
+    /// It's called *before* the transaction is submitted, so that the transaction can't be forgotten.
+    pub(crate) async fn submit<D>(
+        &mut self,
+        transaction: &mut Transaction<D>,
+        begin: impl FnOnce(&mut L, TransactionId),
+    ) -> crate::Result<()>
+    where
+        D: TransactionExecute + Send + Sync,
+    {
+        if self.in_flight.len() >= self.max_in_flight {
+            let transaction_id = self.in_flight.pop_front().unwrap();
+            self.await_receipt(transaction_id).await?;
+        }
+
+        transaction.prepare_execute(self.client).await?;
+
+        let transaction_id =
+            transaction.get_transaction_id().expect("frozen transactions have an ID");
+
+        begin(self.ledger, transaction_id);
+
+        match execute(self.client, transaction, self.timeout).await {
+            Ok(response) => {
+                self.in_flight.push_back(response.transaction_id);
+                Ok(())
+            }
+
+            Err(e) => {
+                if is_rejected(&e) {
+                    self.ledger.forget_pending(transaction_id);
+                }
+
+                Err(e)
+            }
+        }
+    }
+
+    /// Waits for the receipts of all the transactions that have been submitted.
+    pub(crate) async fn finish(&mut self) -> crate::Result<()> {
+        while let Some(transaction_id) = self.in_flight.pop_front() {
+            self.await_receipt(transaction_id).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn await_receipt(&mut self, transaction_id: TransactionId) -> crate::Result<()> {
+        let receipt = TransactionReceiptQuery::new()
+            .transaction_id(transaction_id)
+            .execute_with_optional_timeout(self.client, self.timeout)
+            .await?;
+
+        if L::is_success(receipt.status) {
+            self.ledger.confirm_pending(transaction_id, &receipt);
+            return Ok(());
+        }
+
+        self.ledger.forget_pending(transaction_id);
+
+        Err(Error::ReceiptStatus {
+            status: receipt.status,
+            transaction_id: Some(Box::new(transaction_id)),
+        })
+    }
+}
+
+/// Returns `true` if `error`, from submitting a transaction, proves that the transaction can't reach consensus.
+///
+/// A failed pre-check only says that the node which reported it didn't accept the transaction,
+/// and a node that was tried before it (and timed out, for instance) may have.
+/// So statuses that an earlier submission can cause, or that are transient, prove nothing:
+/// most notably `DuplicateTransaction`, which means that the transaction *was* submitted before.
+fn is_rejected(error: &Error) -> bool {
+    match error {
+        Error::TransactionPreCheckStatus { status, .. } => !matches!(
+            status,
+            Status::DuplicateTransaction
+                | Status::TransactionExpired
+                | Status::Busy
+                | Status::PlatformNotActive
+                | Status::PlatformTransactionNotCreated
+                | Status::Unknown
+        ),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::is_rejected;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        Error,
+        Status,
+    };
+
+    fn pre_check(status: Status) -> Error {
+        Error::TransactionPreCheckStatus { status, transaction_id: Box::new(TEST_TX_ID) }
+    }
+
+    #[test]
+    fn rejected() {
+        assert!(is_rejected(&pre_check(Status::InvalidSignature)));
+        assert!(is_rejected(&pre_check(Status::InsufficientTxFee)));
+    }
+
+    #[test]
+    fn not_rejected() {
+        assert!(!is_rejected(&pre_check(Status::DuplicateTransaction)));
+        assert!(!is_rejected(&pre_check(Status::Busy)));
+        assert!(!is_rejected(&pre_check(Status::TransactionExpired)));
+        assert!(!is_rejected(&Error::ReceiptStatus {
+            status: Status::InvalidSignature,
+            transaction_id: Some(Box::new(TEST_TX_ID)),
+        }));
+    }
+}
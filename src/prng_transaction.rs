@@ -40,6 +40,8 @@ use crate::Transaction;
 pub type PrngTransaction = Transaction<PrngTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 pub struct PrngTransactionData {
     range: Option<u32>,
@@ -20,6 +20,7 @@
 
 mod convert;
 mod time;
+mod unknown;
 
 #[macro_use]
 pub(crate) mod get;
@@ -28,3 +29,7 @@ pub(crate) use convert::{
     FromProtobuf,
     ToProtobuf,
 };
+pub(crate) use unknown::{
+    strip_fields,
+    unknown_fields,
+};
@@ -0,0 +1,116 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use prost::encoding::{
+    decode_key,
+    skip_field,
+    DecodeContext,
+};
+use prost::Message;
+
+use crate::Error;
+
+/// Splits protobuf encoded `bytes` into its top level fields, as `(tag, encoded field)` pairs.
+fn fields(bytes: &[u8]) -> crate::Result<Vec<(u32, &[u8])>> {
+    let mut buf = bytes;
+    let mut fields = Vec::new();
+
+    while !buf.is_empty() {
+        let start = bytes.len() - buf.len();
+
+        let (tag, wire_type) = decode_key(&mut buf).map_err(Error::from_protobuf)?;
+        skip_field(wire_type, tag, &mut buf, DecodeContext::default())
+            .map_err(Error::from_protobuf)?;
+
+        fields.push((tag, &bytes[start..bytes.len() - buf.len()]));
+    }
+
+    Ok(fields)
+}
+
+/// Returns `bytes` with every top level field with one of the given `tags` removed, leaving everything else exactly as encoded.
+pub(crate) fn strip_fields(bytes: &[u8], tags: &[u32]) -> crate::Result<Vec<u8>> {
+    Ok(fields(bytes)?
+        .into_iter()
+        .filter(|(tag, _)| !tags.contains(tag))
+        .flat_map(|(_, field)| field)
+        .copied()
+        .collect())
+}
+
+/// Returns the top level fields of `bytes` that get lost when decoding it as an `M`, exactly as encoded.
+///
+/// Note: known fields that are explicitly set to their default value are also lost, so they're included too.
+pub(crate) fn unknown_fields<M: Message + Default>(bytes: &[u8]) -> crate::Result<Vec<u8>> {
+    let known = M::decode(bytes).map_err(Error::from_protobuf)?.encode_to_vec();
+    let known_tags: Vec<_> = fields(&known)?.into_iter().map(|(tag, _)| tag).collect();
+
+    strip_fields(bytes, &known_tags)
+}
+
+#[cfg(test)]
+mod tests {
+    use hedera_proto::services;
+    use prost::Message;
+
+    use super::{
+        strip_fields,
+        unknown_fields,
+    };
+
+    // field 15, varint 1, which `SignedTransaction` doesn't have.
+    const UNKNOWN: [u8; 2] = [0x78, 0x01];
+
+    fn signed_transaction_bytes() -> Vec<u8> {
+        let mut bytes = services::SignedTransaction {
+            body_bytes: Vec::from([1, 2, 3]),
+            sig_map: Some(services::SignatureMap::default()),
+        }
+        .encode_to_vec();
+
+        bytes.extend_from_slice(&UNKNOWN);
+
+        bytes
+    }
+
+    #[test]
+    fn unknown_fields_are_found() {
+        assert_eq!(
+            unknown_fields::<services::SignedTransaction>(&signed_transaction_bytes()).unwrap(),
+            UNKNOWN
+        );
+    }
+
+    #[test]
+    fn strip_fields_keeps_the_rest() {
+        assert_eq!(strip_fields(&signed_transaction_bytes(), &[1, 2]).unwrap(), UNKNOWN);
+        assert_eq!(
+            strip_fields(&signed_transaction_bytes(), &[]).unwrap(),
+            signed_transaction_bytes()
+        );
+    }
+
+    #[test]
+    fn truncated_field_is_an_error() {
+        let bytes = signed_transaction_bytes();
+
+        assert!(strip_fields(&bytes[..bytes.len() - 1], &[]).is_err());
+    }
+}
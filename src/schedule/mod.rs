@@ -26,6 +26,7 @@ mod schedule_info;
 mod schedule_info_query;
 mod schedule_sign_transaction;
 
+pub(crate) use schedulable_transaction_body::scheduled_transaction_from_protobuf;
 pub use schedule_create_transaction::ScheduleCreateTransaction;
 pub(crate) use schedule_create_transaction::ScheduleCreateTransactionData;
 pub use schedule_delete_transaction::ScheduleDeleteTransaction;
@@ -3,10 +3,17 @@ use hedera_proto::services;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
     AnyTransactionData,
+    SignaturePrefixMode,
     ToSchedulableTransactionDataProtobuf,
+    TransactionBody,
     TransactionData,
 };
-use crate::Hbar;
+use crate::{
+    AnyTransaction,
+    Hbar,
+    Transaction,
+    TransactionId,
+};
 
 mod data {
     pub(super) use crate::account::{
@@ -64,6 +71,8 @@ mod data {
 // Hack: In rust this is this, but in swift this is just a less densely populatable `AnyTransaction`.
 // As a result, the sources stuff doesn't carry over... That can be fixed after SDK level FFI is removed.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub(super) struct SchedulableTransactionBody {
     pub(super) data: Box<AnySchedulableTransactionData>,
 
@@ -82,7 +91,33 @@ impl FromProtobuf<services::SchedulableTransactionBody> for SchedulableTransacti
     }
 }
 
+/// Converts a scheduled transaction body into a frozen, unsigned transaction, IE, for inspection.
+pub(crate) fn scheduled_transaction_from_protobuf(
+    pb: services::SchedulableTransactionBody,
+) -> crate::Result<AnyTransaction> {
+    Ok(SchedulableTransactionBody::from_protobuf(pb)?.into_transaction(None))
+}
+
 impl SchedulableTransactionBody {
+    /// Converts `self` into a frozen, unsigned transaction with the given `transaction_id`.
+    pub(super) fn into_transaction(self, transaction_id: Option<TransactionId>) -> AnyTransaction {
+        Transaction::from_parts(
+            TransactionBody {
+                data: (*self.data).into(),
+                node_account_ids: None,
+                transaction_valid_duration: None,
+                max_transaction_fee: self.max_transaction_fee,
+                transaction_memo: self.transaction_memo,
+                transaction_id,
+                operator: None,
+                is_frozen: true,
+                regenerate_transaction_id: Some(false),
+                signature_prefix_mode: SignaturePrefixMode::default(),
+            },
+            Vec::new(),
+        )
+    }
+
     pub(super) fn to_scheduled_body_protobuf(&self) -> services::SchedulableTransactionBody {
         services::SchedulableTransactionBody {
             data: Some(self.data.to_schedulable_transaction_data_protobuf()),
@@ -97,6 +132,8 @@ impl SchedulableTransactionBody {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub(super) enum AnySchedulableTransactionData {
     AccountCreate(data::AccountCreate),
     AccountUpdate(data::AccountUpdate),
@@ -35,6 +35,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -56,6 +57,8 @@ use crate::{
 pub type ScheduleCreateTransaction = Transaction<ScheduleCreateTransactionData>;
 
 #[derive(Default, Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ScheduleCreateTransactionData {
     scheduled_transaction: Option<SchedulableTransactionBody>,
 
@@ -165,7 +168,13 @@ impl ScheduleCreateTransaction {
     }
 }
 
-impl TransactionData for ScheduleCreateTransactionData {}
+impl TransactionData for ScheduleCreateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        if let Some(memo) = &self.schedule_memo {
+            violations.memo("schedule memo", memo);
+        }
+    }
+}
 
 impl TransactionExecute for ScheduleCreateTransactionData {
     fn execute(
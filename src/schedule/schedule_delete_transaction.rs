@@ -49,6 +49,8 @@ use crate::{
 pub type ScheduleDeleteTransaction = Transaction<ScheduleDeleteTransactionData>;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ScheduleDeleteTransactionData {
     schedule_id: Option<ScheduleId>,
 }
@@ -23,7 +23,10 @@ use time::OffsetDateTime;
 
 use super::schedulable_transaction_body::SchedulableTransactionBody;
 use crate::protobuf::ToProtobuf;
-use crate::transaction::TransactionBody;
+use crate::transaction::{
+    SignaturePrefixMode,
+    TransactionBody,
+};
 use crate::{
     AccountId,
     AnyTransaction,
@@ -39,6 +42,8 @@ use crate::{
 // TODO: scheduled_transaction
 /// Response from [`ScheduleInfoQuery`][crate::ScheduleInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ScheduleInfo {
     /// The ID of the schedule for which information is requested.
     pub schedule_id: ScheduleId,
@@ -108,6 +113,7 @@ impl ScheduleInfo {
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                signature_prefix_mode: SignaturePrefixMode::default(),
             },
             Vec::new(),
         ))
@@ -45,6 +45,8 @@ use crate::{
 pub type ScheduleSignTransaction = Transaction<ScheduleSignTransactionData>;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ScheduleSignTransactionData {
     schedule_id: Option<ScheduleId>,
 }
@@ -35,6 +35,8 @@ mod tests;
 /// Hedera follows [semantic versioning](https://semver.org) for both the HAPI protobufs and
 /// the Services software.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SemanticVersion {
     /// Increases with incompatible API changes
     pub major: u32,
@@ -0,0 +1,562 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! `serde` support for types that have a canonical string representation.
+//!
+//! IDs, keys and amounts are (de)serialized as the same strings that their [`Display`] and [`FromStr`] implementations use,
+//! so that they stay stable and readable in formats like JSON.
+
+use std::fmt::Display;
+use std::str::FromStr;
+
+use serde::de::Error as _;
+use serde::{
+    Deserialize,
+    Deserializer,
+    Serialize,
+    Serializer,
+};
+
+use crate::{
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EvmAddress,
+    FileId,
+    Hbar,
+    HbarUnit,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    TokenId,
+    TopicId,
+    TransactionHash,
+    TransactionId,
+};
+
+fn deserialize_from_str<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    String::deserialize(deserializer).and_then(|it| it.parse().map_err(D::Error::custom))
+}
+
+macro_rules! impl_serde_via_str {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl Serialize for $ty {
+                fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+                    serializer.collect_str(self)
+                }
+            }
+
+            impl<'de> Deserialize<'de> for $ty {
+                fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+                    deserialize_from_str(deserializer)
+                }
+            }
+        )*
+    };
+}
+
+impl_serde_via_str!(
+    AccountId,
+    ContractId,
+    DelegateContractId,
+    EvmAddress,
+    FileId,
+    LedgerId,
+    NftId,
+    PublicKey,
+    ScheduleId,
+    TokenId,
+    TopicId,
+    TransactionId,
+);
+
+// note: `Display` picks a unit based on the amount, which is lossy, so always use tinybars.
+impl Serialize for Hbar {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&format_args!("{} {}", self.to_tinybars(), HbarUnit::Tinybar))
+    }
+}
+
+impl<'de> Deserialize<'de> for Hbar {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer)
+    }
+}
+
+impl Serialize for TransactionHash {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TransactionHash {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let bytes = hex::deserialize(deserializer)?;
+
+        bytes
+            .as_slice()
+            .try_into()
+            .map(Self)
+            .map_err(|_| D::Error::invalid_length(bytes.len(), &"a 48 byte SHA-384 hash"))
+    }
+}
+
+/// (De)serializes an amount as a decimal string, for use with `#[serde(with = "...")]`.
+///
+/// Amounts can be larger than JSON numbers can represent exactly (in many parsers that's 2^53), so they're always strings.
+pub(crate) mod amount {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{
+        Deserializer,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<T: Display, S: Serializer>(
+        amount: &T,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(amount)
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        super::deserialize_from_str(deserializer)
+    }
+}
+
+/// Like [`amount`], but for `Option<T>`.
+pub(crate) mod amount_opt {
+    use std::fmt::Display;
+    use std::str::FromStr;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    use super::Amount;
+
+    pub(crate) fn serialize<T: Display, S: Serializer>(
+        amount: &Option<T>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match amount {
+            Some(amount) => serializer.serialize_some(&Amount(amount)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+    where
+        T: FromStr,
+        T::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(Option::<Amount<T>>::deserialize(deserializer)?.map(|it| it.0))
+    }
+}
+
+/// Like [`amount`], but for the values of a map, IE, `HashMap<TokenId, u64>`.
+pub(crate) mod amount_map {
+    use std::collections::HashMap;
+    use std::fmt::Display;
+    use std::hash::Hash;
+    use std::str::FromStr;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    use super::Amount;
+
+    pub(crate) fn serialize<K: Serialize, V: Display, S: Serializer>(
+        map: &HashMap<K, V>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_map(map.iter().map(|(key, value)| (key, Amount(value))))
+    }
+
+    pub(crate) fn deserialize<'de, K, V, D>(deserializer: D) -> Result<HashMap<K, V>, D::Error>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: FromStr,
+        V::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(HashMap::<K, Amount<V>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(key, value)| (key, value.0))
+            .collect())
+    }
+}
+
+/// Like [`amount_map`], but for maps of maps, IE, `HashMap<TokenId, HashMap<AccountId, i64>>`.
+pub(crate) mod amount_nested_map {
+    use std::collections::HashMap;
+    use std::fmt::Display;
+    use std::hash::Hash;
+    use std::str::FromStr;
+
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serialize,
+        Serializer,
+    };
+
+    struct AmountMap<M>(M);
+
+    impl<K: Serialize, V: Display> Serialize for AmountMap<&HashMap<K, V>> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            super::amount_map::serialize(self.0, serializer)
+        }
+    }
+
+    impl<'de, K, V> Deserialize<'de> for AmountMap<HashMap<K, V>>
+    where
+        K: Deserialize<'de> + Eq + Hash,
+        V: FromStr,
+        V::Err: Display,
+    {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            super::amount_map::deserialize(deserializer).map(Self)
+        }
+    }
+
+    pub(crate) fn serialize<K1, K2, V, S>(
+        map: &HashMap<K1, HashMap<K2, V>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        K1: Serialize,
+        K2: Serialize,
+        V: Display,
+        S: Serializer,
+    {
+        serializer.collect_map(map.iter().map(|(key, value)| (key, AmountMap(value))))
+    }
+
+    pub(crate) fn deserialize<'de, K1, K2, V, D>(
+        deserializer: D,
+    ) -> Result<HashMap<K1, HashMap<K2, V>>, D::Error>
+    where
+        K1: Deserialize<'de> + Eq + Hash,
+        K2: Deserialize<'de> + Eq + Hash,
+        V: FromStr,
+        V::Err: Display,
+        D: Deserializer<'de>,
+    {
+        Ok(HashMap::<K1, AmountMap<HashMap<K2, V>>>::deserialize(deserializer)?
+            .into_iter()
+            .map(|(key, value)| (key, value.0))
+            .collect())
+    }
+}
+
+/// Wrapper that (de)serializes the inner value as an [`amount`].
+struct Amount<T>(T);
+
+impl<T: Display> Serialize for Amount<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(&self.0)
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Amount<T>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserialize_from_str(deserializer).map(Self)
+    }
+}
+
+/// (De)serializes bytes as a hex string, for use with `#[serde(with = "...")]`.
+pub(crate) mod hex {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    pub(crate) fn serialize<S: Serializer>(
+        bytes: impl AsRef<[u8]>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&::hex::encode(bytes))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<u8>, D::Error> {
+        let s = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        ::hex::decode(s.strip_prefix("0x").unwrap_or(&s)).map_err(D::Error::custom)
+    }
+}
+
+/// Like [`hex`], but for `Option<Vec<u8>>`.
+pub(crate) mod hex_opt {
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(transparent)]
+    struct Hex(#[serde(with = "super::hex")] Vec<u8>);
+
+    pub(crate) fn serialize<S: Serializer>(
+        bytes: &Option<Vec<u8>>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match bytes {
+            Some(bytes) => serializer.serialize_some(&::hex::encode(bytes)),
+            None => serializer.serialize_none(),
+        }
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Vec<u8>>, D::Error> {
+        Ok(Option::<Hex>::deserialize(deserializer)?.map(|it| it.0))
+    }
+}
+
+/// Like [`hex`], but for `Vec<Vec<u8>>`.
+pub(crate) mod hex_vec {
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    #[derive(serde_derive::Deserialize)]
+    #[serde(transparent)]
+    struct Hex(#[serde(with = "super::hex")] Vec<u8>);
+
+    pub(crate) fn serialize<S: Serializer>(
+        items: &[Vec<u8>],
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(items.iter().map(::hex::encode))
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Vec<u8>>, D::Error> {
+        Ok(Vec::<Hex>::deserialize(deserializer)?.into_iter().map(|it| it.0).collect())
+    }
+}
+
+/// (De)serializes a [`Status`](crate::Status) as its protobuf name, IE, `"SUCCESS"`.
+pub(crate) mod status {
+    use serde::de::Error as _;
+    use serde::{
+        Deserialize,
+        Deserializer,
+        Serializer,
+    };
+
+    use crate::Status;
+
+    // signature required by `serde(with)`.
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn serialize<S: Serializer>(
+        status: &Status,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(status.as_str_name())
+    }
+
+    pub(crate) fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Status, D::Error> {
+        let name = <std::borrow::Cow<'de, str>>::deserialize(deserializer)?;
+
+        Status::from_str_name(&name)
+            .ok_or_else(|| D::Error::custom(format!("unknown status `{name}`")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use expect_test::expect;
+
+    use crate::transaction::test_helpers::unused_private_key;
+    use crate::{
+        AccountId,
+        FixedFeeData,
+        Hbar,
+        Key,
+        KeyList,
+        Status,
+        TokenId,
+        TransactionId,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn hbar_is_lossless() {
+        let hbar = Hbar::from_tinybars(150_000_001);
+
+        let json = serde_json::to_string(&hbar).unwrap();
+
+        assert_eq!(json, r#""150000001 tℏ""#);
+        assert_eq!(serde_json::from_str::<Hbar>(&json).unwrap(), hbar);
+
+        assert_eq!(
+            serde_json::from_str::<Hbar>(r#""1.5 ℏ""#).unwrap(),
+            Hbar::from_tinybars(150_000_000)
+        );
+    }
+
+    #[test]
+    fn ids_are_strings() {
+        let id: TransactionId = "0.0.5006@1554158542.5".parse().unwrap();
+
+        let json = serde_json::to_string(&id).unwrap();
+
+        assert_eq!(json, r#""0.0.5006@1554158542.5""#);
+        assert_eq!(serde_json::from_str::<TransactionId>(&json).unwrap(), id);
+
+        assert!(serde_json::from_str::<AccountId>(r#""0.0.x""#).is_err());
+    }
+
+    #[test]
+    fn key() {
+        let public_key = unused_private_key().public_key();
+
+        let key = Key::KeyList(KeyList { keys: vec![public_key.into()], threshold: Some(1) });
+
+        let json = serde_json::to_string(&key).unwrap();
+
+        expect![[r#"{"keyList":{"keys":[{"single":"302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7"}],"threshold":1}}"#]]
+            .assert_eq(&json);
+
+        assert_eq!(serde_json::from_str::<Key>(&json).unwrap(), key);
+    }
+
+    #[test]
+    fn status() {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq)]
+        struct Wrapper(#[serde(with = "super::status")] Status);
+
+        let json = serde_json::to_string(&Wrapper(Status::InvalidSignature)).unwrap();
+
+        assert_eq!(json, r#""INVALID_SIGNATURE""#);
+        assert_eq!(
+            serde_json::from_str::<Wrapper>(&json).unwrap(),
+            Wrapper(Status::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn amounts_are_strings() {
+        #[derive(serde_derive::Serialize, serde_derive::Deserialize, Debug, PartialEq)]
+        struct Wrapper(
+            #[serde(with = "super::amount_nested_map")] HashMap<TokenId, HashMap<AccountId, i64>>,
+        );
+
+        let mut record_transfers = HashMap::new();
+        record_transfers.insert(AccountId::new(0, 0, 2), i64::MAX);
+
+        let mut token_transfers = HashMap::new();
+        token_transfers.insert(TokenId::new(0, 0, 5), record_transfers);
+
+        let fee = FixedFeeData { amount: i64::MAX, denominating_token_id: None };
+
+        let json = serde_json::to_string(&fee).unwrap();
+
+        assert_eq!(json, r#"{"amount":"9223372036854775807","denominatingTokenId":null}"#);
+        assert_eq!(serde_json::from_str::<FixedFeeData>(&json).unwrap(), fee);
+
+        let json = serde_json::to_string(&Wrapper(token_transfers.clone())).unwrap();
+
+        assert_eq!(json, r#"{"0.0.5":{"0.0.2":"9223372036854775807"}}"#);
+        assert_eq!(serde_json::from_str::<Wrapper>(&json).unwrap(), Wrapper(token_transfers));
+    }
+
+    #[test]
+    fn transaction_round_trip() {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::from_tinybars(10))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::from_tinybars(-10))
+            .transaction_memo("hi");
+
+        let json = serde_json::to_string_pretty(&tx).unwrap();
+
+        expect![[r#"
+            {
+              "data": {
+                "transfers": [
+                  {
+                    "accountId": "0.0.2",
+                    "amount": "10",
+                    "isApproval": false
+                  },
+                  {
+                    "accountId": "0.0.101",
+                    "amount": "-10",
+                    "isApproval": false
+                  }
+                ],
+                "tokenTransfers": []
+              },
+              "nodeAccountIds": [
+                "0.0.5005",
+                "0.0.5006"
+              ],
+              "transactionValidDuration": null,
+              "maxTransactionFee": "200000000 tℏ",
+              "transactionMemo": "hi",
+              "transactionId": "0.0.5006@1554158542.0",
+              "regenerateTransactionId": null
+            }"#]]
+        .assert_eq(&json);
+
+        let tx2: TransferTransaction = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(serde_json::to_string_pretty(&tx2).unwrap(), json);
+    }
+}
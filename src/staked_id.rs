@@ -7,6 +7,8 @@ use crate::{
 // can't do boxing because `Copy`.
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub(crate) enum StakedId {
     AccountId(AccountId),
     NodeId(u64),
@@ -11,6 +11,8 @@ use crate::{
 // todo(sr): is this right?
 /// Info related to account/contract staking settings.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct StakingInfo {
     /// If `true`, the contract declines receiving a staking reward. The default value is `false`.
     pub decline_staking_reward: bool,
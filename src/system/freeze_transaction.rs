@@ -50,9 +50,12 @@ use crate::{
 pub type FreezeTransaction = Transaction<FreezeTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct FreezeTransactionData {
     start_time: Option<OffsetDateTime>,
     file_id: Option<FileId>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_opt"))]
     file_hash: Option<Vec<u8>>,
     freeze_type: FreezeType,
 }
@@ -21,6 +21,8 @@
 // todo(sr): Not happy with this doc.
 /// What type of freeze should be executed?
 #[derive(Default, Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub enum FreezeType {
     /// An (invalid) default value for this enum, to ensure the client explicitly sets
     /// the intended type of freeze transaction.
@@ -60,6 +60,8 @@ pub type SystemDeleteTransaction = Transaction<SystemDeleteTransactionData>;
 ///
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SystemDeleteTransactionData {
     expiration_time: Option<OffsetDateTime>,
     file_id: Option<FileId>,
@@ -49,6 +49,8 @@ pub type SystemUndeleteTransaction = Transaction<SystemUndeleteTransactionData>;
 
 /// Undelete a file or smart contract that was deleted by  [`SystemUndeleteTransaction`](crate::SystemUndeleteTransaction).
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct SystemUndeleteTransactionData {
     file_id: Option<FileId>,
     contract_id: Option<ContractId>,
@@ -11,8 +11,11 @@ use crate::{
 
 /// A custom transfer fee that was assessed during the handling of a `CryptoTransfer`.
 #[derive(Debug, Eq, PartialEq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct AssessedCustomFee {
     /// The amount of currency charged to each payer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     pub amount: i64,
 
     /// The currency `amount` is charged in, if `None` the fee is in HBar.
@@ -0,0 +1,765 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::{
+    HashMap,
+    HashSet,
+};
+
+use crate::{
+    AccountId,
+    AnyCustomFee,
+    AssessedCustomFee,
+    BalanceChange,
+    Error,
+    Fee,
+    FeeAssessmentMethod,
+    FixedFeeData,
+    FractionalFeeData,
+    RoyaltyFeeData,
+    TokenId,
+    TokenInfo,
+    TokenNftTransfer,
+    TransferTransaction,
+};
+
+/// The number of levels of transfers the network charges custom fees for.
+///
+/// Custom fees are charged for the transfers of a transaction, and for the fixed fees charged for those transfers, but no further.
+const MAX_CUSTOM_FEE_DEPTH: usize = 2;
+
+/// Predicts the custom fees the network would charge for a [`TransferTransaction`], without submitting it.
+///
+/// The simulator needs to know about every token with custom fees that the transaction transfers,
+/// including tokens that custom fees are denominated in, see [`token_info`](Self::token_info).
+///
+/// Balances aren't known to the simulator, so transfers the network would reject for insufficient balance are still simulated.
+#[derive(Debug, Clone, Default)]
+pub struct CustomFeeSimulator {
+    tokens: HashMap<TokenId, SimulatedToken>,
+}
+
+/// The result of [`CustomFeeSimulator::simulate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CustomFeeSimulation {
+    /// The custom fees the network is expected to charge, in the order it charges them.
+    pub assessed_custom_fees: Vec<AssessedCustomFee>,
+
+    /// The net change to every balance, including both the transfers of the transaction and the custom fees.
+    ///
+    /// Balances that don't change aren't included.
+    pub balance_changes: Vec<BalanceChange>,
+}
+
+#[derive(Debug, Clone)]
+struct SimulatedToken {
+    treasury_account_id: AccountId,
+    decimals: Option<u32>,
+    custom_fees: Vec<AnyCustomFee>,
+}
+
+impl SimulatedToken {
+    /// Returns `true` if `payer` doesn't pay `fee`.
+    fn is_exempt(&self, payer: AccountId, fee: &AnyCustomFee) -> bool {
+        payer == self.treasury_account_id
+            || fee.fee_collector_account_id == Some(payer)
+            || (fee.all_collectors_are_exempt
+                && self.custom_fees.iter().any(|it| it.fee_collector_account_id == Some(payer)))
+    }
+}
+
+/// A fungible debit that custom fees get charged for.
+#[derive(Debug, Clone, Copy)]
+struct Debit {
+    token_id: TokenId,
+    payer: AccountId,
+    amount: i64,
+}
+
+/// The net balance changes of a single level of transfers.
+#[derive(Debug, Default)]
+struct Ledger(Vec<(Option<TokenId>, AccountId, i64)>);
+
+impl Ledger {
+    fn add(&mut self, token_id: Option<TokenId>, account_id: AccountId, amount: i64) {
+        match self.0.iter_mut().find(|it| it.0 == token_id && it.1 == account_id) {
+            Some(change) => change.2 = change.2.saturating_add(amount),
+            None => self.0.push((token_id, account_id, amount)),
+        }
+    }
+
+    /// Takes `amount` out of the credits of `token_id`, in proportion to each credit.
+    ///
+    /// Returns the accounts whose credits were reduced, or `None` if the credits don't cover `amount`.
+    fn reclaim(&mut self, token_id: Option<TokenId>, amount: i64) -> Option<Vec<AccountId>> {
+        let credits: Vec<_> = self
+            .0
+            .iter()
+            .enumerate()
+            .filter(|(_, it)| it.0 == token_id && it.2 > 0)
+            .map(|(index, it)| (index, it.2))
+            .collect();
+
+        let available: i128 = credits.iter().map(|it| i128::from(it.1)).sum();
+
+        if available < i128::from(amount) {
+            return None;
+        }
+
+        let mut taken: Vec<i64> = credits
+            .iter()
+            .map(|&(_, credit)| {
+                (i128::from(amount) * i128::from(credit) / available).try_into().unwrap()
+            })
+            .collect();
+
+        // rounding down leaves a little behind, take it from the first credits that still have anything.
+        let mut leftover = amount - taken.iter().sum::<i64>();
+
+        for (taken, &(_, credit)) in taken.iter_mut().zip(&credits) {
+            let more = leftover.min(credit - *taken);
+            *taken += more;
+            leftover -= more;
+        }
+
+        let mut payers = Vec::new();
+
+        for (taken, (index, _)) in taken.into_iter().zip(credits) {
+            if taken > 0 {
+                self.0[index].2 -= taken;
+                payers.push(self.0[index].1);
+            }
+        }
+
+        Some(payers)
+    }
+}
+
+/// The state of charging custom fees for a single level of transfers.
+struct Level<'a> {
+    current: &'a mut Ledger,
+    next: Ledger,
+    nested: Vec<Debit>,
+    assessed: &'a mut Vec<AssessedCustomFee>,
+    fixed_fees_charged: HashSet<(TokenId, AccountId)>,
+    royalties_charged: HashSet<(TokenId, AccountId)>,
+}
+
+impl CustomFeeSimulator {
+    /// Create a new, empty, `CustomFeeSimulator`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Adds a token's custom fees, treasury and decimals from its [`TokenInfo`].
+    pub fn token_info(&mut self, info: &TokenInfo) -> &mut Self {
+        self.tokens.insert(
+            info.token_id,
+            SimulatedToken {
+                treasury_account_id: info.treasury_account_id,
+                decimals: Some(info.decimals),
+                custom_fees: info.custom_fees.clone(),
+            },
+        );
+
+        self
+    }
+
+    /// Adds a token's custom fees and treasury (which doesn't pay any custom fees).
+    pub fn token_custom_fees(
+        &mut self,
+        token_id: TokenId,
+        treasury_account_id: AccountId,
+        custom_fees: impl IntoIterator<Item = AnyCustomFee>,
+    ) -> &mut Self {
+        self.tokens.insert(
+            token_id,
+            SimulatedToken {
+                treasury_account_id,
+                decimals: None,
+                custom_fees: custom_fees.into_iter().collect(),
+            },
+        );
+
+        self
+    }
+
+    /// Predicts the custom fees the network would charge for `transaction`, and the resulting balance changes.
+    ///
+    /// # Errors
+    /// - [`Error::CustomFeeSimulation`] if the network would reject the transaction because of its custom fees, IE,
+    ///   if the fees are nested too deeply, or if an inclusive fractional fee is more than the amount received.
+    pub fn simulate(
+        &self,
+        transaction: &TransferTransaction,
+    ) -> crate::Result<CustomFeeSimulation> {
+        let data = transaction.data();
+
+        let mut levels = Vec::from([Ledger::default()]);
+
+        for (token_id, account_id, amount) in data.fungible_transfers() {
+            levels[0].add(token_id, account_id, amount);
+        }
+
+        let mut debits: Vec<_> = levels[0]
+            .0
+            .iter()
+            .filter_map(|&(token_id, payer, amount)| {
+                Some(Debit { token_id: token_id?, payer, amount: -amount })
+                    .filter(|it| it.amount > 0)
+            })
+            .collect();
+
+        let mut nft_transfers: Vec<_> = data.nft_transfers().collect();
+        let mut assessed_custom_fees = Vec::new();
+
+        for depth in 0.. {
+            if debits.is_empty() && nft_transfers.is_empty() {
+                break;
+            }
+
+            let mut level = Level {
+                current: levels.last_mut().unwrap(),
+                next: Ledger::default(),
+                nested: Vec::new(),
+                assessed: &mut assessed_custom_fees,
+                fixed_fees_charged: HashSet::new(),
+                royalties_charged: HashSet::new(),
+            };
+
+            for debit in debits {
+                self.charge_debit(&mut level, debit, depth)?;
+            }
+
+            for transfer in nft_transfers.drain(..) {
+                self.charge_nft_transfer(&mut level, transfer);
+            }
+
+            let Level { next, nested, .. } = level;
+
+            debits = nested;
+            levels.push(next);
+        }
+
+        Ok(CustomFeeSimulation {
+            assessed_custom_fees,
+            balance_changes: self.balance_changes(&levels),
+        })
+    }
+
+    fn charge_debit(&self, level: &mut Level<'_>, debit: Debit, depth: usize) -> crate::Result<()> {
+        let Some(token) = self.tokens.get(&debit.token_id) else {
+            return Ok(());
+        };
+
+        let mut fees = token.custom_fees.iter().filter(|fee| !token.is_exempt(debit.payer, fee));
+
+        if depth >= MAX_CUSTOM_FEE_DEPTH {
+            return match fees.next() {
+                Some(_) => Err(Error::CustomFeeSimulation {
+                    token_id: Box::new(debit.token_id),
+                    reason: "custom fees are nested deeper than the network allows",
+                }),
+                None => Ok(()),
+            };
+        }
+
+        for fee in fees {
+            let Some(collector) = fee.fee_collector_account_id else {
+                continue;
+            };
+
+            match &fee.fee {
+                Fee::Fixed(fixed) => {
+                    self.charge_fixed(level, debit.token_id, fixed, collector, debit.payer);
+                }
+
+                Fee::Fractional(fractional) => {
+                    charge_fractional(level, debit, fractional, collector)?;
+                }
+
+                // royalty fees are only charged for NFTs.
+                Fee::Royalty(_) => {}
+            }
+        }
+
+        Ok(())
+    }
+
+    fn charge_nft_transfer(&self, level: &mut Level<'_>, transfer: &TokenNftTransfer) {
+        let Some(token) = self.tokens.get(&transfer.token_id) else {
+            return;
+        };
+
+        for fee in &token.custom_fees {
+            let Some(collector) = fee.fee_collector_account_id else {
+                continue;
+            };
+
+            match &fee.fee {
+                Fee::Fixed(fixed) => {
+                    if !token.is_exempt(transfer.sender, fee) {
+                        self.charge_fixed(
+                            level,
+                            transfer.token_id,
+                            fixed,
+                            collector,
+                            transfer.sender,
+                        );
+                    }
+                }
+
+                Fee::Royalty(royalty) => {
+                    self.charge_royalty(level, token, fee, transfer, royalty, collector);
+                }
+
+                // fractional fees are only charged for fungible tokens.
+                Fee::Fractional(_) => {}
+            }
+        }
+    }
+
+    /// Charges `fee` (for transferring `token_id`) to `payer`, at most once per level.
+    fn charge_fixed(
+        &self,
+        level: &mut Level<'_>,
+        token_id: TokenId,
+        fee: &FixedFeeData,
+        collector: AccountId,
+        payer: AccountId,
+    ) {
+        if !level.fixed_fees_charged.insert((token_id, payer)) {
+            return;
+        }
+
+        let denominating_token_id = fee.denominating_token_id;
+
+        level.next.add(denominating_token_id, payer, -fee.amount);
+        level.next.add(denominating_token_id, collector, fee.amount);
+
+        level.assessed.push(AssessedCustomFee {
+            amount: fee.amount,
+            token_id: denominating_token_id,
+            fee_collector_account_id: Some(collector),
+            payer_account_id_list: Vec::from([payer]),
+        });
+
+        // fees paid in a different token are transfers of that token, which may have custom fees of its own.
+        if let Some(denominating_token_id) = denominating_token_id {
+            let has_custom_fees = self
+                .tokens
+                .get(&denominating_token_id)
+                .map_or(false, |it| !it.custom_fees.is_empty());
+
+            if denominating_token_id != token_id && has_custom_fees {
+                level.nested.push(Debit {
+                    token_id: denominating_token_id,
+                    payer,
+                    amount: fee.amount,
+                });
+            }
+        }
+    }
+
+    fn charge_royalty(
+        &self,
+        level: &mut Level<'_>,
+        token: &SimulatedToken,
+        fee: &AnyCustomFee,
+        transfer: &TokenNftTransfer,
+        royalty: &RoyaltyFeeData,
+        collector: AccountId,
+    ) {
+        // the fungible value the sender receives in exchange for the NFT.
+        let exchanged: Vec<_> = level
+            .current
+            .0
+            .iter()
+            .filter(|it| it.1 == transfer.sender && it.2 > 0)
+            .map(|it| (it.0, it.2))
+            .collect();
+
+        if exchanged.is_empty() {
+            // with nothing exchanged the receiver pays the fallback fee (if any) instead.
+            if let Some(fallback_fee) = &royalty.fallback_fee {
+                if !token.is_exempt(transfer.receiver, fee) {
+                    self.charge_fixed(
+                        level,
+                        transfer.token_id,
+                        fallback_fee,
+                        collector,
+                        transfer.receiver,
+                    );
+                }
+            }
+
+            return;
+        }
+
+        if token.is_exempt(transfer.sender, fee)
+            || !level.royalties_charged.insert((transfer.token_id, transfer.sender))
+        {
+            return;
+        }
+
+        for (token_id, value) in exchanged {
+            let amount = fraction_of(value, royalty.numerator, royalty.denominator);
+
+            if amount == 0 {
+                continue;
+            }
+
+            level.current.add(token_id, transfer.sender, -amount);
+            level.next.add(token_id, collector, amount);
+
+            level.assessed.push(AssessedCustomFee {
+                amount,
+                token_id,
+                fee_collector_account_id: Some(collector),
+                payer_account_id_list: Vec::from([transfer.sender]),
+            });
+        }
+    }
+
+    fn balance_changes(&self, levels: &[Ledger]) -> Vec<BalanceChange> {
+        let mut total = Ledger::default();
+
+        for &(token_id, account_id, amount) in levels.iter().flat_map(|it| &it.0) {
+            total.add(token_id, account_id, amount);
+        }
+
+        total
+            .0
+            .into_iter()
+            .filter(|it| it.2 != 0)
+            .map(|(token_id, account_id, amount)| BalanceChange {
+                account_id,
+                token_id,
+                amount,
+                decimals: token_id.and_then(|it| self.tokens.get(&it)).and_then(|it| it.decimals),
+            })
+            .collect()
+    }
+}
+
+fn charge_fractional(
+    level: &mut Level<'_>,
+    debit: Debit,
+    fee: &FractionalFeeData,
+    collector: AccountId,
+) -> crate::Result<()> {
+    let mut amount =
+        fraction_of(debit.amount, fee.numerator, fee.denominator).max(fee.minimum_amount);
+
+    if fee.maximum_amount > 0 {
+        amount = amount.min(fee.maximum_amount);
+    }
+
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    let token_id = Some(debit.token_id);
+
+    let payers = match fee.assessment_method {
+        // the fee comes out of what the receivers get.
+        FeeAssessmentMethod::Inclusive => {
+            level.current.reclaim(token_id, amount).ok_or_else(|| Error::CustomFeeSimulation {
+                token_id: Box::new(debit.token_id),
+                reason: "an inclusive fractional fee is more than the amount received",
+            })?
+        }
+
+        // the fee is charged to the sender on top of the transfer.
+        FeeAssessmentMethod::Exclusive => {
+            level.next.add(token_id, debit.payer, -amount);
+            Vec::from([debit.payer])
+        }
+    };
+
+    level.next.add(token_id, collector, amount);
+
+    level.assessed.push(AssessedCustomFee {
+        amount,
+        token_id,
+        fee_collector_account_id: Some(collector),
+        payer_account_id_list: payers,
+    });
+
+    Ok(())
+}
+
+/// Returns `amount * numerator / denominator`, rounded down.
+fn fraction_of(amount: i64, numerator: u64, denominator: u64) -> i64 {
+    if denominator == 0 {
+        return 0;
+    }
+
+    (i128::from(amount) * i128::from(numerator) / i128::from(denominator))
+        .try_into()
+        .unwrap_or(i64::MAX)
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::CustomFeeSimulator;
+    use crate::{
+        AccountId,
+        AnyCustomFee,
+        AssessedCustomFee,
+        BalanceChange,
+        Error,
+        FeeAssessmentMethod,
+        FixedFeeData,
+        FractionalFeeData,
+        Hbar,
+        RoyaltyFeeData,
+        TokenId,
+        TransferTransaction,
+    };
+
+    const TOKEN: TokenId = TokenId::new(0, 0, 1000);
+    const FEE_TOKEN: TokenId = TokenId::new(0, 0, 1001);
+    const FEE_FEE_TOKEN: TokenId = TokenId::new(0, 0, 1002);
+    const TREASURY: AccountId = AccountId::new(0, 0, 10);
+    const ALICE: AccountId = AccountId::new(0, 0, 11);
+    const BOB: AccountId = AccountId::new(0, 0, 12);
+    const COLLECTOR: AccountId = AccountId::new(0, 0, 13);
+
+    fn custom_fee(fee: impl Into<crate::Fee>) -> AnyCustomFee {
+        AnyCustomFee {
+            fee: fee.into(),
+            fee_collector_account_id: Some(COLLECTOR),
+            all_collectors_are_exempt: false,
+        }
+    }
+
+    fn fractional(
+        numerator: u64,
+        denominator: u64,
+        minimum_amount: i64,
+        maximum_amount: i64,
+        assessment_method: FeeAssessmentMethod,
+    ) -> AnyCustomFee {
+        custom_fee(FractionalFeeData {
+            denominator,
+            numerator,
+            minimum_amount,
+            maximum_amount,
+            assessment_method,
+        })
+    }
+
+    fn simulator(fees: impl IntoIterator<Item = AnyCustomFee>) -> CustomFeeSimulator {
+        let mut simulator = CustomFeeSimulator::new();
+        simulator.token_custom_fees(TOKEN, TREASURY, fees);
+        simulator
+    }
+
+    fn send_tokens(amount: i64) -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, ALICE, -amount).token_transfer(TOKEN, BOB, amount);
+        tx
+    }
+
+    fn change(account_id: AccountId, token_id: Option<TokenId>, amount: i64) -> BalanceChange {
+        BalanceChange { account_id, token_id, amount, decimals: None }
+    }
+
+    fn assessed(amount: i64, token_id: Option<TokenId>, payer: AccountId) -> AssessedCustomFee {
+        AssessedCustomFee {
+            amount,
+            token_id,
+            fee_collector_account_id: Some(COLLECTOR),
+            payer_account_id_list: Vec::from([payer]),
+        }
+    }
+
+    #[test]
+    fn fixed_hbar_fee() -> crate::Result<()> {
+        let simulation = simulator([custom_fee(FixedFeeData::from_hbar(Hbar::new(1)))])
+            .simulate(&send_tokens(100))?;
+
+        assert_eq!(simulation.assessed_custom_fees, [assessed(100_000_000, None, ALICE)]);
+        assert_eq!(
+            simulation.balance_changes,
+            [
+                change(ALICE, Some(TOKEN), -100),
+                change(BOB, Some(TOKEN), 100),
+                change(ALICE, None, -100_000_000),
+                change(COLLECTOR, None, 100_000_000),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fractional_inclusive_maximum() -> crate::Result<()> {
+        let simulation = simulator([fractional(1, 10, 0, 5, FeeAssessmentMethod::Inclusive)])
+            .simulate(&send_tokens(100))?;
+
+        assert_eq!(simulation.assessed_custom_fees, [assessed(5, Some(TOKEN), BOB)]);
+        assert_eq!(
+            simulation.balance_changes,
+            [
+                change(ALICE, Some(TOKEN), -100),
+                change(BOB, Some(TOKEN), 95),
+                change(COLLECTOR, Some(TOKEN), 5),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fractional_exclusive_minimum() -> crate::Result<()> {
+        let simulation = simulator([fractional(1, 100, 3, 0, FeeAssessmentMethod::Exclusive)])
+            .simulate(&send_tokens(100))?;
+
+        assert_eq!(simulation.assessed_custom_fees, [assessed(3, Some(TOKEN), ALICE)]);
+        assert_eq!(
+            simulation.balance_changes,
+            [
+                change(ALICE, Some(TOKEN), -103),
+                change(BOB, Some(TOKEN), 100),
+                change(COLLECTOR, Some(TOKEN), 3),
+            ]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn fractional_inclusive_too_large() {
+        let res = simulator([fractional(1, 10, 200, 0, FeeAssessmentMethod::Inclusive)])
+            .simulate(&send_tokens(100));
+
+        assert_matches!(res, Err(Error::CustomFeeSimulation { .. }));
+    }
+
+    #[test]
+    fn exempt_payers() -> crate::Result<()> {
+        let fee = AnyCustomFee {
+            all_collectors_are_exempt: true,
+            ..custom_fee(FixedFeeData::from_hbar(Hbar::new(1)))
+        };
+
+        let other_collector = AnyCustomFee {
+            fee_collector_account_id: Some(ALICE),
+            ..custom_fee(FixedFeeData::from_hbar(Hbar::new(2)))
+        };
+
+        let simulation = simulator([fee, other_collector]).simulate(&send_tokens(100))?;
+        assert!(simulation.assessed_custom_fees.is_empty());
+
+        let mut tx = TransferTransaction::new();
+        tx.token_transfer(TOKEN, TREASURY, -100).token_transfer(TOKEN, BOB, 100);
+
+        let simulation =
+            simulator([custom_fee(FixedFeeData::from_hbar(Hbar::new(1)))]).simulate(&tx)?;
+        assert!(simulation.assessed_custom_fees.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn royalty_fee() -> crate::Result<()> {
+        let royalty = custom_fee(RoyaltyFeeData {
+            denominator: 10,
+            numerator: 1,
+            fallback_fee: Some(FixedFeeData::from_hbar(Hbar::new(5))),
+        });
+
+        let mut sale = TransferTransaction::new();
+        sale.nft_transfer(TOKEN.nft(1), ALICE, BOB)
+            .hbar_transfer(BOB, Hbar::new(-100))
+            .hbar_transfer(ALICE, Hbar::new(100));
+
+        let simulation = simulator([royalty.clone()]).simulate(&sale)?;
+
+        assert_eq!(simulation.assessed_custom_fees, [assessed(1_000_000_000, None, ALICE)]);
+        assert_eq!(
+            simulation.balance_changes,
+            [
+                change(BOB, None, -10_000_000_000),
+                change(ALICE, None, 9_000_000_000),
+                change(COLLECTOR, None, 1_000_000_000),
+            ]
+        );
+
+        // without anything exchanged for the NFT, the receiver pays the fallback fee.
+        let mut gift = TransferTransaction::new();
+        gift.nft_transfer(TOKEN.nft(1), ALICE, BOB);
+
+        let simulation = simulator([royalty]).simulate(&gift)?;
+
+        assert_eq!(simulation.assessed_custom_fees, [assessed(500_000_000, None, BOB)]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nested_fees() -> crate::Result<()> {
+        let mut simulator = simulator([custom_fee(FixedFeeData {
+            amount: 10,
+            denominating_token_id: Some(FEE_TOKEN),
+        })]);
+
+        simulator.token_custom_fees(
+            FEE_TOKEN,
+            TREASURY,
+            [custom_fee(FixedFeeData::from_hbar(Hbar::from_tinybars(1)))],
+        );
+
+        let simulation = simulator.simulate(&send_tokens(100))?;
+
+        assert_eq!(
+            simulation.assessed_custom_fees,
+            [assessed(10, Some(FEE_TOKEN), ALICE), assessed(1, None, ALICE)]
+        );
+
+        // a third level of fees is too deep.
+        simulator
+            .token_custom_fees(
+                FEE_TOKEN,
+                TREASURY,
+                [custom_fee(FixedFeeData {
+                    amount: 10,
+                    denominating_token_id: Some(FEE_FEE_TOKEN),
+                })],
+            )
+            .token_custom_fees(
+                FEE_FEE_TOKEN,
+                TREASURY,
+                [custom_fee(FixedFeeData::from_hbar(Hbar::from_tinybars(1)))],
+            );
+
+        assert_matches!(
+            simulator.simulate(&send_tokens(100)),
+            Err(Error::CustomFeeSimulation { token_id, .. }) if *token_id == FEE_FEE_TOKEN
+        );
+
+        Ok(())
+    }
+}
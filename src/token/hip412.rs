@@ -0,0 +1,612 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Typed NFT metadata, as specified by [HIP-412](https://hips.hedera.com/hip/hip-412).
+
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::{
+    Map,
+    Value,
+};
+
+use super::token_mint_transaction::MAX_METADATA_LEN;
+use crate::Error;
+
+/// The `format` of metadata following this version of HIP-412.
+const FORMAT: &str = "HIP412@2.0.0";
+
+/// The JSON metadata of an NFT, as specified by [HIP-412](https://hips.hedera.com/hip/hip-412).
+///
+/// The JSON is stored off chain (for instance on IPFS), and the NFT's on chain metadata points to it,
+/// see [`NftMetadataUri`].
+#[derive(Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Hip412Metadata {
+    /// The name of the NFT.
+    pub name: String,
+
+    /// The artist(s) who created the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub creator: Option<String>,
+
+    /// The decentralized identifier of the creator.
+    #[serde(rename = "creatorDID", default, skip_serializing_if = "Option::is_none")]
+    pub creator_did: Option<String>,
+
+    /// A human readable description of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+
+    /// A URI pointing to the preview image of the NFT.
+    pub image: String,
+
+    /// The hex encoded SHA-256 hash of the preview image.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// The MIME type of the preview image, for instance `image/png`.
+    #[serde(rename = "type")]
+    pub mime_type: String,
+
+    /// The format of this metadata, `HIP412@2.0.0` when created with [`new`](Self::new).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub format: Option<String>,
+
+    /// Arbitrary properties of the NFT that don't affect its rarity.
+    #[serde(default, skip_serializing_if = "Map::is_empty")]
+    pub properties: Map<String, Value>,
+
+    /// The files of the NFT, for instance the full resolution artwork.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub files: Vec<Hip412File>,
+
+    /// The traits of the NFT, which define its rarity.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub attributes: Vec<Hip412Attribute>,
+
+    /// Where to find translations of this metadata.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub localization: Option<Hip412Localization>,
+}
+
+/// A file of an NFT in its [`Hip412Metadata`].
+#[derive(Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct Hip412File {
+    /// A URI pointing to the file.
+    pub uri: String,
+
+    /// The hex encoded SHA-256 hash of the file.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub checksum: Option<String>,
+
+    /// Whether this is the main file of the NFT.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub is_default_file: Option<bool>,
+
+    /// The MIME type of the file.
+    #[serde(rename = "type")]
+    pub mime_type: String,
+
+    /// A URI pointing to metadata of the file itself.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metadata_uri: Option<String>,
+}
+
+/// A trait of an NFT in its [`Hip412Metadata`].
+#[derive(Debug, Clone, PartialEq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct Hip412Attribute {
+    /// The name of the trait.
+    pub trait_type: String,
+
+    /// How the trait should be displayed, for instance `percentage` or `date`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub display_type: Option<String>,
+
+    /// The value of the trait, a string, number or boolean.
+    pub value: Value,
+
+    /// The largest possible value of a numeric trait.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_value: Option<Value>,
+}
+
+/// Where to find translations of [`Hip412Metadata`].
+#[derive(Debug, Clone, PartialEq, Eq, serde_derive::Serialize, serde_derive::Deserialize)]
+pub struct Hip412Localization {
+    /// A URI template for the translated metadata, where `{locale}` is replaced with the locale.
+    pub uri: String,
+
+    /// The two letter ISO 639-1 code of the locale of the metadata itself.
+    pub default: String,
+
+    /// The two letter ISO 639-1 codes of the other locales the metadata is available in.
+    pub locales: Vec<String>,
+}
+
+impl Hip412Metadata {
+    /// Create new metadata with the fields HIP-412 requires.
+    ///
+    /// `image` is a URI pointing to the preview image of the NFT, and `mime_type` is the MIME type of that image.
+    #[must_use]
+    pub fn new(
+        name: impl Into<String>,
+        image: impl Into<String>,
+        mime_type: impl Into<String>,
+    ) -> Self {
+        Self {
+            name: name.into(),
+            creator: None,
+            creator_did: None,
+            description: None,
+            image: image.into(),
+            checksum: None,
+            mime_type: mime_type.into(),
+            format: Some(FORMAT.to_owned()),
+            properties: Map::new(),
+            files: Vec::new(),
+            attributes: Vec::new(),
+            localization: None,
+        }
+    }
+
+    /// Sets the artist(s) who created the NFT.
+    pub fn creator(&mut self, creator: impl Into<String>) -> &mut Self {
+        self.creator = Some(creator.into());
+
+        self
+    }
+
+    /// Sets the human readable description of the NFT.
+    pub fn description(&mut self, description: impl Into<String>) -> &mut Self {
+        self.description = Some(description.into());
+
+        self
+    }
+
+    /// Sets the property `key` of the NFT.
+    pub fn property(&mut self, key: impl Into<String>, value: impl Into<Value>) -> &mut Self {
+        self.properties.insert(key.into(), value.into());
+
+        self
+    }
+
+    /// Adds a file to the NFT.
+    pub fn file(&mut self, uri: impl Into<String>, mime_type: impl Into<String>) -> &mut Self {
+        self.files.push(Hip412File {
+            uri: uri.into(),
+            checksum: None,
+            is_default_file: None,
+            mime_type: mime_type.into(),
+            metadata_uri: None,
+        });
+
+        self
+    }
+
+    /// Adds a trait to the NFT.
+    pub fn attribute(
+        &mut self,
+        trait_type: impl Into<String>,
+        value: impl Into<Value>,
+    ) -> &mut Self {
+        self.attributes.push(Hip412Attribute {
+            trait_type: trait_type.into(),
+            display_type: None,
+            value: value.into(),
+            max_value: None,
+        });
+
+        self
+    }
+
+    /// Parses and validates metadata from `json`.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`] if `json` isn't valid HIP-412 metadata.
+    pub fn from_json(json: &str) -> crate::Result<Self> {
+        let metadata: Self = serde_json::from_str(json).map_err(Error::hip412)?;
+        metadata.validate()?;
+
+        Ok(metadata)
+    }
+
+    /// Validates `self` and serializes it to JSON.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`] if `self` isn't valid HIP-412 metadata.
+    pub fn to_json(&self) -> crate::Result<String> {
+        self.validate()?;
+
+        serde_json::to_string(self).map_err(Error::hip412)
+    }
+
+    /// Checks that `self` is valid HIP-412 metadata.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`] describing the first problem found.
+    pub fn validate(&self) -> crate::Result<()> {
+        if self.name.trim().is_empty() {
+            return Err(Error::hip412("`name` must not be empty"));
+        }
+
+        check_uri("image", &self.image)?;
+        check_mime_type("type", &self.mime_type)?;
+
+        if let Some(checksum) = &self.checksum {
+            check_checksum("checksum", checksum)?;
+        }
+
+        if let Some(format) = &self.format {
+            if !format.starts_with("HIP412@") {
+                return Err(Error::hip412(format!(
+                    "`format` must be `HIP412@<version>`, got `{format}`"
+                )));
+            }
+        }
+
+        for (index, file) in self.files.iter().enumerate() {
+            check_uri(&format!("files[{index}].uri"), &file.uri)?;
+            check_mime_type(&format!("files[{index}].type"), &file.mime_type)?;
+
+            if let Some(checksum) = &file.checksum {
+                check_checksum(&format!("files[{index}].checksum"), checksum)?;
+            }
+
+            if let Some(uri) = &file.metadata_uri {
+                check_uri(&format!("files[{index}].metadata_uri"), uri)?;
+            }
+        }
+
+        for (index, attribute) in self.attributes.iter().enumerate() {
+            if attribute.trait_type.trim().is_empty() {
+                return Err(Error::hip412(format!(
+                    "`attributes[{index}].trait_type` must not be empty"
+                )));
+            }
+
+            if !matches!(attribute.value, Value::String(_) | Value::Number(_) | Value::Bool(_)) {
+                return Err(Error::hip412(format!(
+                    "`attributes[{index}].value` must be a string, number or boolean"
+                )));
+            }
+
+            if attribute.max_value.as_ref().map_or(false, |it| !it.is_number()) {
+                return Err(Error::hip412(format!(
+                    "`attributes[{index}].max_value` must be a number"
+                )));
+            }
+        }
+
+        if let Some(localization) = &self.localization {
+            localization.validate()?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Hip412Localization {
+    fn validate(&self) -> crate::Result<()> {
+        if !self.uri.contains("{locale}") {
+            return Err(Error::hip412("`localization.uri` must contain `{locale}`"));
+        }
+
+        check_uri("localization.uri", &self.uri)?;
+
+        let is_locale = |it: &str| it.len() == 2 && it.bytes().all(|it| it.is_ascii_lowercase());
+
+        if !is_locale(&self.default) {
+            return Err(Error::hip412(format!(
+                "`localization.default` must be a two letter locale, got `{}`",
+                self.default
+            )));
+        }
+
+        for (index, locale) in self.locales.iter().enumerate() {
+            if !is_locale(locale) {
+                return Err(Error::hip412(format!(
+                    "`localization.locales[{index}]` must be a two letter locale, got `{locale}`"
+                )));
+            }
+
+            if *locale == self.default || self.locales[..index].contains(locale) {
+                return Err(Error::hip412(format!(
+                    "`localization.locales` must not repeat `{locale}`"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The on chain metadata of an NFT that follows HIP-412: a URI pointing to its [`Hip412Metadata`].
+///
+/// Pass it to [`TokenMintTransaction::metadata`](crate::TokenMintTransaction::metadata),
+/// and get it back with [`TokenNftInfo::metadata_uri`](crate::TokenNftInfo::metadata_uri).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct NftMetadataUri(String);
+
+impl NftMetadataUri {
+    /// Create a new `NftMetadataUri` from `uri`.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`] if `uri` isn't a URI or is longer than the network allows for NFT metadata.
+    pub fn new(uri: impl Into<String>) -> crate::Result<Self> {
+        let uri = uri.into();
+
+        check_uri("metadata URI", &uri)?;
+
+        if uri.len() > MAX_METADATA_LEN {
+            return Err(Error::hip412(format!(
+                "metadata URI is {} bytes, the maximum is {MAX_METADATA_LEN}",
+                uri.len()
+            )));
+        }
+
+        Ok(Self(uri))
+    }
+
+    /// Create a new `NftMetadataUri` pointing to the IPFS content identifier `cid`.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`] if the URI is longer than the network allows for NFT metadata.
+    pub fn ipfs(cid: &str) -> crate::Result<Self> {
+        Self::new(format!("ipfs://{cid}"))
+    }
+
+    /// Parses the on chain metadata of an NFT.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`] if `bytes` aren't a UTF-8 URI.
+    pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        std::str::from_utf8(bytes).map_err(Error::hip412).and_then(Self::new)
+    }
+
+    /// Returns the URI as the bytes to store on chain.
+    #[must_use]
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.as_bytes().to_vec()
+    }
+
+    /// Returns the URI.
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+}
+
+impl AsRef<[u8]> for NftMetadataUri {
+    fn as_ref(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+}
+
+impl fmt::Display for NftMetadataUri {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl FromStr for NftMetadataUri {
+    type Err = Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::new(s)
+    }
+}
+
+/// Checks that `value` looks like a URI, that is, `scheme:rest`.
+fn check_uri(field: &str, value: &str) -> crate::Result<()> {
+    let is_uri = value.split_once(':').map_or(false, |(scheme, rest)| {
+        let mut chars = scheme.chars();
+
+        chars.next().map_or(false, |it| it.is_ascii_alphabetic())
+            && chars.all(|it| it.is_ascii_alphanumeric() || matches!(it, '+' | '-' | '.'))
+            && !rest.is_empty()
+            && !value.contains(char::is_whitespace)
+    });
+
+    if !is_uri {
+        return Err(Error::hip412(format!("`{field}` must be a URI, got `{value}`")));
+    }
+
+    Ok(())
+}
+
+/// Checks that `value` looks like a MIME type, that is, `type/subtype`.
+fn check_mime_type(field: &str, value: &str) -> crate::Result<()> {
+    let is_mime_type = value.split_once('/').map_or(false, |(kind, subtype)| {
+        !kind.is_empty() && !subtype.is_empty() && !value.contains(char::is_whitespace)
+    });
+
+    if !is_mime_type {
+        return Err(Error::hip412(format!("`{field}` must be a MIME type, got `{value}`")));
+    }
+
+    Ok(())
+}
+
+/// Checks that `value` is a hex encoded SHA-256 hash.
+fn check_checksum(field: &str, value: &str) -> crate::Result<()> {
+    if value.len() != 64 || !value.bytes().all(|it| it.is_ascii_hexdigit()) {
+        return Err(Error::hip412(format!("`{field}` must be a hex encoded SHA-256 hash")));
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use serde_json::json;
+
+    use crate::{
+        Error,
+        Hip412Localization,
+        Hip412Metadata,
+        NftMetadataUri,
+        TokenId,
+        TokenNftInfo,
+    };
+
+    const EXAMPLE: &str = r#"{
+        "name": "Example NFT 001",
+        "creator": "Jane Doe, John Doe",
+        "creatorDID": "did:hedera:mainnet:7Prd74ry1Uct87nZqL3ny7aR7Cg46JamVbJgk8azVgUm;hedera:mainnet:fid=0.0.123",
+        "description": "This describes my NFT",
+        "image": "https://myserver.com/preview-image-nft-001.png",
+        "checksum": "9defc6e3b7c79f5fc5b3d2dd9a0f4dbd4d8c2b5f4a0d8c6e2b7c8f9a0b1c2d3e",
+        "type": "image/png",
+        "format": "HIP412@2.0.0",
+        "properties": {
+            "external_url": "https://nft.com/mycollection/001"
+        },
+        "files": [
+            {
+                "uri": "https://myserver.com/high-resolution-nft-001.png",
+                "is_default_file": true,
+                "type": "image/png"
+            }
+        ],
+        "attributes": [
+            { "trait_type": "color", "display_type": "color", "value": "rgb(255,0,0)" },
+            { "trait_type": "hasPipe", "display_type": "boolean", "value": true },
+            { "trait_type": "stamina", "display_type": "percentage", "value": 83.4, "max_value": 100 }
+        ],
+        "localization": {
+            "uri": "ipfs://QmWS1VAdMD353A6SDk9wNyvkT14kyCiZrNDYAad4w1tKqT/{locale}.json",
+            "default": "en",
+            "locales": ["es", "fr"]
+        }
+    }"#;
+
+    #[test]
+    fn parse() {
+        let metadata = Hip412Metadata::from_json(EXAMPLE).unwrap();
+
+        assert_eq!(metadata.name, "Example NFT 001");
+        assert_eq!(metadata.mime_type, "image/png");
+        assert!(metadata.creator_did.unwrap().starts_with("did:hedera:mainnet:"));
+        assert_eq!(metadata.files[0].is_default_file, Some(true));
+        assert_eq!(metadata.attributes[2].max_value, Some(json!(100)));
+        assert_eq!(metadata.localization.unwrap().locales, ["es", "fr"]);
+    }
+
+    #[test]
+    fn json_round_trip() {
+        let metadata = Hip412Metadata::from_json(EXAMPLE).unwrap();
+
+        assert_eq!(Hip412Metadata::from_json(&metadata.to_json().unwrap()).unwrap(), metadata);
+    }
+
+    #[test]
+    fn build() {
+        let mut metadata = Hip412Metadata::new("Sunset #1", "ipfs://bafy/image.png", "image/png");
+        metadata
+            .creator("Alice")
+            .description("A sunset")
+            .property("edition", 1)
+            .attribute("color", "orange");
+
+        assert_eq!(
+            metadata.to_json().unwrap(),
+            r#"{"name":"Sunset #1","creator":"Alice","description":"A sunset","image":"ipfs://bafy/image.png","type":"image/png","format":"HIP412@2.0.0","properties":{"edition":1},"attributes":[{"trait_type":"color","value":"orange"}]}"#
+        );
+    }
+
+    #[test]
+    fn invalid() {
+        let valid = Hip412Metadata::new("NFT", "ipfs://bafy/image.png", "image/png");
+
+        let check = |f: fn(&mut Hip412Metadata)| {
+            let mut metadata = valid.clone();
+            f(&mut metadata);
+            metadata.validate()
+        };
+
+        assert_matches!(check(|it| it.name = " ".to_owned()), Err(Error::Hip412(_)));
+        assert_matches!(check(|it| it.image = "image.png".to_owned()), Err(Error::Hip412(_)));
+        assert_matches!(check(|it| it.mime_type = "png".to_owned()), Err(Error::Hip412(_)));
+        assert_matches!(check(|it| it.checksum = Some("abc".to_owned())), Err(Error::Hip412(_)));
+        assert_matches!(check(|it| it.format = Some("v2".to_owned())), Err(Error::Hip412(_)));
+        assert_matches!(
+            check(|it| {
+                it.file("https://example.com/nft.png", "image png");
+            }),
+            Err(Error::Hip412(_))
+        );
+        assert_matches!(
+            check(|it| {
+                it.attribute("rarity", serde_json::json!({ "nested": true }));
+            }),
+            Err(Error::Hip412(_))
+        );
+        assert_matches!(
+            check(|it| {
+                it.localization = Some(Hip412Localization {
+                    uri: "ipfs://bafy/{locale}.json".to_owned(),
+                    default: "en".to_owned(),
+                    locales: Vec::from(["es".to_owned(), "en".to_owned()]),
+                });
+            }),
+            Err(Error::Hip412(_))
+        );
+
+        assert_matches!(Hip412Metadata::from_json(r#"{"name": "NFT"}"#), Err(Error::Hip412(_)));
+    }
+
+    #[test]
+    fn metadata_uri() {
+        let uri =
+            NftMetadataUri::ipfs("bafkreibwci24bt2xtqi23g35gfx63wj555u77lwl2t55ajbfjqomgefxce")
+                .unwrap();
+
+        assert_eq!(
+            uri.as_str(),
+            "ipfs://bafkreibwci24bt2xtqi23g35gfx63wj555u77lwl2t55ajbfjqomgefxce"
+        );
+        assert_eq!(NftMetadataUri::from_bytes(&uri.to_bytes()).unwrap(), uri);
+        assert_eq!(uri.to_string().parse::<NftMetadataUri>().unwrap(), uri);
+    }
+
+    #[test]
+    fn metadata_uri_invalid() {
+        assert_matches!(NftMetadataUri::new("not a uri"), Err(Error::Hip412(_)));
+        assert_matches!(NftMetadataUri::ipfs(&"a".repeat(100)), Err(Error::Hip412(_)));
+        assert_matches!(NftMetadataUri::from_bytes(&[0xff, 0xfe]), Err(Error::Hip412(_)));
+    }
+
+    #[test]
+    fn nft_info_metadata_uri() {
+        let info = TokenNftInfo {
+            nft_id: TokenId::new(1, 2, 3).nft(4),
+            account_id: "5.6.7".parse().unwrap(),
+            creation_time: crate::transaction::test_helpers::VALID_START,
+            metadata: b"ipfs://bafy/metadata.json".to_vec(),
+            spender_id: None,
+            ledger_id: crate::LedgerId::mainnet(),
+        };
+
+        assert_eq!(info.metadata_uri().unwrap().as_str(), "ipfs://bafy/metadata.json");
+    }
+}
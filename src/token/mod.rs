@@ -19,8 +19,16 @@
  */
 
 mod assessed_custom_fee;
+mod custom_fee_simulator;
 mod custom_fees;
+#[cfg(feature = "hip412")]
+mod hip412;
 mod nft_id;
+mod nft_mint_flow;
+#[cfg(test)]
+pub(crate) mod test_helpers;
+mod token_admin_batch;
+mod token_amount;
 mod token_associate_transaction;
 mod token_association;
 mod token_burn_transaction;
@@ -33,11 +41,13 @@ mod token_grant_kyc_transaction;
 mod token_id;
 mod token_info;
 mod token_info_query;
+mod token_launch_flow;
 mod token_mint_transaction;
 mod token_nft_info;
 mod token_nft_info_query;
 mod token_nft_transfer;
 mod token_pause_transaction;
+mod token_permissions;
 mod token_revoke_kyc_transaction;
 mod token_supply_type;
 mod token_type;
@@ -47,6 +57,10 @@ mod token_update_transaction;
 mod token_wipe_transaction;
 
 pub use assessed_custom_fee::AssessedCustomFee;
+pub use custom_fee_simulator::{
+    CustomFeeSimulation,
+    CustomFeeSimulator,
+};
 pub use custom_fees::{
     AnyCustomFee,
     CustomFee,
@@ -59,7 +73,26 @@ pub use custom_fees::{
     RoyaltyFee,
     RoyaltyFeeData,
 };
+#[cfg(feature = "hip412")]
+pub use hip412::{
+    Hip412Attribute,
+    Hip412File,
+    Hip412Localization,
+    Hip412Metadata,
+    NftMetadataUri,
+};
 pub use nft_id::NftId;
+pub use nft_mint_flow::{
+    NftMintFlow,
+    NftMintProgress,
+};
+pub use token_admin_batch::{
+    TokenAdminBatch,
+    TokenAdminOperation,
+    TokenAdminOutcome,
+    TokenAdminResult,
+};
+pub use token_amount::TokenAmount;
 pub use token_associate_transaction::{
     TokenAssociateTransaction,
     TokenAssociateTransactionData,
@@ -99,6 +132,13 @@ pub use token_info_query::{
     TokenInfoQuery,
     TokenInfoQueryData,
 };
+pub use token_launch_flow::{
+    TokenLaunchFlow,
+    TokenLaunchRecipient,
+    TokenLaunchRecipientReport,
+    TokenLaunchReport,
+    TokenLaunchStep,
+};
 pub use token_mint_transaction::{
     TokenMintTransaction,
     TokenMintTransactionData,
@@ -113,6 +153,11 @@ pub use token_pause_transaction::{
     TokenPauseTransaction,
     TokenPauseTransactionData,
 };
+pub use token_permissions::{
+    TokenOperation,
+    TokenPermission,
+    TokenPermissions,
+};
 pub use token_revoke_kyc_transaction::{
     TokenRevokeKycTransaction,
     TokenRevokeKycTransactionData,
@@ -0,0 +1,539 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::pending::{
+    PendingLedger,
+    Submitter,
+};
+use crate::signer::AnySigner;
+use crate::transaction::{
+    MAX_BATCH_SIZE,
+    MAX_TRANSACTION_SIZE,
+};
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    PublicKey,
+    TokenId,
+    TokenMintTransaction,
+    TransactionId,
+    TransactionReceipt,
+};
+
+/// Tracks which NFTs of an [`NftMintFlow`] have been minted, and their serial numbers.
+///
+/// Executing a flow again with the same progress only mints what's missing.
+/// The progress belongs to one list of metadata, and must not be reused for another.
+///
+/// NFTs whose transaction has been submitted, but whose receipt hasn't been seen, aren't minted again
+/// until a later execution finds the receipt or [`clear_pending`](Self::clear_pending) is called.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct NftMintProgress {
+    serials: Vec<Option<i64>>,
+    pending: Vec<PendingMint>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+struct PendingMint {
+    transaction_id: TransactionId,
+    indices: Vec<usize>,
+}
+
+impl NftMintProgress {
+    /// Create a new `NftMintProgress`, for a flow that hasn't been executed yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the serial number of each NFT, in the order of the flow's metadata, if it's been minted.
+    ///
+    /// This is empty until the flow is first executed.
+    #[must_use]
+    pub fn serials(&self) -> &[Option<i64>] {
+        &self.serials
+    }
+
+    /// Returns `true` if every NFT has been minted.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.pending.is_empty() && self.serials.iter().all(Option::is_some)
+    }
+
+    /// Returns the IDs of the transactions whose outcome isn't known yet.
+    #[must_use]
+    pub fn pending_transaction_ids(&self) -> Vec<TransactionId> {
+        self.pending.iter().map(|it| it.transaction_id).collect()
+    }
+
+    /// Forget the pending NFTs of `transaction_id`, so that they're minted by the next execution.
+    ///
+    /// Only call this once it's certain that the transaction didn't reach consensus,
+    /// or those NFTs will be minted twice.
+    pub fn clear_pending(&mut self, transaction_id: TransactionId) -> &mut Self {
+        self.pending.retain(|it| it.transaction_id != transaction_id);
+
+        self
+    }
+
+    /// Returns the indices of the NFTs that are neither minted nor pending.
+    fn remaining(&self, len: usize) -> crate::Result<Vec<usize>> {
+        if !self.serials.is_empty() && self.serials.len() != len {
+            return Err(Error::NftMintProgressMismatch {
+                expected: self.serials.len(),
+                actual: len,
+            });
+        }
+
+        Ok((0..len)
+            .filter(|&index| self.serials.get(index).map_or(true, Option::is_none))
+            .filter(|index| self.pending.iter().all(|it| !it.indices.contains(index)))
+            .collect())
+    }
+
+    fn begin(&mut self, len: usize, transaction_id: TransactionId, indices: Vec<usize>) {
+        if self.serials.is_empty() {
+            self.serials = vec![None; len];
+        }
+
+        self.pending.push(PendingMint { transaction_id, indices });
+    }
+
+    fn confirm(&mut self, transaction_id: TransactionId, serials: &[i64]) {
+        let Some(position) = self.pending.iter().position(|it| it.transaction_id == transaction_id)
+        else {
+            return;
+        };
+
+        let pending = self.pending.remove(position);
+
+        // the network assigns serial numbers in the order of the transaction's metadata.
+        for (index, serial) in pending.indices.into_iter().zip(serials) {
+            self.serials[index] = Some(*serial);
+        }
+    }
+}
+
+impl PendingLedger for NftMintProgress {
+    fn confirm_pending(&mut self, transaction_id: TransactionId, receipt: &TransactionReceipt) {
+        self.confirm(transaction_id, &receipt.serials);
+    }
+
+    fn forget_pending(&mut self, transaction_id: TransactionId) {
+        self.clear_pending(transaction_id);
+    }
+}
+
+/// Mint any number of NFTs of a token.
+///
+/// The metadata is split into as few [`TokenMintTransaction`]s as the network's limits on NFTs per transaction
+/// and transaction size allow, and the transactions are submitted with at most [`max_in_flight`](Self::max_in_flight)
+/// waiting for their receipts at once.
+///
+/// Progress is tracked in an [`NftMintProgress`], which makes execution resumable:
+/// if an execution fails part way through, executing again with the same progress only mints what's missing.
+#[derive(Debug)]
+pub struct NftMintFlow {
+    token_id: Option<TokenId>,
+    metadata: Vec<Vec<u8>>,
+    max_in_flight: usize,
+    max_transaction_fee: Option<Hbar>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signer: Option<AnySigner>,
+}
+
+impl Default for NftMintFlow {
+    fn default() -> Self {
+        Self {
+            token_id: None,
+            metadata: Vec::new(),
+            max_in_flight: 1,
+            max_transaction_fee: None,
+            node_account_ids: None,
+            signer: None,
+        }
+    }
+}
+
+impl NftMintFlow {
+    /// Create a new `NftMintFlow`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the token for which to mint NFTs.
+    #[must_use]
+    pub fn get_token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Sets the token for which to mint NFTs.
+    pub fn token_id(&mut self, token_id: impl Into<TokenId>) -> &mut Self {
+        self.token_id = Some(token_id.into());
+
+        self
+    }
+
+    /// Returns the metadata of the NFTs to mint, one entry per NFT.
+    #[must_use]
+    pub fn get_metadata(&self) -> &[Vec<u8>] {
+        &self.metadata
+    }
+
+    /// Sets the metadata of the NFTs to mint, one entry per NFT.
+    pub fn metadata<Bytes>(&mut self, metadata: impl IntoIterator<Item = Bytes>) -> &mut Self
+    where
+        Bytes: AsRef<[u8]>,
+    {
+        self.metadata = metadata.into_iter().map(|bytes| bytes.as_ref().to_vec()).collect();
+
+        self
+    }
+
+    /// Returns the maximum number of transactions that may be waiting for their receipts at once.
+    #[must_use]
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Sets the maximum number of transactions that may be waiting for their receipts at once.
+    ///
+    /// Defaults to `1`, which submits each transaction only once the previous one has reached consensus.
+    ///
+    /// # Panics
+    /// - If `max_in_flight` is `0`.
+    pub fn max_in_flight(&mut self, max_in_flight: usize) -> &mut Self {
+        assert!(max_in_flight > 0, "max_in_flight must be greater than 0");
+        self.max_in_flight = max_in_flight;
+
+        self
+    }
+
+    /// Returns the maximum transaction fee of each transaction.
+    #[must_use]
+    pub fn get_max_transaction_fee(&self) -> Option<Hbar> {
+        self.max_transaction_fee
+    }
+
+    /// Sets the maximum transaction fee of each transaction.
+    pub fn max_transaction_fee(&mut self, fee: Hbar) -> &mut Self {
+        self.max_transaction_fee = Some(fee);
+
+        self
+    }
+
+    /// Returns the node account IDs the transactions are submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the node account IDs the transactions are submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Sets the supply key of the token, if it isn't the client's operator.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer for the supply key of the token, if it isn't the client's operator.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Returns the transactions that would mint every NFT that `progress` has no record of.
+    ///
+    /// # Errors
+    /// - [`Error::NftMintProgressMismatch`] if `progress` is the progress of a flow with a different number of NFTs.
+    /// - [`Error::TransactionValidation`] if any of the transactions would be invalid,
+    ///   for instance because some metadata is too long.
+    pub fn plan(&self, progress: &NftMintProgress) -> crate::Result<Vec<TokenMintTransaction>> {
+        let transactions: Vec<_> =
+            self.batches(progress)?.iter().map(|it| self.make_transaction(it)).collect();
+
+        for transaction in &transactions {
+            transaction.validate()?;
+        }
+
+        Ok(transactions)
+    }
+
+    /// Mints every NFT that `progress` has no record of, returning the serial numbers of all the NFTs in the order of their metadata.
+    ///
+    /// NFTs left pending by an earlier execution are resolved first.
+    ///
+    /// # Errors
+    /// - [`Error::NftMintProgressMismatch`] if `progress` is the progress of a flow with a different number of NFTs.
+    /// - [`Error::TransactionValidation`] if any of the transactions would be invalid, before anything is submitted.
+    /// - [`Error::ReceiptStatus`] if one of the transactions fails.
+    /// - [`Error::NftMintUnconfirmed`] if the outcome of some transactions couldn't be found out.
+    /// - Any error from submitting a transaction or querying its receipt.
+    pub async fn execute(
+        &self,
+        client: &Client,
+        progress: &mut NftMintProgress,
+    ) -> crate::Result<Vec<i64>> {
+        self.execute_with_optional_timeout(client, progress, None).await
+    }
+
+    /// Mints every NFT that `progress` has no record of, returning the serial numbers of all the NFTs in the order of their metadata.
+    ///
+    /// NFTs left pending by an earlier execution are resolved first.
+    ///
+    /// # Errors
+    /// - [`Error::NftMintProgressMismatch`] if `progress` is the progress of a flow with a different number of NFTs.
+    /// - [`Error::TransactionValidation`] if any of the transactions would be invalid, before anything is submitted.
+    /// - [`Error::ReceiptStatus`] if one of the transactions fails.
+    /// - [`Error::NftMintUnconfirmed`] if the outcome of some transactions couldn't be found out.
+    /// - Any error from submitting a transaction or querying its receipt.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        progress: &mut NftMintProgress,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<Vec<i64>> {
+        self.execute_with_optional_timeout(client, progress, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        progress: &mut NftMintProgress,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<Vec<i64>> {
+        let pending = progress.pending_transaction_ids();
+
+        let mut submitter =
+            Submitter::new(client, progress, self.max_in_flight, timeout_per_transaction);
+
+        submitter.resolve(pending).await?;
+
+        let batches = self.batches(submitter.ledger())?;
+        let transactions: Vec<_> = batches.iter().map(|it| self.make_transaction(it)).collect();
+
+        // validate everything up front, so that an invalid NFT doesn't stop the flow half way.
+        for transaction in &transactions {
+            transaction.validate()?;
+        }
+
+        for (mut transaction, indices) in transactions.into_iter().zip(batches) {
+            if let Some(signer) = &self.signer {
+                transaction.sign_signer(signer.clone());
+            }
+
+            submitter
+                .submit(&mut transaction, |progress, transaction_id| {
+                    progress.begin(self.metadata.len(), transaction_id, indices);
+                })
+                .await?;
+        }
+
+        submitter.finish().await?;
+
+        let pending = progress.pending_transaction_ids();
+        if !pending.is_empty() {
+            return Err(Error::NftMintUnconfirmed(pending));
+        }
+
+        Ok(progress
+            .serials
+            .iter()
+            .map(|it| it.expect("every NFT has been minted once nothing is pending"))
+            .collect())
+    }
+
+    /// Splits the NFTs that `progress` has no record of into transactions, as indices into `self.metadata`.
+    fn batches(&self, progress: &NftMintProgress) -> crate::Result<Vec<Vec<usize>>> {
+        // the operator, and the supply key if it's someone else.
+        let expected_signers = 1 + usize::from(self.signer.is_some());
+
+        let mut batches = Vec::new();
+        let mut batch: Vec<usize> = Vec::new();
+
+        for index in progress.remaining(self.metadata.len())? {
+            batch.push(index);
+
+            let fits = batch.len() <= MAX_BATCH_SIZE
+                && self.make_transaction(&batch).projected_size_bytes(expected_signers)?
+                    <= MAX_TRANSACTION_SIZE;
+
+            // an NFT that doesn't fit on its own is left for validation to report.
+            if !fits && batch.len() > 1 {
+                batch.pop();
+                batches.push(std::mem::replace(&mut batch, Vec::from([index])));
+            }
+        }
+
+        if !batch.is_empty() {
+            batches.push(batch);
+        }
+
+        Ok(batches)
+    }
+
+    fn make_transaction(&self, indices: &[usize]) -> TokenMintTransaction {
+        let mut transaction = TokenMintTransaction::new();
+
+        transaction.metadata(indices.iter().map(|&index| &self.metadata[index]));
+
+        if let Some(token_id) = self.token_id {
+            transaction.token_id(token_id);
+        }
+
+        if let Some(fee) = self.max_transaction_fee {
+            transaction.max_transaction_fee(fee);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            transaction.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        transaction
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::token::test_helpers::{
+        make_nft_mint_flow,
+        other_tx_id,
+        TEST_FLOW_TOKEN_ID,
+    };
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        Error,
+        NftMintFlow,
+        NftMintProgress,
+    };
+
+    #[test]
+    fn defaults() {
+        let flow = NftMintFlow::new();
+
+        assert_eq!(flow.get_token_id(), None);
+        assert!(flow.get_metadata().is_empty());
+        assert_eq!(flow.get_max_in_flight(), 1);
+        assert_eq!(flow.get_max_transaction_fee(), None);
+        assert_eq!(flow.get_node_account_ids(), None);
+    }
+
+    #[test]
+    fn plan() {
+        let flow = make_nft_mint_flow(25);
+
+        let transactions = flow.plan(&NftMintProgress::new()).unwrap();
+
+        let lens: Vec<_> = transactions.iter().map(|it| it.get_metadata().len()).collect();
+        assert_eq!(lens, [10, 10, 5]);
+
+        assert_eq!(transactions[1].get_metadata()[0], [10]);
+        assert!(transactions.iter().all(|it| it.get_token_id() == Some(TEST_FLOW_TOKEN_ID)));
+    }
+
+    #[test]
+    fn plan_invalid_metadata() {
+        let mut flow = make_nft_mint_flow(0);
+        flow.metadata([[0; 101]]);
+
+        assert_matches!(
+            flow.plan(&NftMintProgress::new()),
+            Err(Error::TransactionValidation { .. })
+        );
+    }
+
+    #[test]
+    fn resume() {
+        let flow = make_nft_mint_flow(25);
+        let mut progress = NftMintProgress::new();
+
+        progress.begin(25, TEST_TX_ID, (0..10).collect());
+        progress.begin(25, other_tx_id(), (10..20).collect());
+
+        // pending NFTs aren't minted again.
+        let transactions = flow.plan(&progress).unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].get_metadata().len(), 5);
+
+        progress.confirm(other_tx_id(), &(110..120).collect::<Vec<_>>());
+        progress.clear_pending(TEST_TX_ID);
+
+        assert!(progress.pending_transaction_ids().is_empty());
+        assert_eq!(progress.serials()[10], Some(110));
+        assert_eq!(progress.serials()[0], None);
+        assert!(!progress.is_complete());
+
+        let lens: Vec<_> =
+            flow.plan(&progress).unwrap().iter().map(|it| it.get_metadata().len()).collect();
+        assert_eq!(lens, [10, 5]);
+    }
+
+    #[test]
+    fn complete() {
+        let mut progress = NftMintProgress::new();
+        progress.begin(3, TEST_TX_ID, Vec::from([0, 1, 2]));
+        progress.confirm(TEST_TX_ID, &[7, 8, 9]);
+
+        assert!(progress.is_complete());
+        assert_eq!(progress.serials(), [Some(7), Some(8), Some(9)]);
+        assert!(make_nft_mint_flow(3).plan(&progress).unwrap().is_empty());
+    }
+
+    #[test]
+    fn plan_mismatched_progress() {
+        let mut progress = NftMintProgress::new();
+        progress.begin(3, TEST_TX_ID, Vec::from([0, 1, 2]));
+
+        assert_matches!(
+            make_nft_mint_flow(4).plan(&progress),
+            Err(Error::NftMintProgressMismatch { expected: 3, actual: 4 })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_in_flight_zero() {
+        NftMintFlow::new().max_in_flight(0);
+    }
+}
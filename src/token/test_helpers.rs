@@ -0,0 +1,145 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+//! Fixtures shared by the tests of the token module.
+
+use std::str::FromStr;
+
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use crate::transaction::test_helpers::TEST_TX_ID;
+use crate::{
+    AccountId,
+    FixedFee,
+    FractionalFee,
+    Key,
+    LedgerId,
+    NftMintFlow,
+    PrivateKey,
+    TokenCreateTransaction,
+    TokenId,
+    TokenInfo,
+    TokenLaunchFlow,
+    TokenLaunchRecipient,
+    TokenSupplyType,
+    TokenType,
+    TransactionId,
+};
+
+pub(crate) const TEST_FLOW_TOKEN_ID: TokenId = TokenId::new(0, 0, 1234);
+
+pub(crate) const TEST_TREASURY_ACCOUNT_ID: AccountId = AccountId::new(0, 0, 1001);
+
+/// Returns a transaction ID other than [`TEST_TX_ID`], for tests that need two.
+pub(crate) fn other_tx_id() -> TransactionId {
+    TransactionId { valid_start: TEST_TX_ID.valid_start + Duration::SECOND, ..TEST_TX_ID }
+}
+
+/// Creates a flow that mints `len` NFTs of [`TEST_FLOW_TOKEN_ID`], the metadata of each being its index.
+pub(crate) fn make_nft_mint_flow(len: u8) -> NftMintFlow {
+    let mut flow = NftMintFlow::new();
+    flow.token_id(TEST_FLOW_TOKEN_ID).metadata((0..len).map(|it| [it]));
+
+    flow
+}
+
+/// Creates a flow that launches a token with [`TEST_TREASURY_ACCOUNT_ID`] as the treasury, after `configure`,
+/// to `0.0.2001` (which gets 100 tokens) and `0.0.2002` (which is already associated and gets none).
+pub(crate) fn make_token_launch_flow(
+    configure: impl FnOnce(&mut TokenCreateTransaction),
+) -> TokenLaunchFlow {
+    let mut token = TokenCreateTransaction::new();
+    token
+        .name("Test")
+        .symbol("TST")
+        .treasury_account_id(TEST_TREASURY_ACCOUNT_ID)
+        .initial_supply(1000);
+    configure(&mut token);
+
+    let mut flow = TokenLaunchFlow::new(token);
+    flow.recipient(TokenLaunchRecipient::new(AccountId::new(0, 0, 2001), 100))
+        .recipient(TokenLaunchRecipient::new(AccountId::new(0, 0, 2002), 0).associate(false));
+
+    flow
+}
+
+fn public_key(s: &str) -> Key {
+    PrivateKey::from_str(s).unwrap().public_key().into()
+}
+
+/// Creates the info of a fungible token that has every key and a couple of custom fees.
+pub(crate) fn make_token_info() -> TokenInfo {
+    let custom_fees = [
+        FixedFee {
+            fee_collector_account_id: Some("4322".parse().unwrap()),
+            all_collectors_are_exempt: false,
+            fee: crate::FixedFeeData {
+                amount: 10,
+                denominating_token_id: Some(TokenId::new(0, 0, 483902)),
+            },
+        }
+        .into(),
+        FractionalFee {
+            fee_collector_account_id: Some("389042".parse().unwrap()),
+            all_collectors_are_exempt: false,
+            fee: crate::FractionalFeeData {
+                denominator: 7,
+                numerator: 3,
+                minimum_amount: 3,
+                maximum_amount: 100,
+                assessment_method: crate::FeeAssessmentMethod::Inclusive,
+            },
+        }
+        .into(),
+    ]
+    .into();
+
+    TokenInfo {
+        token_id: "0.6.9".parse().unwrap(),
+        name: "test token name".to_owned(),
+        symbol: "TTN".to_owned(),
+        decimals: 3,
+        total_supply: 1000,
+        treasury_account_id: "7.7.7".parse().unwrap(),
+        admin_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e11")),
+        kyc_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e12")),
+        freeze_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e13")),
+        wipe_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e14")),
+        supply_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e15")),
+        fee_schedule_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e16")),
+        default_freeze_status: Some(true),
+        default_kyc_status: Some(true),
+        is_deleted: false,
+        auto_renew_account: Some("8.9.0".parse().unwrap()),
+        auto_renew_period: Some(Duration::hours(10)),
+        expiration_time: Some(OffsetDateTime::from_unix_timestamp(1554158542).unwrap()),
+        token_memo: "memo".to_owned(),
+        token_type: TokenType::FungibleCommon,
+        supply_type: TokenSupplyType::Finite,
+        max_supply: 1000000,
+        custom_fees,
+        pause_key: Some(public_key("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e17")),
+        pause_status: Some(true),
+        ledger_id: LedgerId::mainnet(),
+    }
+}
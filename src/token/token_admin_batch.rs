@@ -0,0 +1,616 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use futures_util::StreamExt;
+
+use crate::execute::execute;
+use crate::signer::AnySigner;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    PublicKey,
+    Status,
+    TokenAssociateTransaction,
+    TokenDissociateTransaction,
+    TokenFreezeTransaction,
+    TokenGrantKycTransaction,
+    TokenId,
+    TokenRevokeKycTransaction,
+    TokenUnfreezeTransaction,
+    TokenWipeTransaction,
+    Transaction,
+    TransactionId,
+};
+
+/// An operation that a [`TokenAdminBatch`] applies to each of its accounts.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenAdminOperation {
+    /// Associate each account with the token (via a [`TokenAssociateTransaction`]).
+    ///
+    /// Needs the signature of each account.
+    Associate,
+
+    /// Dissociate each account from the token (via a [`TokenDissociateTransaction`]).
+    ///
+    /// Needs the signature of each account.
+    Dissociate,
+
+    /// Freeze each account for the token (via a [`TokenFreezeTransaction`]).
+    Freeze,
+
+    /// Unfreeze each account for the token (via a [`TokenUnfreezeTransaction`]).
+    Unfreeze,
+
+    /// Grant KYC for the token to each account (via a [`TokenGrantKycTransaction`]).
+    GrantKyc,
+
+    /// Revoke KYC for the token from each account (via a [`TokenRevokeKycTransaction`]).
+    RevokeKyc,
+
+    /// Wipe an amount of the token from each account (via a [`TokenWipeTransaction`]).
+    ///
+    /// The amount is given for each account with [`TokenAdminBatch::account_with_wipe_amount`],
+    /// so this only works with fungible tokens.
+    /// Accounts without an amount fail with [`Error::TokenWipeAmountMissing`].
+    Wipe,
+}
+
+impl TokenAdminOperation {
+    /// Returns `true` if a transaction for this operation failing with `status` means that the account
+    /// was already in the state this operation would put it in.
+    fn is_satisfied_by_status(self, status: Status) -> bool {
+        matches!(
+            (self, status),
+            (Self::Associate, Status::TokenAlreadyAssociatedToAccount)
+                | (Self::Dissociate, Status::TokenNotAssociatedToAccount)
+        )
+    }
+}
+
+/// What happened to one account of a [`TokenAdminBatch`].
+#[derive(Debug)]
+pub enum TokenAdminOutcome {
+    /// The operation's transaction reached consensus successfully.
+    Applied(TransactionId),
+
+    /// The account was already in the target state, so the operation wasn't needed.
+    AlreadySatisfied,
+
+    /// The operation failed.
+    Failed {
+        /// The ID of the transaction that failed, if one was submitted.
+        transaction_id: Option<TransactionId>,
+
+        /// Why the operation failed.
+        error: Error,
+    },
+}
+
+impl TokenAdminOutcome {
+    /// Returns `true` if the operation failed.
+    #[must_use]
+    pub fn is_failure(&self) -> bool {
+        matches!(self, Self::Failed { .. })
+    }
+}
+
+/// The result of a [`TokenAdminBatch`] for one account.
+#[derive(Debug)]
+pub struct TokenAdminResult {
+    /// The account the operation was applied to.
+    pub account_id: AccountId,
+
+    /// What happened to the account.
+    pub outcome: TokenAdminOutcome,
+}
+
+#[derive(Debug, Clone)]
+struct Target {
+    account_id: AccountId,
+    signer: Option<AnySigner>,
+    wipe_amount: Option<u64>,
+}
+
+/// Apply one account-level operation on a token, such as freezing or granting KYC, to many accounts.
+///
+/// Each account gets its own transaction, and at most [`max_in_flight`](Self::max_in_flight) of them
+/// are submitted or waiting for their receipts at once.
+/// A failure for one account doesn't stop the others; instead, execution returns the outcome for every account,
+/// in the order the accounts were added.
+///
+/// Accounts that are already in the target state are reported as [`TokenAdminOutcome::AlreadySatisfied`]:
+/// when associating or dissociating, that's known from the status the network rejects the transaction with,
+/// and when wiping, from a wipe amount of zero.
+#[derive(Debug)]
+pub struct TokenAdminBatch {
+    token_id: TokenId,
+    operation: TokenAdminOperation,
+    targets: Vec<Target>,
+    skip_satisfied: bool,
+    max_in_flight: usize,
+    max_transaction_fee: Option<Hbar>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signers: Vec<AnySigner>,
+}
+
+impl TokenAdminBatch {
+    /// Create a new `TokenAdminBatch` that applies `operation` for `token_id`.
+    #[must_use]
+    pub fn new(token_id: TokenId, operation: TokenAdminOperation) -> Self {
+        Self {
+            token_id,
+            operation,
+            targets: Vec::new(),
+            skip_satisfied: true,
+            max_in_flight: 1,
+            max_transaction_fee: None,
+            node_account_ids: None,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Returns the token the operation is for.
+    #[must_use]
+    pub fn get_token_id(&self) -> TokenId {
+        self.token_id
+    }
+
+    /// Returns the operation applied to each account.
+    #[must_use]
+    pub fn get_operation(&self) -> TokenAdminOperation {
+        self.operation
+    }
+
+    /// Returns the accounts the operation is applied to.
+    #[must_use]
+    pub fn get_accounts(&self) -> Vec<AccountId> {
+        self.targets.iter().map(|it| it.account_id).collect()
+    }
+
+    /// Adds an account to apply the operation to.
+    ///
+    /// Accounts that are added more than once are only administered once.
+    pub fn account(&mut self, account_id: AccountId) -> &mut Self {
+        self.target(account_id);
+
+        self
+    }
+
+    /// Adds accounts to apply the operation to.
+    ///
+    /// Accounts that are added more than once are only administered once.
+    pub fn accounts(&mut self, account_ids: impl IntoIterator<Item = AccountId>) -> &mut Self {
+        for account_id in account_ids {
+            self.target(account_id);
+        }
+
+        self
+    }
+
+    /// Adds an account to apply the operation to, along with its key.
+    ///
+    /// The key only signs the account's own transaction, which is needed to associate or dissociate it.
+    pub fn account_with_key(&mut self, account_id: AccountId, key: PrivateKey) -> &mut Self {
+        self.target(account_id).signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds an account to apply the operation to, along with the amount of the token to wipe from it.
+    ///
+    /// The amount is in the lowest denomination of the token, and is only used when wiping,
+    /// which needs one for every account.
+    pub fn account_with_wipe_amount(&mut self, account_id: AccountId, amount: u64) -> &mut Self {
+        self.target(account_id).wipe_amount = Some(amount);
+
+        self
+    }
+
+    /// Returns the amount of the token to wipe from `account_id`, if one was given.
+    #[must_use]
+    pub fn get_wipe_amount(&self, account_id: AccountId) -> Option<u64> {
+        self.targets.iter().find(|it| it.account_id == account_id)?.wipe_amount
+    }
+
+    /// Adds an account to apply the operation to, along with the signer for its key.
+    ///
+    /// The signer only signs the account's own transaction, which is needed to associate or dissociate it.
+    pub fn account_with_signer<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        account_id: AccountId,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.target(account_id).signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Returns whether accounts that are already in the target state are skipped.
+    #[must_use]
+    pub fn get_skip_satisfied(&self) -> bool {
+        self.skip_satisfied
+    }
+
+    /// Sets whether accounts that are known to already be in the target state are skipped.
+    ///
+    /// Defaults to `true`, which skips wiping an amount of zero.
+    /// Associating an account that's already associated (or dissociating one that isn't) is reported as
+    /// [`TokenAdminOutcome::AlreadySatisfied`] regardless, since that's only known once the network rejects the transaction.
+    pub fn skip_satisfied(&mut self, skip_satisfied: bool) -> &mut Self {
+        self.skip_satisfied = skip_satisfied;
+
+        self
+    }
+
+    /// Returns the maximum number of accounts that may be administered at once.
+    #[must_use]
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Sets the maximum number of accounts that may be administered at once.
+    ///
+    /// Defaults to `1`, which administers each account only once the previous one is done.
+    ///
+    /// # Panics
+    /// - If `max_in_flight` is `0`.
+    pub fn max_in_flight(&mut self, max_in_flight: usize) -> &mut Self {
+        assert!(max_in_flight > 0, "max_in_flight must be greater than 0");
+        self.max_in_flight = max_in_flight;
+
+        self
+    }
+
+    /// Returns the maximum transaction fee of each transaction.
+    #[must_use]
+    pub fn get_max_transaction_fee(&self) -> Option<Hbar> {
+        self.max_transaction_fee
+    }
+
+    /// Sets the maximum transaction fee of each transaction.
+    pub fn max_transaction_fee(&mut self, fee: Hbar) -> &mut Self {
+        self.max_transaction_fee = Some(fee);
+
+        self
+    }
+
+    /// Returns the node account IDs the transactions are submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the node account IDs the transactions are submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Adds a key that signs every transaction, such as the token's freeze, KYC or wipe key
+    /// if it isn't the client's operator.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer that signs every transaction, such as the token's freeze, KYC or wipe key
+    /// if it isn't the client's operator.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Applies the operation to every account, returning the outcome for each in the order they were added.
+    pub async fn execute(&self, client: &Client) -> Vec<TokenAdminResult> {
+        self.execute_with_optional_timeout(client, None).await
+    }
+
+    /// Applies the operation to every account, returning the outcome for each in the order they were added.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: std::time::Duration,
+    ) -> Vec<TokenAdminResult> {
+        self.execute_with_optional_timeout(client, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> Vec<TokenAdminResult> {
+        futures_util::stream::iter(&self.targets)
+            .map(|target| async move {
+                TokenAdminResult {
+                    account_id: target.account_id,
+                    outcome: self.administer(client, target, timeout_per_transaction).await,
+                }
+            })
+            .buffered(self.max_in_flight)
+            .collect()
+            .await
+    }
+
+    fn target(&mut self, account_id: AccountId) -> &mut Target {
+        let index = match self.targets.iter().position(|it| it.account_id == account_id) {
+            Some(index) => index,
+            None => {
+                self.targets.push(Target { account_id, signer: None, wipe_amount: None });
+                self.targets.len() - 1
+            }
+        };
+
+        &mut self.targets[index]
+    }
+
+    async fn administer(
+        &self,
+        client: &Client,
+        target: &Target,
+        timeout: Option<std::time::Duration>,
+    ) -> TokenAdminOutcome {
+        let account_id = target.account_id;
+
+        match self.operation {
+            TokenAdminOperation::Associate => {
+                let mut transaction = TokenAssociateTransaction::new();
+                transaction.account_id(account_id).token_ids([self.token_id]);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+
+            TokenAdminOperation::Dissociate => {
+                let mut transaction = TokenDissociateTransaction::new();
+                transaction.account_id(account_id).token_ids([self.token_id]);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+
+            TokenAdminOperation::Freeze => {
+                let mut transaction = TokenFreezeTransaction::new();
+                transaction.account_id(account_id).token_id(self.token_id);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+
+            TokenAdminOperation::Unfreeze => {
+                let mut transaction = TokenUnfreezeTransaction::new();
+                transaction.account_id(account_id).token_id(self.token_id);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+
+            TokenAdminOperation::GrantKyc => {
+                let mut transaction = TokenGrantKycTransaction::new();
+                transaction.account_id(account_id).token_id(self.token_id);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+
+            TokenAdminOperation::RevokeKyc => {
+                let mut transaction = TokenRevokeKycTransaction::new();
+                transaction.account_id(account_id).token_id(self.token_id);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+
+            TokenAdminOperation::Wipe => {
+                let Some(amount) = target.wipe_amount else {
+                    let error = Error::TokenWipeAmountMissing(Box::new(account_id));
+
+                    return TokenAdminOutcome::Failed { transaction_id: None, error };
+                };
+
+                if self.skip_satisfied && amount == 0 {
+                    return TokenAdminOutcome::AlreadySatisfied;
+                }
+
+                let mut transaction = TokenWipeTransaction::new();
+                transaction.account_id(account_id).token_id(self.token_id).amount(amount);
+
+                self.submit(client, transaction, target, timeout).await
+            }
+        }
+    }
+
+    /// Submits `transaction` for `target`, and waits for its receipt.
+    async fn submit<D>(
+        &self,
+        client: &Client,
+        mut transaction: Transaction<D>,
+        target: &Target,
+        timeout: Option<std::time::Duration>,
+    ) -> TokenAdminOutcome
+    where
+        D: TransactionExecute + Send + Sync,
+    {
+        if let Some(fee) = self.max_transaction_fee {
+            transaction.max_transaction_fee(fee);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            transaction.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        for signer in self.signers.iter().chain(&target.signer) {
+            transaction.sign_signer(signer.clone());
+        }
+
+        // applies the client's fee policy and validation, and freezes the transaction, just like `execute` would.
+        if let Err(error) = transaction.prepare_execute(client).await {
+            return TokenAdminOutcome::Failed { transaction_id: None, error };
+        }
+
+        let transaction_id =
+            transaction.get_transaction_id().expect("frozen transactions have an ID");
+
+        let response = match execute(client, &transaction, timeout).await {
+            Ok(response) => response,
+            Err(error) => return self.failed(transaction_id, error),
+        };
+
+        match response.get_receipt_query().execute_with_optional_timeout(client, timeout).await {
+            Ok(_) => TokenAdminOutcome::Applied(transaction_id),
+            Err(error) => self.failed(transaction_id, error),
+        }
+    }
+
+    fn failed(&self, transaction_id: TransactionId, error: Error) -> TokenAdminOutcome {
+        let status = match &error {
+            Error::TransactionPreCheckStatus { status, .. }
+            | Error::ReceiptStatus { status, .. } => Some(*status),
+            _ => None,
+        };
+
+        if status.map_or(false, |status| self.operation.is_satisfied_by_status(status)) {
+            return TokenAdminOutcome::AlreadySatisfied;
+        }
+
+        TokenAdminOutcome::Failed { transaction_id: Some(transaction_id), error }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        Status,
+        TokenAdminBatch,
+        TokenAdminOperation,
+        TokenAdminOutcome,
+        TokenId,
+    };
+
+    const TOKEN_ID: TokenId = TokenId::new(0, 0, 1234);
+
+    fn account(num: u64) -> AccountId {
+        AccountId::new(0, 0, num)
+    }
+
+    #[test]
+    fn defaults() {
+        let batch = TokenAdminBatch::new(TOKEN_ID, TokenAdminOperation::Freeze);
+
+        assert_eq!(batch.get_token_id(), TOKEN_ID);
+        assert_eq!(batch.get_operation(), TokenAdminOperation::Freeze);
+        assert!(batch.get_accounts().is_empty());
+        assert!(batch.get_skip_satisfied());
+        assert_eq!(batch.get_max_in_flight(), 1);
+        assert_eq!(batch.get_max_transaction_fee(), None);
+        assert_eq!(batch.get_node_account_ids(), None);
+    }
+
+    #[test]
+    fn accounts_are_deduplicated() {
+        let mut batch = TokenAdminBatch::new(TOKEN_ID, TokenAdminOperation::Associate);
+        batch
+            .accounts([account(1001), account(1002), account(1001)])
+            .account(account(1003))
+            .account_with_key(account(1002), unused_private_key());
+
+        assert_eq!(batch.get_accounts(), [account(1001), account(1002), account(1003)]);
+        assert!(batch.targets[1].signer.is_some());
+        assert!(batch.targets[0].signer.is_none());
+    }
+
+    #[test]
+    fn wipe_amounts() {
+        let mut batch = TokenAdminBatch::new(TOKEN_ID, TokenAdminOperation::Wipe);
+        batch
+            .account(account(1001))
+            .account_with_wipe_amount(account(1002), 50)
+            .account_with_wipe_amount(account(1001), 25)
+            .account_with_wipe_amount(account(1002), 75);
+
+        assert_eq!(batch.get_accounts(), [account(1001), account(1002)]);
+        assert_eq!(batch.get_wipe_amount(account(1001)), Some(25));
+        assert_eq!(batch.get_wipe_amount(account(1002)), Some(75));
+        assert_eq!(batch.get_wipe_amount(account(1003)), None);
+    }
+
+    #[test]
+    fn failed_already_satisfied() {
+        let batch = TokenAdminBatch::new(TOKEN_ID, TokenAdminOperation::Associate);
+
+        let outcome = batch.failed(
+            TEST_TX_ID,
+            Error::ReceiptStatus {
+                status: Status::TokenAlreadyAssociatedToAccount,
+                transaction_id: Some(Box::new(TEST_TX_ID)),
+            },
+        );
+
+        assert_matches!(outcome, TokenAdminOutcome::AlreadySatisfied);
+
+        let outcome = batch.failed(
+            TEST_TX_ID,
+            Error::TransactionPreCheckStatus {
+                status: Status::InvalidSignature,
+                transaction_id: Box::new(TEST_TX_ID),
+            },
+        );
+
+        assert!(outcome.is_failure());
+        assert_matches!(
+            outcome,
+            TokenAdminOutcome::Failed { transaction_id: Some(id), error: Error::TransactionPreCheckStatus { .. } }
+                if id == TEST_TX_ID
+        );
+
+        let batch = TokenAdminBatch::new(TOKEN_ID, TokenAdminOperation::Dissociate);
+        let outcome = batch.failed(
+            TEST_TX_ID,
+            Error::ReceiptStatus {
+                status: Status::TokenNotAssociatedToAccount,
+                transaction_id: Some(Box::new(TEST_TX_ID)),
+            },
+        );
+
+        assert_matches!(outcome, TokenAdminOutcome::AlreadySatisfied);
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_in_flight_zero() {
+        TokenAdminBatch::new(TOKEN_ID, TokenAdminOperation::Freeze).max_in_flight(0);
+    }
+}
@@ -0,0 +1,269 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::fmt;
+
+use crate::Error;
+
+/// An amount of a fungible token, together with the number of decimals the token uses.
+///
+/// The amount is stored in the lowest denomination of the token, so for a token with 2 decimals,
+/// `100.55` tokens are represented as `10055`.
+#[derive(Debug, Copy, Clone, Hash, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TokenAmount {
+    amount: i64,
+    decimals: u32,
+}
+
+impl TokenAmount {
+    /// Create a new `TokenAmount` from an `amount` in the lowest denomination of a token with `decimals` decimals.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::TokenAmount;
+    /// let amount = TokenAmount::from_lowest_denomination(10055, 2);
+    /// assert_eq!(amount.to_string(), "100.55");
+    /// ```
+    #[must_use]
+    pub const fn from_lowest_denomination(amount: i64, decimals: u32) -> Self {
+        Self { amount, decimals }
+    }
+
+    /// Parse a human readable amount, such as `"100.55"`, of a token with `decimals` decimals.
+    ///
+    /// # Errors
+    /// - [`Error::BasicParse`] if `s` isn't a decimal number.
+    /// - [`Error::BasicParse`] if `s` has more fractional digits than `decimals`.
+    /// - [`Error::BasicParse`] if the amount doesn't fit in an `i64` when converted to the lowest denomination.
+    ///
+    /// # Examples
+    /// ```
+    /// use hedera::TokenAmount;
+    /// let amount = TokenAmount::parse("100.55", 2).unwrap();
+    /// assert_eq!(amount.to_lowest_denomination(), 10055);
+    /// ```
+    pub fn parse(s: &str, decimals: u32) -> crate::Result<Self> {
+        let (negative, digits) = match s.strip_prefix('-') {
+            Some(rest) => (true, rest),
+            None => (false, s.strip_prefix('+').unwrap_or(s)),
+        };
+
+        let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+
+        if whole.is_empty() && fraction.is_empty() {
+            return Err(Error::basic_parse(format!("Given string `{s}` is not a token amount")));
+        }
+
+        if fraction.len() > decimals as usize {
+            return Err(Error::basic_parse(format!(
+                "Given string `{s}` has more than {decimals} decimal places"
+            )));
+        }
+
+        let overflow =
+            || Error::basic_parse(format!("Given string `{s}` is out of range for a token amount"));
+
+        let mut amount: i128 = 0;
+        for ch in whole.chars().chain(fraction.chars()) {
+            let digit = ch.to_digit(10).ok_or_else(|| {
+                Error::basic_parse(format!("Given string `{s}` is not a token amount"))
+            })?;
+
+            amount = amount
+                .checked_mul(10)
+                .and_then(|it| it.checked_add(i128::from(digit)))
+                .ok_or_else(overflow)?;
+        }
+
+        // scaling zero would never overflow, but it could loop for a very long time.
+        if amount != 0 {
+            for _ in fraction.len()..decimals as usize {
+                amount = amount.checked_mul(10).ok_or_else(overflow)?;
+            }
+        }
+
+        let amount = if negative { -amount } else { amount };
+        let amount = i64::try_from(amount).map_err(|_| overflow())?;
+
+        Ok(Self { amount, decimals })
+    }
+
+    /// Returns the amount in the lowest denomination of the token.
+    #[must_use]
+    pub const fn to_lowest_denomination(self) -> i64 {
+        self.amount
+    }
+
+    /// Returns the number of decimals of the token.
+    #[must_use]
+    pub const fn decimals(self) -> u32 {
+        self.decimals
+    }
+
+    /// Returns `true` if the amount is less than zero.
+    #[must_use]
+    pub const fn is_negative(self) -> bool {
+        self.amount < 0
+    }
+
+    /// Adds `rhs` to `self`.
+    ///
+    /// Returns `None` if the decimals of the two amounts differ or if the result would overflow.
+    #[must_use]
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if self.decimals != rhs.decimals {
+            return None;
+        }
+
+        Some(Self { amount: self.amount.checked_add(rhs.amount)?, decimals: self.decimals })
+    }
+
+    /// Subtracts `rhs` from `self`.
+    ///
+    /// Returns `None` if the decimals of the two amounts differ or if the result would overflow.
+    #[must_use]
+    pub fn checked_sub(self, rhs: Self) -> Option<Self> {
+        if self.decimals != rhs.decimals {
+            return None;
+        }
+
+        Some(Self { amount: self.amount.checked_sub(rhs.amount)?, decimals: self.decimals })
+    }
+
+    /// Multiplies `self` by `rhs`, returning `None` if the result would overflow.
+    #[must_use]
+    pub fn checked_mul(self, rhs: i64) -> Option<Self> {
+        Some(Self { amount: self.amount.checked_mul(rhs)?, decimals: self.decimals })
+    }
+
+    /// Negates `self`, returning `None` if the result would overflow.
+    #[must_use]
+    pub fn checked_neg(self) -> Option<Self> {
+        Some(Self { amount: self.amount.checked_neg()?, decimals: self.decimals })
+    }
+
+    /// Returns the amount as a `u64`, for use where negative amounts aren't meaningful.
+    ///
+    /// # Errors
+    /// - [`Error::NegativeTokenAmount`] if the amount is negative.
+    pub(crate) fn to_unsigned(self) -> crate::Result<u64> {
+        u64::try_from(self.amount).map_err(|_| Error::NegativeTokenAmount(self))
+    }
+}
+
+impl fmt::Display for TokenAmount {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let digits = self.amount.unsigned_abs().to_string();
+        let sign = if self.amount < 0 { "-" } else { "" };
+        let decimals = self.decimals as usize;
+
+        if decimals == 0 {
+            return write!(f, "{sign}{digits}");
+        }
+
+        if digits.len() > decimals {
+            let (whole, fraction) = digits.split_at(digits.len() - decimals);
+            write!(f, "{sign}{whole}.{fraction}")
+        } else {
+            write!(f, "{sign}0.{digits:0>decimals$}")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::{
+        Error,
+        TokenAmount,
+    };
+
+    #[test]
+    fn parse() {
+        assert_eq!(TokenAmount::parse("100.55", 2).unwrap().to_lowest_denomination(), 10055);
+        assert_eq!(TokenAmount::parse("100.5", 2).unwrap().to_lowest_denomination(), 10050);
+        assert_eq!(TokenAmount::parse("100", 2).unwrap().to_lowest_denomination(), 10000);
+        assert_eq!(TokenAmount::parse(".5", 2).unwrap().to_lowest_denomination(), 50);
+        assert_eq!(TokenAmount::parse("-0.01", 2).unwrap().to_lowest_denomination(), -1);
+        assert_eq!(TokenAmount::parse("+7", 0).unwrap().to_lowest_denomination(), 7);
+        assert_eq!(TokenAmount::parse("0", u32::MAX).unwrap().to_lowest_denomination(), 0);
+    }
+
+    #[test]
+    fn parse_invalid() {
+        assert_matches!(TokenAmount::parse("1.234", 2), Err(Error::BasicParse(_)));
+        assert_matches!(TokenAmount::parse("1.2", 0), Err(Error::BasicParse(_)));
+        assert_matches!(TokenAmount::parse("", 2), Err(Error::BasicParse(_)));
+        assert_matches!(TokenAmount::parse(".", 2), Err(Error::BasicParse(_)));
+        assert_matches!(TokenAmount::parse("1e5", 2), Err(Error::BasicParse(_)));
+        assert_matches!(TokenAmount::parse("92233720368547758.08", 2), Err(Error::BasicParse(_)));
+    }
+
+    #[test]
+    fn parse_extremes() {
+        assert_eq!(
+            TokenAmount::parse("92233720368547758.07", 2).unwrap().to_lowest_denomination(),
+            i64::MAX
+        );
+        assert_eq!(
+            TokenAmount::parse("-92233720368547758.08", 2).unwrap().to_lowest_denomination(),
+            i64::MIN
+        );
+    }
+
+    #[test]
+    fn display() {
+        assert_eq!(TokenAmount::from_lowest_denomination(10055, 2).to_string(), "100.55");
+        assert_eq!(TokenAmount::from_lowest_denomination(5, 3).to_string(), "0.005");
+        assert_eq!(TokenAmount::from_lowest_denomination(-5, 3).to_string(), "-0.005");
+        assert_eq!(TokenAmount::from_lowest_denomination(1000, 3).to_string(), "1.000");
+        assert_eq!(TokenAmount::from_lowest_denomination(42, 0).to_string(), "42");
+        assert_eq!(
+            TokenAmount::from_lowest_denomination(i64::MIN, 2).to_string(),
+            "-92233720368547758.08"
+        );
+    }
+
+    #[test]
+    fn display_round_trip() {
+        for s in ["100.550", "0.005", "-3.140", "0.000"] {
+            assert_eq!(TokenAmount::parse(s, 3).unwrap().to_string(), s);
+        }
+    }
+
+    #[test]
+    fn checked_arithmetic() {
+        let a = TokenAmount::from_lowest_denomination(150, 2);
+        let b = TokenAmount::from_lowest_denomination(50, 2);
+
+        assert_eq!(a.checked_add(b), Some(TokenAmount::from_lowest_denomination(200, 2)));
+        assert_eq!(a.checked_sub(b), Some(TokenAmount::from_lowest_denomination(100, 2)));
+        assert_eq!(a.checked_mul(3), Some(TokenAmount::from_lowest_denomination(450, 2)));
+        assert_eq!(a.checked_neg(), Some(TokenAmount::from_lowest_denomination(-150, 2)));
+
+        assert_eq!(a.checked_add(TokenAmount::from_lowest_denomination(50, 3)), None);
+        assert_eq!(a.checked_sub(TokenAmount::from_lowest_denomination(50, 1)), None);
+        assert_eq!(TokenAmount::from_lowest_denomination(i64::MAX, 2).checked_add(b), None);
+        assert_eq!(TokenAmount::from_lowest_denomination(i64::MIN, 2).checked_neg(), None);
+    }
+}
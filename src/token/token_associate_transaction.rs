@@ -31,6 +31,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -58,6 +59,8 @@ use crate::{
 pub type TokenAssociateTransaction = Transaction<TokenAssociateTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenAssociateTransactionData {
     /// The account to be associated with the provided tokens.
     account_id: Option<AccountId>,
@@ -92,7 +95,11 @@ impl TokenAssociateTransaction {
     }
 }
 
-impl TransactionData for TokenAssociateTransactionData {}
+impl TransactionData for TokenAssociateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.unique_token_ids(&self.token_ids);
+    }
+}
 
 impl TransactionExecute for TokenAssociateTransactionData {
     fn execute(
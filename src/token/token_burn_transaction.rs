@@ -33,10 +33,13 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     BoxGrpcFuture,
     Error,
+    Status,
+    TokenAmount,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -68,11 +71,14 @@ use crate::{
 pub type TokenBurnTransaction = Transaction<TokenBurnTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenBurnTransactionData {
     /// The token for which to burn tokens.
     token_id: Option<TokenId>,
 
     /// The amount of a fungible token to burn from the treasury account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     amount: u64,
 
     /// The serial numbers of a non-fungible token to burn from the treasury account.
@@ -104,6 +110,18 @@ impl TokenBurnTransaction {
         self
     }
 
+    /// Sets the amount of a fungible token to burn from the treasury account, given as a [`TokenAmount`].
+    ///
+    /// The decimals of `amount` aren't sent to the network, the amount is converted to the lowest denomination.
+    ///
+    /// # Errors
+    /// - [`Error::NegativeTokenAmount`](crate::Error::NegativeTokenAmount) if `amount` is negative.
+    pub fn token_amount(&mut self, amount: TokenAmount) -> crate::Result<&mut Self> {
+        let amount = amount.to_unsigned()?;
+        self.data_mut().amount = amount;
+        Ok(self)
+    }
+
     /// Returns the serial numbers of a non-fungible token to burn from the treasury account.
     #[must_use]
     pub fn get_serials(&self) -> &[i64] {
@@ -117,7 +135,16 @@ impl TokenBurnTransaction {
     }
 }
 
-impl TransactionData for TokenBurnTransactionData {}
+impl TransactionData for TokenBurnTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.batch_size("serials", self.serials.len());
+
+        if self.amount != 0 && !self.serials.is_empty() {
+            violations
+                .push(Status::InvalidTransactionBody, "only one of amount and serials may be set");
+        }
+    }
+}
 
 impl TransactionExecute for TokenBurnTransactionData {
     fn execute(
@@ -41,12 +41,14 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
     Key,
+    Status,
     Transaction,
     ValidateChecksums,
 };
@@ -76,6 +78,8 @@ use crate::{
 pub type TokenCreateTransaction = Transaction<TokenCreateTransactionData>;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenCreateTransactionData {
     /// The publicly visible name of the token.
     name: String,
@@ -87,6 +91,7 @@ pub struct TokenCreateTransactionData {
     decimals: u32,
 
     /// The initial supply of fungible tokens to to mint to the treasury account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     initial_supply: u64,
 
     /// The account which will act as a treasury for the token.
@@ -131,6 +136,7 @@ pub struct TokenCreateTransactionData {
     token_supply_type: TokenSupplyType,
 
     /// Sets the maximum number of tokens that can be in circulation.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     max_supply: u64,
 
     /// The key which can change the token's custom fee schedule.
@@ -445,6 +451,72 @@ impl TransactionData for TokenCreateTransactionData {
     fn default_max_transaction_fee(&self) -> crate::Hbar {
         crate::Hbar::from_unit(40, crate::HbarUnit::Hbar)
     }
+
+    fn validate_data(&self, violations: &mut Violations) {
+        if self.name.is_empty() {
+            violations.push(Status::MissingTokenName, "token name is empty");
+        }
+
+        violations.token_name(&self.name);
+
+        if self.symbol.is_empty() {
+            violations.push(Status::MissingTokenSymbol, "token symbol is empty");
+        }
+
+        violations.token_symbol(&self.symbol);
+        violations.memo("token memo", &self.token_memo);
+
+        if self.token_type == TokenType::NonFungibleUnique {
+            if self.decimals != 0 {
+                violations.push(
+                    Status::InvalidTokenDecimals,
+                    format!("non fungible tokens must have 0 decimals, not {}", self.decimals),
+                );
+            }
+
+            if self.initial_supply != 0 {
+                violations.push(
+                    Status::InvalidTokenInitialSupply,
+                    format!(
+                        "non fungible tokens must have an initial supply of 0, not {}",
+                        self.initial_supply
+                    ),
+                );
+            }
+
+            if self.supply_key.is_none() {
+                violations.push(
+                    Status::TokenHasNoSupplyKey,
+                    "non fungible tokens must have a supply key",
+                );
+            }
+        }
+
+        match self.token_supply_type {
+            TokenSupplyType::Finite if self.max_supply == 0 => violations.push(
+                Status::InvalidTokenMaxSupply,
+                "tokens with a finite supply must have a max supply",
+            ),
+            TokenSupplyType::Finite if self.initial_supply > self.max_supply => violations.push(
+                Status::InvalidTokenInitialSupply,
+                format!(
+                    "initial supply of {} exceeds the max supply of {}",
+                    self.initial_supply, self.max_supply
+                ),
+            ),
+            TokenSupplyType::Infinite if self.max_supply != 0 => violations.push(
+                Status::InvalidTokenMaxSupply,
+                format!(
+                    "tokens with an infinite supply must not have a max supply, found {}",
+                    self.max_supply
+                ),
+            ),
+            _ => {}
+        }
+
+        violations.custom_fees(self.custom_fees.len());
+        violations.auto_renew_period(self.auto_renew_period);
+    }
 }
 
 impl TransactionExecute for TokenCreateTransactionData {
@@ -55,6 +55,8 @@ use crate::{
 pub type TokenDeleteTransaction = Transaction<TokenDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenDeleteTransactionData {
     /// The token to be deleted.
     token_id: Option<TokenId>,
@@ -34,6 +34,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -64,6 +65,8 @@ use crate::{
 pub type TokenDissociateTransaction = Transaction<TokenDissociateTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenDissociateTransactionData {
     /// The account to be dissociated with the provided tokens.
     account_id: Option<AccountId>,
@@ -98,7 +101,11 @@ impl TokenDissociateTransaction {
     }
 }
 
-impl TransactionData for TokenDissociateTransactionData {}
+impl TransactionData for TokenDissociateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.unique_token_ids(&self.token_ids);
+    }
+}
 
 impl TransactionExecute for TokenDissociateTransactionData {
     fn execute(
@@ -34,6 +34,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     BoxGrpcFuture,
@@ -54,6 +55,8 @@ use crate::{
 pub type TokenFeeScheduleUpdateTransaction = Transaction<TokenFeeScheduleUpdateTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenFeeScheduleUpdateTransactionData {
     /// The token whose fee schedule is to be updated.
     token_id: Option<TokenId>,
@@ -92,7 +95,11 @@ impl TokenFeeScheduleUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenFeeScheduleUpdateTransactionData {}
+impl TransactionData for TokenFeeScheduleUpdateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.custom_fees(self.custom_fees.len());
+    }
+}
 
 impl TransactionExecute for TokenFeeScheduleUpdateTransactionData {
     fn execute(
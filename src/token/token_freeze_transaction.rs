@@ -59,6 +59,8 @@ use crate::{
 pub type TokenFreezeTransaction = Transaction<TokenFreezeTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenFreezeTransactionData {
     /// The account to be frozen.
     account_id: Option<AccountId>,
@@ -58,6 +58,8 @@ use crate::{
 pub type TokenGrantKycTransaction = Transaction<TokenGrantKycTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenGrantKycTransactionData {
     /// The account to be granted KYC.
     account_id: Option<AccountId>,
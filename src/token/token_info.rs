@@ -43,6 +43,8 @@ use crate::{
 
 /// Response from [`TokenInfoQuery`][crate::TokenInfoQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenInfo {
     /// The ID of the token for which information is requested.
     pub token_id: TokenId,
@@ -57,6 +59,7 @@ pub struct TokenInfo {
     pub decimals: u32,
 
     /// Total Supply of token.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     pub total_supply: u64,
 
     /// The ID of the account which is set as Treasury.
@@ -109,6 +112,7 @@ pub struct TokenInfo {
     pub supply_type: TokenSupplyType,
 
     /// The Maximum number of tokens that can be in circulation.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     pub max_supply: u64,
 
     /// The custom fees to be assessed during a transfer that transfers units of this token.
@@ -269,84 +273,14 @@ impl ToProtobuf for TokenInfo {
 
 #[cfg(test)]
 mod tests {
-    use std::str::FromStr;
-
     use expect_test::expect;
-    use time::{
-        Duration,
-        OffsetDateTime,
-    };
 
     use crate::protobuf::{
         FromProtobuf,
         ToProtobuf,
     };
-    use crate::{
-        FixedFee,
-        FractionalFee,
-        LedgerId,
-        PrivateKey,
-        TokenId,
-        TokenInfo,
-        TokenSupplyType,
-        TokenType,
-    };
-
-    fn make_token_info() -> TokenInfo {
-        let custom_fees = [
-            FixedFee {
-                fee_collector_account_id: Some("4322".parse().unwrap()),
-                all_collectors_are_exempt: false,
-                fee: crate::FixedFeeData {
-                    amount: 10,
-                    denominating_token_id: Some(TokenId::new(0, 0, 483902)),
-                },
-            }
-            .into(),
-            FractionalFee {
-                fee_collector_account_id: Some("389042".parse().unwrap()),
-                all_collectors_are_exempt: false,
-                fee: crate::FractionalFeeData {
-                    denominator: 7,
-                    numerator: 3,
-                    minimum_amount: 3,
-                    maximum_amount: 100,
-                    assessment_method: crate::FeeAssessmentMethod::Inclusive,
-                },
-            }
-            .into(),
-        ]
-        .into();
-
-        TokenInfo {
-        token_id: "0.6.9".parse().unwrap(),
-        name: "test token name".to_owned(),
-        symbol: "TTN".to_owned(),
-        decimals: 3,
-        total_supply: 1000,
-        treasury_account_id: "7.7.7".parse().unwrap(),
-        admin_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e11").unwrap().public_key().into()),
-        kyc_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e12").unwrap().public_key().into()),
-        freeze_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e13").unwrap().public_key().into()),
-        wipe_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e14").unwrap().public_key().into()),
-        supply_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e15").unwrap().public_key().into()),
-        fee_schedule_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e16").unwrap().public_key().into()),
-        default_freeze_status: Some(true),
-        default_kyc_status: Some(true),
-        is_deleted: false,
-        auto_renew_account: Some(("8.9.0").parse().unwrap()),
-        auto_renew_period: Some(Duration::hours(10)),
-        expiration_time: Some(OffsetDateTime::from_unix_timestamp(1554158542).unwrap()),
-        token_memo: "memo".to_owned(),
-        token_type: TokenType::FungibleCommon,
-        supply_type: TokenSupplyType::Finite,
-        max_supply: 1000000,
-        custom_fees,
-        pause_key: Some(PrivateKey::from_str("302e020100300506032b657004220420db484b828e64b2d8f12ce3c0a0e93a0b8cce7af1bb8f39c97732394482538e17").unwrap().public_key().into()),
-        pause_status:Some(true),
-        ledger_id: LedgerId::mainnet(),
-    }
-    }
+    use crate::token::test_helpers::make_token_info;
+    use crate::TokenInfo;
 
     #[test]
     fn serialize() {
@@ -0,0 +1,933 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::pending::{
+    PendingLedger,
+    Submitter,
+};
+use crate::signer::AnySigner;
+use crate::transaction::TransactionExecute;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    PublicKey,
+    Status,
+    TokenAssociateTransaction,
+    TokenCreateTransaction,
+    TokenGrantKycTransaction,
+    TokenId,
+    TokenType,
+    TokenUnfreezeTransaction,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransferTransaction,
+};
+
+/// The state of one step of a [`TokenLaunchFlow`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum TokenLaunchStep {
+    /// The step hasn't been started.
+    #[default]
+    NotStarted,
+
+    /// The step's transaction has been submitted, but its outcome isn't known yet.
+    Pending(TransactionId),
+
+    /// The step's transaction has reached consensus successfully.
+    Complete,
+
+    /// The step isn't needed, for instance granting KYC for a token without a KYC key.
+    Skipped,
+}
+
+impl TokenLaunchStep {
+    /// Returns `true` if nothing is left to do for this step.
+    #[must_use]
+    pub fn is_done(self) -> bool {
+        matches!(self, Self::Complete | Self::Skipped)
+    }
+}
+
+/// The state of one recipient of a [`TokenLaunchFlow`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TokenLaunchRecipientReport {
+    account_id: AccountId,
+    association: TokenLaunchStep,
+    kyc: TokenLaunchStep,
+    unfreeze: TokenLaunchStep,
+    transfer: TokenLaunchStep,
+}
+
+impl TokenLaunchRecipientReport {
+    /// Returns the account of the recipient.
+    #[must_use]
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the state of associating the recipient with the token.
+    #[must_use]
+    pub fn association(&self) -> TokenLaunchStep {
+        self.association
+    }
+
+    /// Returns the state of granting KYC for the token to the recipient.
+    #[must_use]
+    pub fn kyc(&self) -> TokenLaunchStep {
+        self.kyc
+    }
+
+    /// Returns the state of unfreezing the recipient's account for the token.
+    #[must_use]
+    pub fn unfreeze(&self) -> TokenLaunchStep {
+        self.unfreeze
+    }
+
+    /// Returns the state of transferring the recipient's allocation from the treasury.
+    #[must_use]
+    pub fn transfer(&self) -> TokenLaunchStep {
+        self.transfer
+    }
+
+    /// Returns `true` if the recipient is ready to use the token and has received its allocation.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        Stage::ALL.iter().all(|&stage| self.step(stage).is_done())
+    }
+
+    fn step(&self, stage: Stage) -> TokenLaunchStep {
+        match stage {
+            Stage::Association => self.association,
+            Stage::Kyc => self.kyc,
+            Stage::Unfreeze => self.unfreeze,
+            Stage::Transfer => self.transfer,
+        }
+    }
+
+    fn step_mut(&mut self, stage: Stage) -> &mut TokenLaunchStep {
+        match stage {
+            Stage::Association => &mut self.association,
+            Stage::Kyc => &mut self.kyc,
+            Stage::Unfreeze => &mut self.unfreeze,
+            Stage::Transfer => &mut self.transfer,
+        }
+    }
+}
+
+/// Tracks how far a [`TokenLaunchFlow`] has gotten: the ID of the token once it's created, and the state of each recipient.
+///
+/// Executing a flow again with the same report only does what's missing.
+/// The report belongs to one flow, and must not be reused for another.
+///
+/// A [pending](TokenLaunchStep::Pending) step isn't submitted again until a later execution finds its receipt
+/// or [`clear_pending`](Self::clear_pending) is called.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TokenLaunchReport {
+    create: TokenLaunchStep,
+    token_id: Option<TokenId>,
+    recipients: Vec<TokenLaunchRecipientReport>,
+}
+
+impl TokenLaunchReport {
+    /// Create a new `TokenLaunchReport`, for a flow that hasn't been executed yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the state of creating the token.
+    #[must_use]
+    pub fn create(&self) -> TokenLaunchStep {
+        self.create
+    }
+
+    /// Returns the ID of the token, once it's been created.
+    #[must_use]
+    pub fn token_id(&self) -> Option<TokenId> {
+        self.token_id
+    }
+
+    /// Returns the state of each recipient, in the order of the flow's recipients.
+    ///
+    /// This is empty until the flow is first executed.
+    #[must_use]
+    pub fn recipients(&self) -> &[TokenLaunchRecipientReport] {
+        &self.recipients
+    }
+
+    /// Returns `true` if the token has been created and every recipient is complete.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.create.is_done() && self.recipients.iter().all(TokenLaunchRecipientReport::is_complete)
+    }
+
+    /// Returns the IDs of the transactions whose outcome isn't known yet.
+    #[must_use]
+    pub fn pending_transaction_ids(&self) -> Vec<TransactionId> {
+        self.steps()
+            .filter_map(|step| match step {
+                TokenLaunchStep::Pending(transaction_id) => Some(transaction_id),
+                _ => None,
+            })
+            .collect()
+    }
+
+    /// Forget the pending step of `transaction_id`, so that it's submitted again by the next execution.
+    ///
+    /// Only call this once it's certain that the transaction didn't reach consensus,
+    /// or, for instance, the token may be created twice.
+    pub fn clear_pending(&mut self, transaction_id: TransactionId) -> &mut Self {
+        if let Some(step) = self.find_pending(transaction_id) {
+            *step = TokenLaunchStep::NotStarted;
+        }
+
+        self
+    }
+
+    fn steps(&self) -> impl Iterator<Item = TokenLaunchStep> + '_ {
+        std::iter::once(self.create).chain(
+            self.recipients
+                .iter()
+                .flat_map(|recipient| Stage::ALL.iter().map(|&stage| recipient.step(stage))),
+        )
+    }
+
+    fn step_mut(&mut self, at: StepRef) -> &mut TokenLaunchStep {
+        match at {
+            StepRef::Create => &mut self.create,
+            StepRef::Recipient(index, stage) => self.recipients[index].step_mut(stage),
+        }
+    }
+
+    fn find_pending(&mut self, transaction_id: TransactionId) -> Option<&mut TokenLaunchStep> {
+        std::iter::once(&mut self.create)
+            .chain(self.recipients.iter_mut().flat_map(|recipient| {
+                [
+                    &mut recipient.association,
+                    &mut recipient.kyc,
+                    &mut recipient.unfreeze,
+                    &mut recipient.transfer,
+                ]
+            }))
+            .find(|step| **step == TokenLaunchStep::Pending(transaction_id))
+    }
+
+    fn confirm(&mut self, transaction_id: TransactionId, token_id: Option<TokenId>) {
+        let is_create = self.create == TokenLaunchStep::Pending(transaction_id);
+
+        if let Some(step) = self.find_pending(transaction_id) {
+            *step = TokenLaunchStep::Complete;
+        }
+
+        if is_create {
+            self.token_id = token_id;
+        }
+    }
+}
+
+impl PendingLedger for TokenLaunchReport {
+    fn is_success(status: Status) -> bool {
+        // the account was associated some other way, which is just as good.
+        matches!(status, Status::Success | Status::TokenAlreadyAssociatedToAccount)
+    }
+
+    fn confirm_pending(&mut self, transaction_id: TransactionId, receipt: &TransactionReceipt) {
+        self.confirm(transaction_id, receipt.token_id);
+    }
+
+    fn forget_pending(&mut self, transaction_id: TransactionId) {
+        self.clear_pending(transaction_id);
+    }
+}
+
+/// One recipient of a [`TokenLaunchFlow`], and their allocation of the token.
+#[derive(Debug, Clone)]
+pub struct TokenLaunchRecipient {
+    account_id: AccountId,
+    amount: u64,
+    associate: bool,
+    signer: Option<AnySigner>,
+}
+
+impl TokenLaunchRecipient {
+    /// Create a new `TokenLaunchRecipient` for `account_id`, who receives `amount` of the token
+    /// in its lowest denomination.
+    #[must_use]
+    pub fn new(account_id: AccountId, amount: u64) -> Self {
+        Self { account_id, amount, associate: true, signer: None }
+    }
+
+    /// Returns the account of the recipient.
+    #[must_use]
+    pub fn get_account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the amount of the token the recipient receives, in its lowest denomination.
+    #[must_use]
+    pub fn get_amount(&self) -> u64 {
+        self.amount
+    }
+
+    /// Returns whether the recipient is associated with the token by the flow.
+    #[must_use]
+    pub fn get_associate(&self) -> bool {
+        self.associate
+    }
+
+    /// Sets whether the recipient is associated with the token by the flow.
+    ///
+    /// Defaults to `true`. Set this to `false` for accounts with automatic token associations to spare.
+    #[must_use]
+    pub fn associate(mut self, associate: bool) -> Self {
+        self.associate = associate;
+
+        self
+    }
+
+    /// Sets the key of the recipient's account, which signs the association, if it isn't the client's operator.
+    #[must_use]
+    pub fn sign(mut self, key: PrivateKey) -> Self {
+        self.signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer for the recipient's account, which signs the association, if it isn't the client's operator.
+    #[must_use]
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> Self {
+        self.signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+}
+
+/// Launch a fungible token: create it, and get it into the hands of its first holders.
+///
+/// The operation of this flow is as follows:
+/// 1. Create the token with the given [`TokenCreateTransaction`].
+/// 2. For each recipient, in order:
+///    1. Associate the recipient with the token (via a [`TokenAssociateTransaction`] signed by the recipient).
+///    2. Grant KYC for the token to the recipient (via a [`TokenGrantKycTransaction`]), if the token has a KYC key.
+///    3. Unfreeze the recipient's account for the token (via a [`TokenUnfreezeTransaction`]),
+///       if the token has a freeze key and freezes accounts by default.
+///    4. Transfer the recipient's allocation from the treasury (via a [`TransferTransaction`]).
+///
+/// Each step waits for the receipt of the one before it.
+/// Progress is tracked in a [`TokenLaunchReport`], which makes execution resumable:
+/// if an execution fails part way through, executing again with the same report only does what's missing.
+#[derive(Debug)]
+pub struct TokenLaunchFlow {
+    token: TokenCreateTransaction,
+    recipients: Vec<TokenLaunchRecipient>,
+    max_transaction_fee: Option<Hbar>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signers: Vec<AnySigner>,
+}
+
+impl TokenLaunchFlow {
+    /// Create a new `TokenLaunchFlow` for the token that `token` creates.
+    #[must_use]
+    pub fn new(token: TokenCreateTransaction) -> Self {
+        Self {
+            token,
+            recipients: Vec::new(),
+            max_transaction_fee: None,
+            node_account_ids: None,
+            signers: Vec::new(),
+        }
+    }
+
+    /// Returns the transaction that creates the token.
+    #[must_use]
+    pub fn get_token(&self) -> &TokenCreateTransaction {
+        &self.token
+    }
+
+    /// Returns the recipients of the token.
+    #[must_use]
+    pub fn get_recipients(&self) -> &[TokenLaunchRecipient] {
+        &self.recipients
+    }
+
+    /// Adds a recipient of the token.
+    pub fn recipient(&mut self, recipient: TokenLaunchRecipient) -> &mut Self {
+        self.recipients.push(recipient);
+
+        self
+    }
+
+    /// Adds recipients of the token.
+    pub fn recipients(
+        &mut self,
+        recipients: impl IntoIterator<Item = TokenLaunchRecipient>,
+    ) -> &mut Self {
+        self.recipients.extend(recipients);
+
+        self
+    }
+
+    /// Returns the maximum transaction fee of each transaction after the token is created.
+    #[must_use]
+    pub fn get_max_transaction_fee(&self) -> Option<Hbar> {
+        self.max_transaction_fee
+    }
+
+    /// Sets the maximum transaction fee of each transaction after the token is created.
+    ///
+    /// The token create transaction is submitted as given.
+    pub fn max_transaction_fee(&mut self, fee: Hbar) -> &mut Self {
+        self.max_transaction_fee = Some(fee);
+
+        self
+    }
+
+    /// Returns the node account IDs the transactions after the token is created are submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the node account IDs the transactions after the token is created are submitted to.
+    ///
+    /// The token create transaction is submitted as given.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Adds a key that signs every transaction but the associations.
+    ///
+    /// This is needed for the treasury, admin, KYC and freeze keys of the token that aren't the client's operator.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signers.push(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Adds a signer that signs every transaction but the associations.
+    ///
+    /// This is needed for the treasury, admin, KYC and freeze keys of the token that aren't the client's operator.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signers.push(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Does everything that `report` has no record of, returning the ID of the token.
+    ///
+    /// Steps left pending by an earlier execution are resolved first.
+    ///
+    /// # Errors
+    /// - [`Error::TokenLaunch`] if the flow can't be launched as given, or `report` is the report of a flow
+    ///   with different recipients, before anything is submitted.
+    /// - [`Error::ReceiptStatus`] if one of the transactions fails.
+    /// - [`Error::TokenLaunchUnconfirmed`] if the outcome of some transactions couldn't be found out.
+    /// - Any error from submitting a transaction or querying its receipt.
+    pub async fn execute(
+        &self,
+        client: &Client,
+        report: &mut TokenLaunchReport,
+    ) -> crate::Result<TokenId> {
+        self.execute_with_optional_timeout(client, report, None).await
+    }
+
+    /// Does everything that `report` has no record of, returning the ID of the token.
+    ///
+    /// Steps left pending by an earlier execution are resolved first.
+    ///
+    /// # Errors
+    /// - [`Error::TokenLaunch`] if the flow can't be launched as given, or `report` is the report of a flow
+    ///   with different recipients, before anything is submitted.
+    /// - [`Error::ReceiptStatus`] if one of the transactions fails.
+    /// - [`Error::TokenLaunchUnconfirmed`] if the outcome of some transactions couldn't be found out.
+    /// - Any error from submitting a transaction or querying its receipt.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        report: &mut TokenLaunchReport,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<TokenId> {
+        self.execute_with_optional_timeout(client, report, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        report: &mut TokenLaunchReport,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<TokenId> {
+        self.prepare(report)?;
+
+        let pending = report.pending_transaction_ids();
+
+        let mut submitter = Submitter::new(client, report, 1, timeout_per_transaction);
+
+        submitter.resolve(pending).await?;
+
+        if submitter.ledger().create == TokenLaunchStep::NotStarted {
+            let transaction = self.token.clone();
+            self.submit(&mut submitter, StepRef::Create, transaction, None).await?;
+        }
+
+        let Some(token_id) = submitter.ledger().token_id else {
+            return Err(Error::TokenLaunchUnconfirmed(
+                submitter.ledger().pending_transaction_ids(),
+            ));
+        };
+
+        for (index, recipient) in self.recipients.iter().enumerate() {
+            for stage in Stage::ALL {
+                match submitter.ledger().recipients[index].step(stage) {
+                    TokenLaunchStep::Complete | TokenLaunchStep::Skipped => continue,
+                    // the later steps of this recipient depend on it.
+                    TokenLaunchStep::Pending(_) => break,
+                    TokenLaunchStep::NotStarted => {}
+                }
+
+                let at = StepRef::Recipient(index, stage);
+
+                match stage {
+                    Stage::Association => {
+                        let mut transaction = TokenAssociateTransaction::new();
+                        transaction.account_id(recipient.account_id).token_ids([token_id]);
+
+                        self.submit(
+                            &mut submitter,
+                            at,
+                            self.prepare_transaction(transaction),
+                            recipient.signer.as_ref(),
+                        )
+                        .await?;
+                    }
+
+                    Stage::Kyc => {
+                        let mut transaction = TokenGrantKycTransaction::new();
+                        transaction.account_id(recipient.account_id).token_id(token_id);
+
+                        self.submit(
+                            &mut submitter,
+                            at,
+                            self.prepare_transaction(transaction),
+                            None,
+                        )
+                        .await?;
+                    }
+
+                    Stage::Unfreeze => {
+                        let mut transaction = TokenUnfreezeTransaction::new();
+                        transaction.account_id(recipient.account_id).token_id(token_id);
+
+                        self.submit(
+                            &mut submitter,
+                            at,
+                            self.prepare_transaction(transaction),
+                            None,
+                        )
+                        .await?;
+                    }
+
+                    Stage::Transfer => {
+                        let transaction = self.make_transfer(token_id, recipient);
+
+                        self.submit(
+                            &mut submitter,
+                            at,
+                            self.prepare_transaction(transaction),
+                            None,
+                        )
+                        .await?;
+                    }
+                }
+            }
+        }
+
+        let pending = report.pending_transaction_ids();
+        if !pending.is_empty() {
+            return Err(Error::TokenLaunchUnconfirmed(pending));
+        }
+
+        Ok(token_id)
+    }
+
+    /// Checks that the flow can be launched, and fills in `report` if the flow hasn't been executed yet.
+    fn prepare(&self, report: &mut TokenLaunchReport) -> crate::Result<()> {
+        let invalid = |account_id: Option<AccountId>, reason| Error::TokenLaunch {
+            account_id: account_id.map(Box::new),
+            reason,
+        };
+
+        if self.token.get_token_type() != TokenType::FungibleCommon {
+            return Err(invalid(None, "only fungible tokens can be launched"));
+        }
+
+        let Some(treasury) = self.token.get_treasury_account_id() else {
+            return Err(invalid(None, "the token has no treasury account"));
+        };
+
+        let mut total: u64 = 0;
+
+        for (index, recipient) in self.recipients.iter().enumerate() {
+            let account_id = Some(recipient.account_id);
+
+            if recipient.account_id == treasury {
+                return Err(invalid(account_id, "the treasury can't be a recipient"));
+            }
+
+            if self.recipients[..index].iter().any(|it| it.account_id == recipient.account_id) {
+                return Err(invalid(account_id, "the recipient is listed more than once"));
+            }
+
+            total = match total.checked_add(recipient.amount) {
+                Some(total)
+                    if total <= self.token.get_initial_supply() && i64::try_from(total).is_ok() =>
+                {
+                    total
+                }
+                _ => return Err(invalid(account_id, "the allocations exceed the initial supply")),
+            };
+        }
+
+        if report.recipients.is_empty() {
+            let kyc = self.token.get_kyc_key().is_some();
+            let unfreeze = self.token.get_freeze_key().is_some() && self.token.get_freeze_default();
+
+            let step = |needed: bool| {
+                if needed {
+                    TokenLaunchStep::NotStarted
+                } else {
+                    TokenLaunchStep::Skipped
+                }
+            };
+
+            report.recipients = self
+                .recipients
+                .iter()
+                .map(|recipient| TokenLaunchRecipientReport {
+                    account_id: recipient.account_id,
+                    association: step(recipient.associate),
+                    kyc: step(kyc),
+                    unfreeze: step(unfreeze),
+                    transfer: step(recipient.amount > 0),
+                })
+                .collect();
+        }
+
+        let matches = report.recipients.len() == self.recipients.len()
+            && report
+                .recipients
+                .iter()
+                .zip(&self.recipients)
+                .all(|(report, recipient)| report.account_id == recipient.account_id);
+
+        if !matches {
+            return Err(invalid(None, "the report is for different recipients than the flow"));
+        }
+
+        Ok(())
+    }
+
+    fn make_transfer(
+        &self,
+        token_id: TokenId,
+        recipient: &TokenLaunchRecipient,
+    ) -> TransferTransaction {
+        let treasury =
+            self.token.get_treasury_account_id().expect("the treasury was checked by `prepare`");
+
+        // `prepare` checked that the allocations fit in an `i64`.
+        let amount = recipient.amount as i64;
+        let decimals = self.token.get_decimals();
+
+        let mut transaction = TransferTransaction::new();
+        transaction
+            .token_transfer_with_decimals(token_id, treasury, -amount, decimals)
+            .token_transfer_with_decimals(token_id, recipient.account_id, amount, decimals);
+
+        transaction
+    }
+
+    fn prepare_transaction<D>(&self, mut transaction: Transaction<D>) -> Transaction<D> {
+        if let Some(fee) = self.max_transaction_fee {
+            transaction.max_transaction_fee(fee);
+        }
+
+        if let Some(node_account_ids) = &self.node_account_ids {
+            transaction.node_account_ids(node_account_ids.iter().copied());
+        }
+
+        transaction
+    }
+
+    /// Submits `transaction` for the step `at`, and waits for its receipt.
+    ///
+    /// `signer` signs `transaction` instead of the flow's signers, if given.
+    async fn submit<D>(
+        &self,
+        submitter: &mut Submitter<'_, TokenLaunchReport>,
+        at: StepRef,
+        mut transaction: Transaction<D>,
+        signer: Option<&AnySigner>,
+    ) -> crate::Result<()>
+    where
+        D: TransactionExecute + Send + Sync,
+    {
+        match signer {
+            Some(signer) => {
+                transaction.sign_signer(signer.clone());
+            }
+            None => {
+                for signer in &self.signers {
+                    transaction.sign_signer(signer.clone());
+                }
+            }
+        }
+
+        submitter
+            .submit(&mut transaction, |report, transaction_id| {
+                *report.step_mut(at) = TokenLaunchStep::Pending(transaction_id);
+            })
+            .await?;
+
+        submitter.finish().await
+    }
+}
+
+/// The steps of each recipient, in the order they're done.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Stage {
+    Association,
+    Kyc,
+    Unfreeze,
+    Transfer,
+}
+
+impl Stage {
+    const ALL: [Self; 4] = [Self::Association, Self::Kyc, Self::Unfreeze, Self::Transfer];
+}
+
+#[derive(Debug, Clone, Copy)]
+enum StepRef {
+    Create,
+    Recipient(usize, Stage),
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::{
+        Stage,
+        StepRef,
+    };
+    use crate::token::test_helpers::{
+        make_token_launch_flow,
+        other_tx_id,
+        TEST_TREASURY_ACCOUNT_ID,
+    };
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountId,
+        Error,
+        TokenCreateTransaction,
+        TokenId,
+        TokenLaunchFlow,
+        TokenLaunchRecipient,
+        TokenLaunchReport,
+        TokenLaunchStep,
+        TokenType,
+    };
+
+    const TOKEN_ID: TokenId = TokenId::new(0, 0, 1234);
+
+    fn recipient(num: u64) -> AccountId {
+        AccountId::new(0, 0, num)
+    }
+
+    #[test]
+    fn recipient_defaults() {
+        let recipient = TokenLaunchRecipient::new(recipient(2001), 5);
+
+        assert_eq!(recipient.get_account_id(), AccountId::new(0, 0, 2001));
+        assert_eq!(recipient.get_amount(), 5);
+        assert!(recipient.get_associate());
+    }
+
+    #[test]
+    fn prepare() {
+        let flow = make_token_launch_flow(|_| {});
+        let mut report = TokenLaunchReport::new();
+
+        flow.prepare(&mut report).unwrap();
+
+        let recipients = report.recipients();
+        assert_eq!(recipients.len(), 2);
+
+        assert_eq!(recipients[0].account_id(), recipient(2001));
+        assert_eq!(recipients[0].association(), TokenLaunchStep::NotStarted);
+        assert_eq!(recipients[0].kyc(), TokenLaunchStep::Skipped);
+        assert_eq!(recipients[0].unfreeze(), TokenLaunchStep::Skipped);
+        assert_eq!(recipients[0].transfer(), TokenLaunchStep::NotStarted);
+
+        // nothing to associate, and nothing to transfer.
+        assert!(recipients[1].is_complete());
+
+        assert_eq!(report.create(), TokenLaunchStep::NotStarted);
+        assert_eq!(report.token_id(), None);
+        assert!(!report.is_complete());
+    }
+
+    #[test]
+    fn prepare_kyc_and_freeze() {
+        let key = unused_private_key().public_key();
+
+        let flow = make_token_launch_flow(|token| {
+            token.kyc_key(key).freeze_key(key).freeze_default(true);
+        });
+
+        let mut report = TokenLaunchReport::new();
+        flow.prepare(&mut report).unwrap();
+
+        assert_eq!(report.recipients()[0].kyc(), TokenLaunchStep::NotStarted);
+        assert_eq!(report.recipients()[0].unfreeze(), TokenLaunchStep::NotStarted);
+
+        // frozen by default needs a freeze key to unfreeze with.
+        let flow = make_token_launch_flow(|token| {
+            token.freeze_key(key);
+        });
+
+        let mut report = TokenLaunchReport::new();
+        flow.prepare(&mut report).unwrap();
+
+        assert_eq!(report.recipients()[0].unfreeze(), TokenLaunchStep::Skipped);
+    }
+
+    #[test]
+    fn prepare_invalid() {
+        let mut report = TokenLaunchReport::new();
+
+        assert_matches!(
+            make_token_launch_flow(|token| {
+                token.token_type(TokenType::NonFungibleUnique);
+            })
+            .prepare(&mut report),
+            Err(Error::TokenLaunch { account_id: None, .. })
+        );
+
+        assert_matches!(
+            make_token_launch_flow(|token| {
+                token.initial_supply(99);
+            })
+            .prepare(&mut report),
+            Err(Error::TokenLaunch { account_id: Some(id), .. }) if *id == recipient(2001)
+        );
+
+        assert_matches!(
+            make_token_launch_flow(|_| {}).recipient(TokenLaunchRecipient::new(TEST_TREASURY_ACCOUNT_ID, 1)).prepare(&mut report),
+            Err(Error::TokenLaunch { account_id: Some(id), .. }) if *id == TEST_TREASURY_ACCOUNT_ID
+        );
+
+        assert_matches!(
+            make_token_launch_flow(|_| {})
+                .recipient(TokenLaunchRecipient::new(recipient(2001), 1))
+                .prepare(&mut report),
+            Err(Error::TokenLaunch { account_id: Some(id), .. }) if *id == recipient(2001)
+        );
+
+        assert_matches!(
+            TokenLaunchFlow::new(TokenCreateTransaction::new()).prepare(&mut report),
+            Err(Error::TokenLaunch { account_id: None, .. })
+        );
+
+        // nothing was filled in.
+        assert!(report.recipients().is_empty());
+    }
+
+    #[test]
+    fn resume() {
+        let flow = make_token_launch_flow(|_| {});
+        let mut report = TokenLaunchReport::new();
+        flow.prepare(&mut report).unwrap();
+
+        *report.step_mut(StepRef::Create) = TokenLaunchStep::Pending(TEST_TX_ID);
+        assert_eq!(report.pending_transaction_ids(), [TEST_TX_ID]);
+
+        report.confirm(TEST_TX_ID, Some(TOKEN_ID));
+        assert_eq!(report.create(), TokenLaunchStep::Complete);
+        assert_eq!(report.token_id(), Some(TOKEN_ID));
+
+        *report.step_mut(StepRef::Recipient(0, Stage::Association)) =
+            TokenLaunchStep::Pending(other_tx_id());
+        report.clear_pending(other_tx_id());
+        assert_eq!(report.recipients()[0].association(), TokenLaunchStep::NotStarted);
+
+        for (stage, transaction_id) in
+            [(Stage::Association, TEST_TX_ID), (Stage::Transfer, other_tx_id())]
+        {
+            *report.step_mut(StepRef::Recipient(0, stage)) =
+                TokenLaunchStep::Pending(transaction_id);
+            report.confirm(transaction_id, None);
+        }
+
+        // confirming a later step doesn't change the token.
+        assert_eq!(report.token_id(), Some(TOKEN_ID));
+        assert!(report.pending_transaction_ids().is_empty());
+        assert!(report.is_complete());
+    }
+
+    #[test]
+    fn prepare_mismatched_report() {
+        let mut report = TokenLaunchReport::new();
+        make_token_launch_flow(|_| {}).prepare(&mut report).unwrap();
+
+        let mut flow = make_token_launch_flow(|_| {});
+        flow.recipient(TokenLaunchRecipient::new(recipient(2003), 1));
+
+        assert_matches!(
+            flow.prepare(&mut report),
+            Err(Error::TokenLaunch { account_id: None, .. })
+        );
+    }
+}
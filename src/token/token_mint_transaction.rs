@@ -34,10 +34,13 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     BoxGrpcFuture,
     Error,
+    Status,
+    TokenAmount,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -64,14 +67,18 @@ use crate::{
 pub type TokenMintTransaction = Transaction<TokenMintTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenMintTransactionData {
     /// The token for which to mint tokens.
     token_id: Option<TokenId>,
 
     /// The amount of a fungible token to mint to the treasury account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     amount: u64,
 
     /// The list of metadata for a non-fungible token to mint to the treasury account.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_vec"))]
     metadata: Vec<Vec<u8>>,
 }
 
@@ -100,6 +107,18 @@ impl TokenMintTransaction {
         self
     }
 
+    /// Sets the amount of a fungible token to mint to the treasury account, given as a [`TokenAmount`].
+    ///
+    /// The decimals of `amount` aren't sent to the network, the amount is converted to the lowest denomination.
+    ///
+    /// # Errors
+    /// - [`Error::NegativeTokenAmount`](crate::Error::NegativeTokenAmount) if `amount` is negative.
+    pub fn token_amount(&mut self, amount: TokenAmount) -> crate::Result<&mut Self> {
+        let amount = amount.to_unsigned()?;
+        self.data_mut().amount = amount;
+        Ok(self)
+    }
+
     /// Returns the list of metadata for a non-fungible token to mint to the treasury account.
     #[must_use]
     pub fn get_metadata(&self) -> &[Vec<u8>] {
@@ -118,7 +137,31 @@ impl TokenMintTransaction {
     }
 }
 
-impl TransactionData for TokenMintTransactionData {}
+/// The maximum length of a single NFT's metadata, in bytes.
+pub(crate) const MAX_METADATA_LEN: usize = 100;
+
+impl TransactionData for TokenMintTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.batch_size("metadata entries", self.metadata.len());
+
+        for (index, metadata) in self.metadata.iter().enumerate() {
+            if metadata.len() > MAX_METADATA_LEN {
+                violations.push(
+                    Status::MetadataTooLong,
+                    format!(
+                        "metadata entry {index} is {} bytes, the maximum is {MAX_METADATA_LEN}",
+                        metadata.len()
+                    ),
+                );
+            }
+        }
+
+        if self.amount != 0 && !self.metadata.is_empty() {
+            violations
+                .push(Status::InvalidTransactionBody, "only one of amount and metadata may be set");
+        }
+    }
+}
 
 impl TransactionExecute for TokenMintTransactionData {
     fn execute(
@@ -185,6 +228,7 @@ impl ToProtobuf for TokenMintTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use assert_matches::assert_matches;
     use expect_test::expect;
     use hedera_proto::services::TokenMintTransactionBody;
 
@@ -199,6 +243,8 @@ mod tests {
     };
     use crate::{
         AnyTransaction,
+        Error,
+        TokenAmount,
         TokenId,
         TokenMintTransaction,
     };
@@ -358,6 +404,26 @@ mod tests {
         tx.amount(TEST_AMOUNT);
     }
 
+    #[test]
+    fn get_set_token_amount() {
+        let mut tx = TokenMintTransaction::new();
+        tx.token_amount(TokenAmount::parse("1.5", 2).unwrap()).unwrap();
+
+        assert_eq!(tx.get_amount(), 150);
+    }
+
+    #[test]
+    fn set_negative_token_amount() {
+        let mut tx = TokenMintTransaction::new();
+        tx.amount(TEST_AMOUNT);
+
+        assert_matches!(
+            tx.token_amount(TokenAmount::from_lowest_denomination(-1, 2)),
+            Err(Error::NegativeTokenAmount(_))
+        );
+        assert_eq!(tx.get_amount(), TEST_AMOUNT);
+    }
+
     #[test]
     fn get_set_metadata() {
         let mut tx = TokenMintTransaction::new();
@@ -33,6 +33,8 @@ use crate::{
 /// Response from [`TokenNftInfoQuery`][crate::TokenNftInfoQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenNftInfo {
     /// The ID of the NFT.
     pub nft_id: NftId,
@@ -44,6 +46,7 @@ pub struct TokenNftInfo {
     pub creation_time: OffsetDateTime,
 
     /// The unique metadata of the NFT.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub metadata: Vec<u8>,
 
     /// If an allowance is granted for the NFT, its corresponding spender account.
@@ -76,6 +79,15 @@ impl TokenNftInfo {
         }
         .encode_to_vec()
     }
+
+    /// Parses the metadata of the NFT as a [HIP-412](https://hips.hedera.com/hip/hip-412) metadata URI.
+    ///
+    /// # Errors
+    /// - [`Error::Hip412`](crate::Error::Hip412) if the metadata isn't a UTF-8 URI.
+    #[cfg(feature = "hip412")]
+    pub fn metadata_uri(&self) -> crate::Result<crate::NftMetadataUri> {
+        crate::NftMetadataUri::from_bytes(&self.metadata)
+    }
 }
 
 impl FromProtobuf<services::response::Response> for TokenNftInfo {
@@ -8,6 +8,8 @@ use crate::{
 
 /// Represents a transfer of an NFT from one account to another.
 #[derive(Debug, Clone, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub struct TokenNftTransfer {
     /// The ID of the NFT's token.
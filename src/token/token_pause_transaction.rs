@@ -55,6 +55,8 @@ use crate::{
 pub type TokenPauseTransaction = Transaction<TokenPauseTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenPauseTransactionData {
     /// The token to be paused.
     token_id: Option<TokenId>,
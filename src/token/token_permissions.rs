@@ -0,0 +1,304 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use crate::{
+    Key,
+    KeyVerification,
+    PublicKey,
+    TokenId,
+    TokenInfo,
+};
+
+/// An operation on a token that needs the signature of one of the token's keys.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum TokenOperation {
+    /// Update the token's properties; authorized by the admin key.
+    Update,
+
+    /// Delete the token; authorized by the admin key.
+    Delete,
+
+    /// Mint new tokens; authorized by the supply key.
+    Mint,
+
+    /// Burn tokens from the treasury; authorized by the supply key.
+    Burn,
+
+    /// Wipe tokens from an account; authorized by the wipe key.
+    Wipe,
+
+    /// Freeze or unfreeze an account for the token; authorized by the freeze key.
+    Freeze,
+
+    /// Grant or revoke KYC for the token; authorized by the KYC key.
+    Kyc,
+
+    /// Pause or unpause the token; authorized by the pause key.
+    Pause,
+
+    /// Update the token's custom fees; authorized by the fee schedule key.
+    FeeScheduleUpdate,
+}
+
+impl TokenOperation {
+    /// Every token operation.
+    pub const ALL: [Self; 9] = [
+        Self::Update,
+        Self::Delete,
+        Self::Mint,
+        Self::Burn,
+        Self::Wipe,
+        Self::Freeze,
+        Self::Kyc,
+        Self::Pause,
+        Self::FeeScheduleUpdate,
+    ];
+
+    /// Returns the key of the token described by `info` that authorizes this operation, if the token has one.
+    #[must_use]
+    pub fn key(self, info: &TokenInfo) -> Option<&Key> {
+        match self {
+            Self::Update | Self::Delete => info.admin_key.as_ref(),
+            Self::Mint | Self::Burn => info.supply_key.as_ref(),
+            Self::Wipe => info.wipe_key.as_ref(),
+            Self::Freeze => info.freeze_key.as_ref(),
+            Self::Kyc => info.kyc_key.as_ref(),
+            Self::Pause => info.pause_key.as_ref(),
+            Self::FeeScheduleUpdate => info.fee_schedule_key.as_ref(),
+        }
+    }
+}
+
+/// Whether a set of keys is able to perform a [`TokenOperation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TokenPermission {
+    /// The keys satisfy the operation's key.
+    Authorized(KeyVerification),
+
+    /// The keys don't satisfy the operation's key.
+    ///
+    /// The verification shows which parts of the key are missing.
+    Unauthorized(KeyVerification),
+
+    /// The token has no key for the operation, so it can't be performed by anyone.
+    Impossible,
+}
+
+impl TokenPermission {
+    /// Returns `true` if the operation is authorized.
+    #[must_use]
+    pub fn is_authorized(&self) -> bool {
+        matches!(self, Self::Authorized(_))
+    }
+
+    /// Returns `true` if the token has no key for the operation.
+    #[must_use]
+    pub fn is_impossible(&self) -> bool {
+        matches!(self, Self::Impossible)
+    }
+}
+
+/// Which [`TokenOperation`]s a set of keys is able to perform on a token.
+///
+/// Key lists are evaluated recursively, respecting their thresholds.
+/// Contract keys are never satisfied, since they're authorized by contract execution rather than signatures.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TokenPermissions {
+    token_id: TokenId,
+
+    /// The permission of each operation, indexed by the operation (`TokenOperation::ALL` is in declaration order).
+    permissions: [TokenPermission; TokenOperation::ALL.len()],
+}
+
+impl TokenPermissions {
+    /// Evaluate every operation on the token described by `info` against `keys`.
+    ///
+    /// To evaluate the keys of signers, pass their public keys (for instance from [`PrivateKey::public_key`](crate::PrivateKey::public_key)).
+    #[must_use]
+    pub fn new(info: &TokenInfo, keys: &[PublicKey]) -> Self {
+        let permissions = TokenOperation::ALL.map(|operation| {
+            match operation.key(info).map(|key| key.verify_keys(keys)) {
+                Some(verification) if verification.is_satisfied() => {
+                    TokenPermission::Authorized(verification)
+                }
+                Some(verification) => TokenPermission::Unauthorized(verification),
+                None => TokenPermission::Impossible,
+            }
+        });
+
+        Self { token_id: info.token_id, permissions }
+    }
+
+    /// Returns the token the permissions are for.
+    #[must_use]
+    pub fn token_id(&self) -> TokenId {
+        self.token_id
+    }
+
+    /// Returns the permission for `operation`.
+    #[must_use]
+    pub fn get(&self, operation: TokenOperation) -> &TokenPermission {
+        &self.permissions[operation as usize]
+    }
+
+    /// Returns every operation and its permission, in the order of [`TokenOperation::ALL`].
+    pub fn iter(&self) -> impl Iterator<Item = (TokenOperation, &TokenPermission)> {
+        TokenOperation::ALL.into_iter().zip(&self.permissions)
+    }
+
+    /// Returns the operations that the keys are able to perform.
+    #[must_use]
+    pub fn authorized(&self) -> Vec<TokenOperation> {
+        self.filter(TokenPermission::is_authorized)
+    }
+
+    /// Returns the operations that the keys aren't able to perform, but that other keys could.
+    #[must_use]
+    pub fn unauthorized(&self) -> Vec<TokenOperation> {
+        self.filter(|it| matches!(it, TokenPermission::Unauthorized(_)))
+    }
+
+    /// Returns the operations that can't be performed by anyone, because the token has no key for them.
+    #[must_use]
+    pub fn impossible(&self) -> Vec<TokenOperation> {
+        self.filter(TokenPermission::is_impossible)
+    }
+
+    fn filter(&self, predicate: impl Fn(&TokenPermission) -> bool) -> Vec<TokenOperation> {
+        self.iter()
+            .filter(|(_, permission)| predicate(permission))
+            .map(|(operation, _)| operation)
+            .collect()
+    }
+}
+
+impl TokenInfo {
+    /// Returns which operations `keys` are able to perform on this token.
+    ///
+    /// See [`TokenPermissions::new`].
+    #[must_use]
+    pub fn permissions(&self, keys: &[PublicKey]) -> TokenPermissions {
+        TokenPermissions::new(self, keys)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::token::test_helpers;
+    use crate::{
+        ContractId,
+        Key,
+        KeyList,
+        PrivateKey,
+        TokenId,
+        TokenInfo,
+        TokenOperation,
+        TokenPermission,
+    };
+
+    fn keyless_token_info() -> TokenInfo {
+        TokenInfo {
+            admin_key: None,
+            kyc_key: None,
+            freeze_key: None,
+            wipe_key: None,
+            supply_key: None,
+            fee_schedule_key: None,
+            pause_key: None,
+            ..test_helpers::make_token_info()
+        }
+    }
+
+    #[test]
+    fn operations_are_in_declaration_order() {
+        for (index, operation) in TokenOperation::ALL.into_iter().enumerate() {
+            assert_eq!(operation as usize, index);
+        }
+    }
+
+    #[test]
+    fn no_keys() {
+        let permissions =
+            keyless_token_info().permissions(&[PrivateKey::generate_ed25519().public_key()]);
+
+        assert_eq!(permissions.impossible(), TokenOperation::ALL);
+        assert!(permissions.authorized().is_empty());
+        assert!(permissions.unauthorized().is_empty());
+    }
+
+    #[test]
+    fn single_keys() {
+        let admin = PrivateKey::generate_ed25519();
+        let supply = PrivateKey::generate_ecdsa();
+
+        let mut info = keyless_token_info();
+        info.admin_key = Some(admin.public_key().into());
+        info.supply_key = Some(supply.public_key().into());
+        info.kyc_key = Some(admin.public_key().into());
+
+        let permissions = info.permissions(&[admin.public_key()]);
+
+        assert_eq!(permissions.token_id(), TokenId::new(0, 6, 9));
+        assert_eq!(
+            permissions.authorized(),
+            [TokenOperation::Update, TokenOperation::Delete, TokenOperation::Kyc]
+        );
+        assert_eq!(permissions.unauthorized(), [TokenOperation::Mint, TokenOperation::Burn]);
+        assert_eq!(
+            permissions.impossible(),
+            [
+                TokenOperation::Wipe,
+                TokenOperation::Freeze,
+                TokenOperation::Pause,
+                TokenOperation::FeeScheduleUpdate
+            ]
+        );
+
+        let mint = assert_matches!(permissions.get(TokenOperation::Mint), TokenPermission::Unauthorized(it) => it);
+        assert_eq!(mint.unsatisfied_keys(), [supply.public_key()]);
+    }
+
+    #[test]
+    fn threshold_key() {
+        let keys = [
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ed25519(),
+            PrivateKey::generate_ecdsa(),
+        ];
+
+        let mut key_list: KeyList = keys.iter().map(|it| Key::from(it.public_key())).collect();
+        key_list.threshold = Some(2);
+
+        let mut info = keyless_token_info();
+        info.freeze_key = Some(Key::KeyList(key_list));
+        info.wipe_key = Some(ContractId::new(0, 0, 5005).into());
+
+        let one = info.permissions(&[keys[0].public_key()]);
+        assert!(!one.get(TokenOperation::Freeze).is_authorized());
+
+        let two = info.permissions(&[keys[0].public_key(), keys[2].public_key()]);
+        assert!(two.get(TokenOperation::Freeze).is_authorized());
+
+        // contract keys can't be satisfied by signatures.
+        assert_matches!(two.get(TokenOperation::Wipe), TokenPermission::Unauthorized(_));
+    }
+}
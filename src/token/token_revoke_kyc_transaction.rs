@@ -59,6 +59,8 @@ use crate::{
 pub type TokenRevokeKycTransaction = Transaction<TokenRevokeKycTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenRevokeKycTransactionData {
     /// The account to have their KYC revoked.
     account_id: Option<AccountId>,
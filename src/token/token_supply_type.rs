@@ -29,6 +29,8 @@ use crate::{
 /// Can be used to restrict supply to a set maximum.
 /// Defaults to [`Infinite`](Self::Infinite).
 #[derive(Debug, Hash, PartialEq, Eq, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[repr(C)]
 pub enum TokenSupplyType {
     /// Indicates the token has a maximum supply of [`u64::MAX`].
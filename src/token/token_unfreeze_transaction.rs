@@ -58,6 +58,8 @@ use crate::{
 pub type TokenUnfreezeTransaction = Transaction<TokenUnfreezeTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenUnfreezeTransactionData {
     /// The account to be unfrozen.
     account_id: Option<AccountId>,
@@ -54,6 +54,8 @@ use crate::{
 pub type TokenUnpauseTransaction = Transaction<TokenUnpauseTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenUnpauseTransactionData {
     /// The token to be unpaused.
     token_id: Option<TokenId>,
@@ -38,6 +38,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -71,6 +72,8 @@ use crate::{
 pub type TokenUpdateTransaction = Transaction<TokenUpdateTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenUpdateTransactionData {
     /// The token to be updated.
     token_id: Option<TokenId>,
@@ -337,7 +340,14 @@ impl TokenUpdateTransaction {
     }
 }
 
-impl TransactionData for TokenUpdateTransactionData {}
+impl TransactionData for TokenUpdateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.token_name(&self.token_name);
+        violations.token_symbol(&self.token_symbol);
+        violations.memo("token memo", &self.token_memo);
+        violations.auto_renew_period(self.auto_renew_period);
+    }
+}
 
 impl TransactionExecute for TokenUpdateTransactionData {
     fn execute(
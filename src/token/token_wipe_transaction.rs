@@ -34,11 +34,13 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
     BoxGrpcFuture,
     Error,
+    TokenAmount,
     TokenId,
     Transaction,
     ValidateChecksums,
@@ -75,6 +77,8 @@ use crate::{
 pub type TokenWipeTransaction = Transaction<TokenWipeTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TokenWipeTransactionData {
     /// The account to be wiped.
     account_id: Option<AccountId>,
@@ -84,6 +88,7 @@ pub struct TokenWipeTransactionData {
 
     // TODO change type of `amount` from `Option<u64>` to `u64`
     /// The amount of a fungible token to wipe from the specified account.
+    #[cfg_attr(feature = "serde", serde(default, with = "crate::serde_impls::amount_opt"))]
     amount: Option<u64>,
 
     /// The serial numbers of a non-fungible token to wipe from the specified account.
@@ -128,6 +133,18 @@ impl TokenWipeTransaction {
         self
     }
 
+    /// Sets the amount of a fungible token to wipe from the specified account, given as a [`TokenAmount`].
+    ///
+    /// The decimals of `amount` aren't sent to the network, the amount is converted to the lowest denomination.
+    ///
+    /// # Errors
+    /// - [`Error::NegativeTokenAmount`](crate::Error::NegativeTokenAmount) if `amount` is negative.
+    pub fn token_amount(&mut self, amount: TokenAmount) -> crate::Result<&mut Self> {
+        let amount = amount.to_unsigned()?;
+        self.data_mut().amount = Some(amount);
+        Ok(self)
+    }
+
     /// Returns the serial numbers of a non-fungible token to wipe from the specified account.
     #[must_use]
     pub fn get_serials(&self) -> &[u64] {
@@ -141,7 +158,11 @@ impl TokenWipeTransaction {
     }
 }
 
-impl TransactionData for TokenWipeTransactionData {}
+impl TransactionData for TokenWipeTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.batch_size("serials", self.serials.len());
+    }
+}
 
 impl TransactionExecute for TokenWipeTransactionData {
     fn execute(
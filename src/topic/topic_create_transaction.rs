@@ -35,6 +35,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -56,6 +57,8 @@ use crate::{
 pub type TopicCreateTransaction = Transaction<TopicCreateTransactionData>;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicCreateTransactionData {
     /// Short publicly visible memo about the topic. No guarantee of uniqueness.
     topic_memo: String,
@@ -155,7 +158,12 @@ impl TopicCreateTransaction {
     }
 }
 
-impl TransactionData for TopicCreateTransactionData {}
+impl TransactionData for TopicCreateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        violations.memo("topic memo", &self.topic_memo);
+        violations.auto_renew_period(self.auto_renew_period);
+    }
+}
 
 impl TransactionExecute for TopicCreateTransactionData {
     fn execute(
@@ -53,6 +53,8 @@ use crate::{
 pub type TopicDeleteTransaction = Transaction<TopicDeleteTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicDeleteTransactionData {
     /// The topic ID which is being deleted in this transaction.
     topic_id: Option<TopicId>,
@@ -36,6 +36,8 @@ use crate::{
 /// Response from [`TopicInfoQuery`][crate::TopicInfoQuery].
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicInfo {
     /// The ID of the topic for which information is requested.
     pub topic_id: TopicId,
@@ -44,6 +46,7 @@ pub struct TopicInfo {
     pub topic_memo: String,
 
     /// SHA-384 running hash of (previousRunningHash, topicId, consensusTimestamp, sequenceNumber, message).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub running_hash: Vec<u8>,
 
     /// Sequence number (starting at 1 for the first submitMessage) of messages on the topic.
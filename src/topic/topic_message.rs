@@ -27,6 +27,8 @@ use crate::TransactionId;
 /// Metadata for an individual chunk
 #[non_exhaustive]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicMessageChunk {
     /// The consensus timestamp for this chunk.
     pub consensus_timestamp: OffsetDateTime,
@@ -35,6 +37,7 @@ pub struct TopicMessageChunk {
     pub content_size: usize,
 
     /// The new running hash of the topic that received the message.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub running_hash: Vec<u8>,
 
     /// Sequence number for this chunk.
@@ -44,6 +47,8 @@ pub struct TopicMessageChunk {
 /// Topic message records.
 #[non_exhaustive]
 #[derive(Clone, Debug)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicMessage {
     /// The consensus timestamp of the message.
     ///
@@ -51,11 +56,13 @@ pub struct TopicMessage {
     pub consensus_timestamp: OffsetDateTime,
 
     /// The content of the message.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub contents: Vec<u8>,
 
     /// The new running hash of the topic that received the message.
     ///
     /// If there are multiple chunks, this is taken from the *last* chunk.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub running_hash: Vec<u8>,
 
     /// Version of the SHA-384 digest used to update the running hash.
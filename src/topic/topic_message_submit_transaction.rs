@@ -40,10 +40,12 @@ use crate::transaction::{
     TransactionData,
     TransactionExecute,
     TransactionExecuteChunked,
+    Violations,
 };
 use crate::{
     BoxGrpcFuture,
     Error,
+    Status,
     TopicId,
     Transaction,
     ValidateChecksums,
@@ -62,6 +64,8 @@ use crate::{
 pub type TopicMessageSubmitTransaction = Transaction<TopicMessageSubmitTransactionData>;
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicMessageSubmitTransactionData {
     /// The topic ID to submit this message to.
     topic_id: Option<TopicId>,
@@ -99,9 +103,19 @@ impl TransactionData for TopicMessageSubmitTransactionData {
         Some(self.chunk_data())
     }
 
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut ChunkData> {
+        Some(self.chunk_data_mut())
+    }
+
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    fn validate_data(&self, violations: &mut Violations) {
+        if self.chunk_data.data.is_empty() {
+            violations.push(Status::InvalidTopicMessage, "message is empty");
+        }
+    }
 }
 
 impl ChunkedTransactionData for TopicMessageSubmitTransactionData {
@@ -213,6 +227,7 @@ impl FromProtobuf<Vec<services::ConsensusSubmitMessageTransactionBody>>
                 max_chunks: total_chunks,
                 chunk_size: NonZeroUsize::new(largest_chunk_size)
                     .unwrap_or_else(|| NonZeroUsize::new(1).unwrap()),
+                auto_chunk_size_signers: None,
                 data: message,
             },
         })
@@ -38,6 +38,7 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
 };
 use crate::{
     AccountId,
@@ -56,6 +57,8 @@ use crate::{
 pub type TopicUpdateTransaction = Transaction<TopicUpdateTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TopicUpdateTransactionData {
     /// The topic ID which is being updated in this transaction.
     topic_id: Option<TopicId>,
@@ -194,7 +197,15 @@ impl TopicUpdateTransaction {
     }
 }
 
-impl TransactionData for TopicUpdateTransactionData {}
+impl TransactionData for TopicUpdateTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        if let Some(memo) = &self.topic_memo {
+            violations.memo("topic memo", memo);
+        }
+
+        violations.auto_renew_period(self.auto_renew_period);
+    }
+}
 
 impl TransactionExecute for TopicUpdateTransactionData {
     fn execute(
@@ -25,12 +25,14 @@ use super::chunked::ChunkInfo;
 use super::{
     TransactionData,
     TransactionExecuteChunked,
+    Violations,
 };
 use crate::downcast::DowncastOwned;
 use crate::entity_id::ValidateChecksums;
 use crate::ledger_id::RefLedgerId;
 use crate::protobuf::FromProtobuf;
 use crate::transaction::{
+    SignaturePrefixMode,
     ToTransactionDataProtobuf,
     TransactionBody,
     TransactionExecute,
@@ -105,6 +107,8 @@ mod data {
 pub type AnyTransaction = Transaction<AnyTransactionData>;
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[non_exhaustive]
 pub enum AnyTransactionData {
     AccountCreate(data::AccountCreate),
@@ -374,6 +378,52 @@ impl TransactionData for AnyTransactionData {
         }
     }
 
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut super::ChunkData> {
+        match self {
+            Self::AccountCreate(it) => it.maybe_chunk_data_mut(),
+            Self::AccountUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::AccountDelete(it) => it.maybe_chunk_data_mut(),
+            Self::AccountAllowanceApprove(it) => it.maybe_chunk_data_mut(),
+            Self::AccountAllowanceDelete(it) => it.maybe_chunk_data_mut(),
+            Self::ContractCreate(it) => it.maybe_chunk_data_mut(),
+            Self::ContractUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::ContractDelete(it) => it.maybe_chunk_data_mut(),
+            Self::ContractExecute(it) => it.maybe_chunk_data_mut(),
+            Self::Transfer(it) => it.maybe_chunk_data_mut(),
+            Self::TopicCreate(it) => it.maybe_chunk_data_mut(),
+            Self::TopicUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::TopicDelete(it) => it.maybe_chunk_data_mut(),
+            Self::TopicMessageSubmit(it) => it.maybe_chunk_data_mut(),
+            Self::FileAppend(it) => it.maybe_chunk_data_mut(),
+            Self::FileCreate(it) => it.maybe_chunk_data_mut(),
+            Self::FileUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::FileDelete(it) => it.maybe_chunk_data_mut(),
+            Self::Prng(it) => it.maybe_chunk_data_mut(),
+            Self::TokenAssociate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenBurn(it) => it.maybe_chunk_data_mut(),
+            Self::TokenCreate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenDelete(it) => it.maybe_chunk_data_mut(),
+            Self::TokenDissociate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenFeeScheduleUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenFreeze(it) => it.maybe_chunk_data_mut(),
+            Self::TokenGrantKyc(it) => it.maybe_chunk_data_mut(),
+            Self::TokenMint(it) => it.maybe_chunk_data_mut(),
+            Self::TokenPause(it) => it.maybe_chunk_data_mut(),
+            Self::TokenRevokeKyc(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUnfreeze(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUnpause(it) => it.maybe_chunk_data_mut(),
+            Self::TokenUpdate(it) => it.maybe_chunk_data_mut(),
+            Self::TokenWipe(it) => it.maybe_chunk_data_mut(),
+            Self::SystemDelete(it) => it.maybe_chunk_data_mut(),
+            Self::SystemUndelete(it) => it.maybe_chunk_data_mut(),
+            Self::Freeze(it) => it.maybe_chunk_data_mut(),
+            Self::ScheduleCreate(it) => it.maybe_chunk_data_mut(),
+            Self::ScheduleSign(it) => it.maybe_chunk_data_mut(),
+            Self::ScheduleDelete(it) => it.maybe_chunk_data_mut(),
+            Self::Ethereum(it) => it.maybe_chunk_data_mut(),
+        }
+    }
+
     fn wait_for_receipt(&self) -> bool {
         match self {
             Self::AccountCreate(it) => it.wait_for_receipt(),
@@ -419,6 +469,52 @@ impl TransactionData for AnyTransactionData {
             Self::Ethereum(it) => it.wait_for_receipt(),
         }
     }
+
+    fn validate_data(&self, violations: &mut Violations) {
+        match self {
+            Self::AccountCreate(it) => it.validate_data(violations),
+            Self::AccountUpdate(it) => it.validate_data(violations),
+            Self::AccountDelete(it) => it.validate_data(violations),
+            Self::AccountAllowanceApprove(it) => it.validate_data(violations),
+            Self::AccountAllowanceDelete(it) => it.validate_data(violations),
+            Self::ContractCreate(it) => it.validate_data(violations),
+            Self::ContractUpdate(it) => it.validate_data(violations),
+            Self::ContractDelete(it) => it.validate_data(violations),
+            Self::ContractExecute(it) => it.validate_data(violations),
+            Self::Transfer(it) => it.validate_data(violations),
+            Self::TopicCreate(it) => it.validate_data(violations),
+            Self::TopicUpdate(it) => it.validate_data(violations),
+            Self::TopicDelete(it) => it.validate_data(violations),
+            Self::TopicMessageSubmit(it) => it.validate_data(violations),
+            Self::FileAppend(it) => it.validate_data(violations),
+            Self::FileCreate(it) => it.validate_data(violations),
+            Self::FileUpdate(it) => it.validate_data(violations),
+            Self::FileDelete(it) => it.validate_data(violations),
+            Self::Prng(it) => it.validate_data(violations),
+            Self::TokenAssociate(it) => it.validate_data(violations),
+            Self::TokenBurn(it) => it.validate_data(violations),
+            Self::TokenCreate(it) => it.validate_data(violations),
+            Self::TokenDelete(it) => it.validate_data(violations),
+            Self::TokenDissociate(it) => it.validate_data(violations),
+            Self::TokenFeeScheduleUpdate(it) => it.validate_data(violations),
+            Self::TokenFreeze(it) => it.validate_data(violations),
+            Self::TokenGrantKyc(it) => it.validate_data(violations),
+            Self::TokenMint(it) => it.validate_data(violations),
+            Self::TokenPause(it) => it.validate_data(violations),
+            Self::TokenRevokeKyc(it) => it.validate_data(violations),
+            Self::TokenUnfreeze(it) => it.validate_data(violations),
+            Self::TokenUnpause(it) => it.validate_data(violations),
+            Self::TokenUpdate(it) => it.validate_data(violations),
+            Self::TokenWipe(it) => it.validate_data(violations),
+            Self::SystemDelete(it) => it.validate_data(violations),
+            Self::SystemUndelete(it) => it.validate_data(violations),
+            Self::Freeze(it) => it.validate_data(violations),
+            Self::ScheduleCreate(it) => it.validate_data(violations),
+            Self::ScheduleSign(it) => it.validate_data(violations),
+            Self::ScheduleDelete(it) => it.validate_data(violations),
+            Self::Ethereum(it) => it.validate_data(violations),
+        }
+    }
 }
 
 impl TransactionExecute for AnyTransactionData {
@@ -765,6 +861,7 @@ impl AnyTransaction {
                 operator: None,
                 is_frozen: true,
                 regenerate_transaction_id: Some(false),
+                signature_prefix_mode: SignaturePrefixMode::default(),
             },
             signers: Vec::new(),
             sources: None,
@@ -975,6 +1072,12 @@ impl AnyTransaction {
     }
 }
 
+impl DowncastOwned<AnyTransactionData> for AnyTransactionData {
+    fn downcast_owned(self) -> Result<AnyTransactionData, Self> {
+        Ok(self)
+    }
+}
+
 // this is macro worthy (there's like 40 transactions that all do this the exact same way)
 /// Impl `DowncastOwned` for `AnyTransactionData`.
 ///
@@ -0,0 +1,145 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use hedera_proto::services;
+use prost::Message;
+
+use super::execute::SignaturePair;
+use super::{
+    AnyTransactionData,
+    SignaturePrefixMode,
+    TransactionExecute,
+};
+use crate::downcast::DowncastOwned;
+use crate::{
+    AccountId,
+    PublicKey,
+    Transaction,
+    TransactionId,
+};
+
+/// The unsigned `TransactionBody` of a transaction for a single node and chunk.
+///
+/// These are what gets signed, so they can be handed off to a signer that doesn't use this SDK,
+/// and turned back into a transaction with [`Transaction::from_body_bytes`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct TransactionBodyBytes {
+    /// The account ID of the node this body will be submitted to.
+    pub node_account_id: AccountId,
+
+    /// The transaction ID of this body's chunk.
+    pub transaction_id: TransactionId,
+
+    /// The index of this body's chunk, `0` for transactions that aren't chunked.
+    pub chunk: usize,
+
+    /// The protobuf encoded `TransactionBody`.
+    pub body_bytes: Vec<u8>,
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Returns the unsigned body of every request this transaction would submit.
+    ///
+    /// The bodies are sorted by chunk, then by node (in the order given to [`node_account_ids`](Self::node_account_ids)),
+    /// which is the order [`from_body_bytes`](Self::from_body_bytes) expects them in.
+    ///
+    /// # Errors
+    /// - If the transaction needs multiple chunks, or has no explicit transaction ID *and* no operator.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`.
+    pub fn to_body_bytes(&self) -> crate::Result<Vec<TransactionBodyBytes>> {
+        assert!(self.is_frozen(), "Transaction must be frozen to call `to_body_bytes`");
+
+        if let Some(sources) = self.sources() {
+            return Ok(sources
+                .chunks()
+                .enumerate()
+                .flat_map(|(chunk, source)| {
+                    let transaction_id = source.transaction_id();
+
+                    source.signed_transactions().iter().zip(source.node_ids()).map(
+                        move |(signed, node_account_id)| TransactionBodyBytes {
+                            node_account_id: *node_account_id,
+                            transaction_id,
+                            chunk,
+                            body_bytes: signed.body_bytes.clone(),
+                        },
+                    )
+                })
+                .collect());
+        }
+
+        Ok(self
+            .chunk_infos()?
+            .iter()
+            .map(|chunk_info| TransactionBodyBytes {
+                node_account_id: chunk_info.node_account_id,
+                transaction_id: chunk_info.current_transaction_id,
+                chunk: chunk_info.current,
+                body_bytes: self.to_transaction_body_protobuf(chunk_info).encode_to_vec(),
+            })
+            .collect())
+    }
+}
+
+impl<D> Transaction<D>
+where
+    AnyTransactionData: DowncastOwned<D>,
+{
+    /// Create a transaction from protobuf encoded `TransactionBody`s and the signatures made over them.
+    ///
+    /// Each item is a body (such as [`TransactionBodyBytes::body_bytes`]) paired with the public key and signature of every signer of that body.
+    /// The bodies must be sorted by chunk, then by node, as returned by [`to_body_bytes`](Transaction::to_body_bytes).
+    ///
+    /// The bodies are kept byte-for-byte, so the signatures stay valid.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`](crate::Error::FromProtobuf) if a valid transaction cannot be parsed from the bodies.
+    /// - [`Error::TransactionTypeMismatch`](crate::Error::TransactionTypeMismatch) if the bodies are for a different kind of transaction.
+    pub fn from_body_bytes<S>(bodies: impl IntoIterator<Item = (Vec<u8>, S)>) -> crate::Result<Self>
+    where
+        S: IntoIterator<Item = (PublicKey, Vec<u8>)>,
+    {
+        let list = bodies
+            .into_iter()
+            .map(|(body_bytes, signatures)| {
+                let mut sig_pair: Vec<_> = signatures
+                    .into_iter()
+                    .map(|it| SignaturePair::from(it).into_protobuf())
+                    .collect();
+
+                SignaturePrefixMode::default().apply(&mut sig_pair);
+
+                let signed_transaction = services::SignedTransaction {
+                    body_bytes,
+                    sig_map: Some(services::SignatureMap { sig_pair }),
+                };
+
+                services::Transaction {
+                    signed_transaction_bytes: signed_transaction.encode_to_vec(),
+                    ..services::Transaction::default()
+                }
+            })
+            .collect();
+
+        Self::from_transaction_list(list)
+    }
+}
@@ -23,9 +23,14 @@ use crate::{
 
 /// Per transaction chunk data (you'd add this to any chunked transaction)
 #[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct ChunkData {
     pub(crate) max_chunks: usize,
     pub(crate) chunk_size: NonZeroUsize,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) auto_chunk_size_signers: Option<usize>,
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub(crate) data: Vec<u8>,
 }
 
@@ -34,6 +39,7 @@ impl Default for ChunkData {
         Self {
             max_chunks: Self::DEFAULT_MAX_CHUNKS,
             chunk_size: Self::DEFAULT_CHUNK_SIZE,
+            auto_chunk_size_signers: None,
             data: Vec::new(),
         }
     }
@@ -30,9 +30,12 @@ use super::source::SourceChunk;
 use super::{
     ChunkData,
     TransactionSources,
+    Violations,
 };
 use crate::execute::Execute;
+use crate::key::has_valid_signature;
 use crate::ledger_id::RefLedgerId;
+use crate::signer::AnySigner;
 use crate::transaction::any::AnyTransactionData;
 use crate::transaction::protobuf::ToTransactionDataProtobuf;
 use crate::transaction::DEFAULT_TRANSACTION_VALID_DURATION;
@@ -51,6 +54,68 @@ use crate::{
     ValidateChecksums,
 };
 
+/// How the public keys of signers are written to a transaction's signature maps.
+///
+/// See [`Transaction::signature_prefix_mode`].
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub enum SignaturePrefixMode {
+    /// The shortest prefix of each public key that no other signer of the same signature map shares.
+    ///
+    /// Prefixes are at least 8 bytes long, even if a shorter one would be unique:
+    /// a transaction that's shared as bytes can be signed by more signers later,
+    /// and the network rejects a signature map where one prefix is a prefix of another,
+    /// which a prefix that's already been written can't be lengthened to avoid.
+    #[default]
+    Shortest,
+
+    /// The full public key of each signer, for compatibility with tools that expect them.
+    FullKey,
+}
+
+/// The minimum length of a prefix written by [`SignaturePrefixMode::Shortest`].
+///
+/// The chance that a new signer's key starts with any given prefix of this length is about one in 2^64.
+const MIN_PREFIX_LEN: usize = 8;
+
+impl SignaturePrefixMode {
+    /// Rewrites the prefixes of a single signature map according to `self`.
+    ///
+    /// Prefixes are only ever shortened, so this is a no-op for [`FullKey`](Self::FullKey).
+    pub(crate) fn apply(self, sig_pairs: &mut [services::SignaturePair]) {
+        if self == Self::FullKey {
+            return;
+        }
+
+        let lengths: Vec<_> = sig_pairs
+            .iter()
+            .enumerate()
+            .map(|(index, pair)| {
+                let longest_shared = sig_pairs
+                    .iter()
+                    .enumerate()
+                    .filter(|(other_index, _)| *other_index != index)
+                    .map(|(_, other)| {
+                        shared_prefix_len(&pair.pub_key_prefix, &other.pub_key_prefix)
+                    })
+                    .max()
+                    .unwrap_or(0);
+
+                (longest_shared + 1).max(MIN_PREFIX_LEN).min(pair.pub_key_prefix.len())
+            })
+            .collect();
+
+        for (pair, len) in sig_pairs.iter_mut().zip(lengths) {
+            pair.pub_key_prefix.truncate(len);
+        }
+    }
+}
+
+fn shared_prefix_len(lhs: &[u8], rhs: &[u8]) -> usize {
+    lhs.iter().zip(rhs).take_while(|(lhs, rhs)| lhs == rhs).count()
+}
+
 #[derive(Debug)]
 pub(super) struct SignaturePair {
     signature: Vec<u8>,
@@ -69,7 +134,7 @@ impl SignaturePair {
         };
         services::SignaturePair {
             signature: Some(signature),
-            // TODO: is there any way to utilize the _prefix_ nature of this field?
+            // note: this is shortened by `SignaturePrefixMode::apply` once every signature is known.
             pub_key_prefix: self.public.to_bytes_raw(),
         }
     }
@@ -111,6 +176,8 @@ where
             }
         }
 
+        self.body.signature_prefix_mode.apply(&mut signatures);
+
         let signed_transaction = services::SignedTransaction {
             body_bytes,
             sig_map: Some(services::SignatureMap { sig_pair: signatures }),
@@ -143,10 +210,18 @@ pub trait TransactionData: Clone + Into<AnyTransactionData> {
         None
     }
 
+    /// Returns the chunk data for this transaction if this is a chunked transaction.
+    fn maybe_chunk_data_mut(&mut self) -> Option<&mut ChunkData> {
+        None
+    }
+
     /// Returns `true` if `self` is a chunked transaction *and* it should wait for receipts between each chunk.
     fn wait_for_receipt(&self) -> bool {
         false
     }
+
+    /// Adds any limits enforced by the network that `self` doesn't satisfy to `violations`.
+    fn validate_data(&self, _violations: &mut Violations) {}
 }
 
 pub trait TransactionExecute:
@@ -264,9 +339,159 @@ impl<D> Transaction<D>
 where
     D: TransactionData + ToTransactionDataProtobuf,
 {
-    #[allow(deprecated)]
-    fn to_transaction_body_protobuf(&self, chunk_info: &ChunkInfo) -> services::TransactionBody {
+    pub(super) fn to_transaction_body_protobuf(
+        &self,
+        chunk_info: &ChunkInfo,
+    ) -> services::TransactionBody {
         assert!(self.is_frozen());
+        self.make_transaction_body_protobuf(chunk_info)
+    }
+
+    /// Returns the first `SignedTransaction` this transaction would submit, as closely as possible without freezing it.
+    ///
+    /// Anything [`freeze_with`](Transaction::freeze_with) would fill in is filled in with a placeholder of a typical size,
+    /// as are signatures that haven't been made yet (including the payer's, if the payer's key isn't known).
+    pub(crate) fn estimate_signed_transaction(&self) -> crate::Result<services::SignedTransaction> {
+        self.project_signed_transaction(0, 0)
+    }
+
+    /// Returns the `SignedTransaction` this transaction would submit for `chunk`, signed by at least `expected_signers` signers.
+    ///
+    /// This is [`estimate_signed_transaction`](Self::estimate_signed_transaction) for any chunk,
+    /// with placeholder signatures for any signers beyond the ones already known.
+    /// Those are assumed to be the largest kind of key (ECDSA) with a full length prefix,
+    /// so that this never underestimates the size of the transaction.
+    ///
+    /// Nothing is actually signed, known signers that haven't signed yet get placeholder signatures too.
+    ///
+    /// Errors with [`Error::ChunkIndexOutOfRange`](crate::Error::ChunkIndexOutOfRange) if the transaction has no chunk `chunk`.
+    pub(crate) fn project_signed_transaction(
+        &self,
+        chunk: usize,
+        expected_signers: usize,
+    ) -> crate::Result<services::SignedTransaction> {
+        let (body_bytes, mut sig_pair) = match self.sources() {
+            Some(sources) => {
+                let Some(source_chunk) = sources.chunks().nth(chunk) else {
+                    return Err(Error::ChunkIndexOutOfRange {
+                        index: chunk,
+                        chunks: sources.chunks_len(),
+                    });
+                };
+
+                let signed = &source_chunk.signed_transactions()[0];
+
+                let mut sig_pair =
+                    signed.sig_map.as_ref().map(|it| it.sig_pair.clone()).unwrap_or_default();
+
+                let mut unsigned: Vec<PublicKey> = Vec::new();
+
+                for public_key in self.signers().map(AnySigner::public_key) {
+                    if !unsigned.contains(&public_key)
+                        && !has_valid_signature(&public_key, &signed.body_bytes, &sig_pair)
+                    {
+                        unsigned.push(public_key);
+                    }
+                }
+
+                if !unsigned.is_empty() {
+                    sig_pair.extend(unsigned.into_iter().map(|public| {
+                        SignaturePair { signature: vec![0; 64], public }.into_protobuf()
+                    }));
+
+                    self.body.signature_prefix_mode.apply(&mut sig_pair);
+                }
+
+                (signed.body_bytes.clone(), sig_pair)
+            }
+
+            None => {
+                let chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+                if chunk >= chunks {
+                    return Err(Error::ChunkIndexOutOfRange { index: chunk, chunks });
+                }
+
+                self.project_unsigned_transaction(chunk)
+            }
+        };
+
+        let missing_signers = expected_signers.saturating_sub(sig_pair.len());
+
+        sig_pair.extend((0..missing_signers).map(|_| services::SignaturePair {
+            pub_key_prefix: vec![0; 33],
+            signature: Some(services::signature_pair::Signature::EcdsaSecp256k1(vec![0; 64])),
+        }));
+
+        Ok(services::SignedTransaction {
+            body_bytes,
+            sig_map: Some(services::SignatureMap { sig_pair }),
+        })
+    }
+
+    /// Returns the body bytes for `chunk`, along with placeholder signatures from every known signer.
+    fn project_unsigned_transaction(
+        &self,
+        chunk: usize,
+    ) -> (Vec<u8>, Vec<services::SignaturePair>) {
+        let payer_account_id =
+            self.body.operator.as_ref().map_or(AccountId::new(0, 0, 0), |it| it.account_id);
+
+        let transaction_id =
+            self.body.transaction_id.unwrap_or_else(|| TransactionId::generate(payer_account_id));
+
+        let chunk_info = ChunkInfo {
+            current: chunk,
+            total: self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks),
+            initial_transaction_id: transaction_id,
+            current_transaction_id: transaction_id,
+            node_account_id: self
+                .body
+                .node_account_ids
+                .as_deref()
+                .and_then(<[_]>::first)
+                .copied()
+                .unwrap_or(AccountId::new(0, 0, 3)),
+        };
+
+        let body_bytes = self.make_transaction_body_protobuf(&chunk_info).encode_to_vec();
+
+        let mut public_keys: Vec<PublicKey> = Vec::with_capacity(1 + self.signers.len());
+
+        let known_signers = self
+            .body
+            .operator
+            .as_ref()
+            .map(|it| it.signer.public_key())
+            .into_iter()
+            .chain(self.signers().map(AnySigner::public_key));
+
+        for public_key in known_signers {
+            if !public_keys.contains(&public_key) {
+                public_keys.push(public_key);
+            }
+        }
+
+        let mut sig_pair: Vec<_> = public_keys
+            .into_iter()
+            .map(|public| SignaturePair { signature: vec![0; 64], public }.into_protobuf())
+            .collect();
+
+        if self.body.operator.is_none() {
+            // the payer's key is unknown, assume it's a single ed25519 key.
+            sig_pair.push(services::SignaturePair {
+                pub_key_prefix: vec![0; 32],
+                signature: Some(services::signature_pair::Signature::Ed25519(vec![0; 64])),
+            });
+        }
+
+        self.body.signature_prefix_mode.apply(&mut sig_pair);
+
+        (body_bytes, sig_pair)
+    }
+
+    #[allow(deprecated)]
+    fn make_transaction_body_protobuf(&self, chunk_info: &ChunkInfo) -> services::TransactionBody {
         let data = self.body.data.to_transaction_data_protobuf(chunk_info);
 
         let max_transaction_fee = self
@@ -295,14 +520,24 @@ where
 pub(crate) struct SourceTransaction<'a, D> {
     inner: &'a Transaction<D>,
     sources: Cow<'a, TransactionSources>,
+    lazy_signing: bool,
 }
 
 impl<'a, D> SourceTransaction<'a, D> {
-    pub(crate) fn new(transaction: &'a Transaction<D>, sources: &'a TransactionSources) -> Self {
-        // fixme: be way more lazy.
-        let sources = sources.sign_with(&transaction.signers);
+    /// With `lazy_signing` the sources are left as is, and only signed for each node right before it's tried.
+    pub(crate) fn new(
+        transaction: &'a Transaction<D>,
+        sources: &'a TransactionSources,
+        lazy_signing: bool,
+    ) -> Self {
+        let sources = match lazy_signing {
+            true => Cow::Borrowed(sources),
+            false => {
+                sources.sign_with(&transaction.signers, transaction.body.signature_prefix_mode)
+            }
+        };
 
-        Self { inner: transaction, sources }
+        Self { inner: transaction, sources, lazy_signing }
     }
 
     pub(crate) async fn execute(
@@ -316,6 +551,32 @@ impl<'a, D> SourceTransaction<'a, D> {
         Ok(self.execute_all(client, timeout).await?.swap_remove(0))
     }
 
+    pub(crate) fn chunks_len(&self) -> usize {
+        self.sources.chunks_len()
+    }
+
+    /// Submits only the chunk at `index`, without waiting for its receipt.
+    pub(crate) async fn execute_chunk(
+        &self,
+        client: &Client,
+        index: usize,
+        timeout: Option<std::time::Duration>,
+    ) -> crate::Result<TransactionResponse>
+    where
+        D: TransactionExecute,
+    {
+        let chunk = self.sources.chunks().nth(index).ok_or_else(|| {
+            Error::ChunkIndexOutOfRange { index, chunks: self.sources.chunks_len() }
+        })?;
+
+        crate::execute::execute(
+            client,
+            &SourceTransactionExecuteView::new(self.inner, chunk, self.lazy_signing),
+            timeout,
+        )
+        .await
+    }
+
     pub(crate) async fn execute_all(
         &self,
         client: &Client,
@@ -328,7 +589,7 @@ impl<'a, D> SourceTransaction<'a, D> {
         for chunk in self.sources.chunks() {
             let response = crate::execute::execute(
                 client,
-                &SourceTransactionExecuteView::new(self.inner, chunk),
+                &SourceTransactionExecuteView::new(self.inner, chunk, self.lazy_signing),
                 timeout_per_chunk,
             )
             .await?;
@@ -349,13 +610,14 @@ struct SourceTransactionExecuteView<'a, D> {
     transaction: &'a Transaction<D>,
     chunk: SourceChunk<'a>,
     indecies_by_node_id: HashMap<AccountId, usize>,
+    lazy_signing: bool,
 }
 
 impl<'a, D> SourceTransactionExecuteView<'a, D> {
-    fn new(transaction: &'a Transaction<D>, chunk: SourceChunk<'a>) -> Self {
+    fn new(transaction: &'a Transaction<D>, chunk: SourceChunk<'a>, lazy_signing: bool) -> Self {
         let indecies_by_node_id =
             chunk.node_ids().iter().copied().enumerate().map(|it| (it.1, it.0)).collect();
-        Self { transaction, chunk, indecies_by_node_id }
+        Self { transaction, chunk, indecies_by_node_id, lazy_signing }
     }
 }
 
@@ -402,7 +664,17 @@ impl<'a, D: TransactionExecute> Execute for SourceTransactionExecuteView<'a, D>
         debug_assert_eq!(transaction_id, self.transaction_id().as_ref());
 
         let index = *self.indecies_by_node_id.get(&node_account_id).unwrap();
-        Ok((self.chunk.transactions()[index].clone(), self.chunk.transaction_hashes()[index]))
+
+        let request = match self.lazy_signing {
+            true => self.chunk.signed_request(
+                index,
+                &self.transaction.signers,
+                self.transaction.body.signature_prefix_mode,
+            ),
+            false => self.chunk.transactions()[index].clone(),
+        };
+
+        Ok((request, self.chunk.transaction_hashes()[index]))
     }
 
     fn execute(
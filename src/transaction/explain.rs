@@ -0,0 +1,1218 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+use std::fmt::{
+    self,
+    Display,
+    Formatter,
+};
+
+use hedera_proto::services;
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use super::{
+    ChunkInfo,
+    TransactionExecute,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::key::has_valid_signature;
+use crate::protobuf::FromProtobuf;
+use crate::signer::AnySigner;
+use crate::transaction::ToTransactionDataProtobuf;
+use crate::{
+    AccountId,
+    ContractId,
+    FileId,
+    Hbar,
+    Key,
+    PublicKey,
+    ScheduleId,
+    TokenId,
+    TokenNftTransfer,
+    TopicId,
+    Transaction,
+    TransactionId,
+};
+
+const INVALID: &str = "<invalid>";
+
+/// A structured, human readable description of what a transaction will do.
+///
+/// Created with [`Transaction::explain`], the [`Display`] implementation renders it as plain text.
+///
+/// The explanation is built from the exact transaction body that would be signed,
+/// so it stays accurate for transactions created via [`AnyTransaction::from_bytes`](crate::AnyTransaction::from_bytes).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct TransactionExplanation {
+    /// The name of the transaction type, IE, `TransferTransaction`.
+    pub transaction_type: &'static str,
+
+    /// The ID of the transaction, if one has been set or generated.
+    pub transaction_id: Option<TransactionId>,
+
+    /// The nodes this transaction may be submitted to, empty means any node on the client.
+    pub node_account_ids: Vec<AccountId>,
+
+    /// The maximum fee the payer is willing to pay for this transaction.
+    pub max_transaction_fee: Hbar,
+
+    /// The memo attached to the transaction.
+    pub transaction_memo: String,
+
+    /// The duration this transaction is valid for after its valid start.
+    pub transaction_valid_duration: Duration,
+
+    /// The public keys that have signed (or will sign) this transaction, including the operator.
+    pub signers: Vec<PublicKey>,
+
+    /// Signature prefixes that couldn't be resolved to a public key.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_vec"))]
+    pub unknown_signatures: Vec<Vec<u8>>,
+
+    /// The net effect of this transaction on each account's hbar and fungible token balances.
+    ///
+    /// Multiple transfers for the same account (and token) are combined.
+    pub balance_changes: Vec<BalanceChange>,
+
+    /// NFTs moved by this transaction.
+    pub nft_transfers: Vec<TokenNftTransfer>,
+
+    /// Transaction type specific details, in a stable order.
+    pub fields: Vec<ExplanationField>,
+
+    /// The explanation of the inner transaction, for a `ScheduleCreateTransaction`.
+    pub scheduled_transaction: Option<Box<TransactionExplanation>>,
+}
+
+impl TransactionExplanation {
+    /// Returns the time at which this transaction becomes valid, if known.
+    #[must_use]
+    pub fn valid_start(&self) -> Option<OffsetDateTime> {
+        self.transaction_id.map(|it| it.valid_start)
+    }
+
+    /// Returns the time after which this transaction will be rejected, if known.
+    #[must_use]
+    pub fn valid_until(&self) -> Option<OffsetDateTime> {
+        self.valid_start().map(|it| it + self.transaction_valid_duration)
+    }
+
+    /// Returns the value of the field with the given `name`, if present.
+    #[must_use]
+    pub fn field(&self, name: &str) -> Option<&str> {
+        self.fields.iter().find(|it| it.name == name).map(|it| it.value.as_str())
+    }
+
+    fn write_indented(&self, f: &mut Formatter<'_>, indent: usize) -> fmt::Result {
+        let pad = " ".repeat(indent);
+
+        writeln!(f, "{pad}{}", self.transaction_type)?;
+
+        let pad = " ".repeat(indent + 2);
+
+        if let Some(transaction_id) = self.transaction_id {
+            writeln!(f, "{pad}Transaction ID: {transaction_id}")?;
+        }
+
+        if !self.node_account_ids.is_empty() {
+            writeln!(f, "{pad}Nodes: {}", join(&self.node_account_ids))?;
+        }
+
+        writeln!(f, "{pad}Max transaction fee: {}", self.max_transaction_fee)?;
+
+        if !self.transaction_memo.is_empty() {
+            writeln!(f, "{pad}Memo: {:?}", self.transaction_memo)?;
+        }
+
+        match (self.valid_start(), self.valid_until()) {
+            (Some(start), Some(until)) => writeln!(f, "{pad}Valid: {start} until {until}")?,
+            _ => writeln!(f, "{pad}Valid duration: {}", self.transaction_valid_duration)?,
+        }
+
+        if self.signers.is_empty() && self.unknown_signatures.is_empty() {
+            writeln!(f, "{pad}Signers: none")?;
+        } else {
+            writeln!(f, "{pad}Signers:")?;
+
+            for signer in &self.signers {
+                writeln!(f, "{pad}  {signer}")?;
+            }
+
+            for prefix in &self.unknown_signatures {
+                writeln!(f, "{pad}  unknown key with prefix {}", hex::encode(prefix))?;
+            }
+        }
+
+        if !self.balance_changes.is_empty() {
+            writeln!(f, "{pad}Balance changes:")?;
+
+            for change in &self.balance_changes {
+                writeln!(f, "{pad}  {change}")?;
+            }
+        }
+
+        if !self.nft_transfers.is_empty() {
+            writeln!(f, "{pad}NFT transfers:")?;
+
+            for transfer in &self.nft_transfers {
+                writeln!(
+                    f,
+                    "{pad}  {}/{}: {} -> {}",
+                    transfer.token_id, transfer.serial, transfer.sender, transfer.receiver
+                )?;
+            }
+        }
+
+        for field in &self.fields {
+            writeln!(f, "{pad}{}: {}", field.name, field.value)?;
+        }
+
+        if let Some(scheduled) = &self.scheduled_transaction {
+            writeln!(f, "{pad}Scheduled transaction:")?;
+            scheduled.write_indented(f, indent + 4)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for TransactionExplanation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write_indented(f, 0)
+    }
+}
+
+/// A single named detail of a [`TransactionExplanation`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ExplanationField {
+    /// A human readable name for the field, IE, `Token`.
+    pub name: &'static str,
+
+    /// The human readable value of the field.
+    pub value: String,
+}
+
+/// The net change to an account's hbar or fungible token balance.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct BalanceChange {
+    /// The account whose balance changes.
+    pub account_id: AccountId,
+
+    /// The token whose balance changes, `None` for hbar.
+    pub token_id: Option<TokenId>,
+
+    /// The amount of the change in the smallest denomination (tinybars, or the lowest denomination of the token).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
+    pub amount: i64,
+
+    /// The decimals of the token, if known.
+    pub decimals: Option<u32>,
+}
+
+impl Display for BalanceChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self.token_id {
+            Some(token_id) => write!(
+                f,
+                "{} {}: {}",
+                self.account_id,
+                token_id,
+                format_token_amount(i128::from(self.amount), self.decimals)
+            ),
+            None => write!(f, "{}: {}", self.account_id, Hbar::from_tinybars(self.amount)),
+        }
+    }
+}
+
+impl<D: TransactionExecute> Transaction<D> {
+    /// Returns a structured, human readable description of what this transaction will do.
+    ///
+    /// Token amounts are displayed with decimals applied where the transaction itself carries them
+    /// (IE, [`token_transfer_with_decimals`](crate::TransferTransaction::token_transfer_with_decimals)),
+    /// use [`explain_with_token_decimals`](Self::explain_with_token_decimals) to provide the rest.
+    #[must_use]
+    pub fn explain(&self) -> TransactionExplanation {
+        self.explain_with_token_decimals([])
+    }
+
+    /// Returns a structured, human readable description of what this transaction will do.
+    ///
+    /// `decimals` provides the decimals for tokens (as in [`TokenInfo::decimals`](crate::TokenInfo::decimals)),
+    /// so that token amounts can be shown in whole tokens.
+    #[must_use]
+    pub fn explain_with_token_decimals(
+        &self,
+        decimals: impl IntoIterator<Item = (TokenId, u32)>,
+    ) -> TransactionExplanation {
+        let decimals: HashMap<_, _> = decimals.into_iter().collect();
+
+        let transaction_id = self.get_transaction_id().or_else(|| {
+            self.sources().map(|sources| sources.chunks().next().unwrap().transaction_id())
+        });
+
+        // the data doesn't depend on the node or transaction ID outside of chunk info.
+        let chunk_info = ChunkInfo::single(
+            transaction_id.unwrap_or_else(placeholder_transaction_id),
+            AccountId::new(0, 0, 0),
+        );
+
+        let mut explainer = Explainer::new(&decimals);
+        explainer.explain_data(self.data().to_transaction_data_protobuf(&chunk_info));
+
+        // chunked transactions only include the first chunk in the body, so report the full message.
+        if let Some(chunk_data) = self.data().maybe_chunk_data() {
+            explainer.field("Total size", format!("{} bytes", chunk_data.data.len()));
+            explainer.field("Chunks", chunk_data.used_chunks());
+        }
+
+        let Explainer {
+            transaction_type, balance_changes, nft_transfers, fields, scheduled, ..
+        } = explainer;
+
+        let mut signers: Vec<PublicKey> = Vec::new();
+        let mut unknown_signatures = Vec::new();
+
+        let known_signers = self
+            .body
+            .operator
+            .as_ref()
+            .map(|it| it.signer.public_key())
+            .into_iter()
+            .chain(self.signers().map(AnySigner::public_key));
+
+        for signer in known_signers {
+            if !signers.contains(&signer) {
+                signers.push(signer);
+            }
+        }
+
+        if let Some(sources) = self.sources() {
+            let first = sources.signed_transactions().first();
+            let body_bytes = first.map_or_else(|| [].as_slice(), |it| it.body_bytes.as_slice());
+            let sig_pairs = first
+                .and_then(|it| it.sig_map.as_ref())
+                .map_or_else(|| [].as_slice(), |it| it.sig_pair.as_slice());
+
+            for sig_pair in sig_pairs {
+                // prefixes are usually shortened, so try to match them against the keys we already know about first.
+                if signers
+                    .iter()
+                    .any(|key| has_valid_signature(key, body_bytes, std::slice::from_ref(sig_pair)))
+                {
+                    continue;
+                }
+
+                match PublicKey::from_bytes(&sig_pair.pub_key_prefix) {
+                    Ok(key) if !signers.contains(&key) => signers.push(key),
+                    Ok(_) => {}
+                    Err(_) => unknown_signatures.push(sig_pair.pub_key_prefix.clone()),
+                }
+            }
+        }
+
+        TransactionExplanation {
+            transaction_type,
+            transaction_id,
+            node_account_ids: self.get_node_account_ids().map(<[_]>::to_vec).unwrap_or_default(),
+            max_transaction_fee: self
+                .get_max_transaction_fee()
+                .unwrap_or_else(|| self.data().default_max_transaction_fee()),
+            transaction_memo: self.get_transaction_memo().to_owned(),
+            transaction_valid_duration: self
+                .get_transaction_valid_duration()
+                .unwrap_or(DEFAULT_TRANSACTION_VALID_DURATION),
+            signers,
+            unknown_signatures,
+            balance_changes,
+            nft_transfers,
+            fields,
+            scheduled_transaction: scheduled.map(Box::new),
+        }
+    }
+}
+
+fn placeholder_transaction_id() -> TransactionId {
+    TransactionId {
+        account_id: AccountId::new(0, 0, 0),
+        valid_start: OffsetDateTime::UNIX_EPOCH,
+        nonce: None,
+        scheduled: false,
+    }
+}
+
+struct Explainer<'a> {
+    decimals: &'a HashMap<TokenId, u32>,
+    transaction_type: &'static str,
+    balance_changes: Vec<BalanceChange>,
+    nft_transfers: Vec<TokenNftTransfer>,
+    fields: Vec<ExplanationField>,
+    scheduled: Option<TransactionExplanation>,
+}
+
+impl<'a> Explainer<'a> {
+    fn new(decimals: &'a HashMap<TokenId, u32>) -> Self {
+        Self {
+            decimals,
+            transaction_type: "UnknownTransaction",
+            balance_changes: Vec::new(),
+            nft_transfers: Vec::new(),
+            fields: Vec::new(),
+            scheduled: None,
+        }
+    }
+
+    fn field(&mut self, name: &'static str, value: impl ToString) {
+        self.fields.push(ExplanationField { name, value: value.to_string() });
+    }
+
+    fn field_opt(&mut self, name: &'static str, value: Option<impl ToString>) {
+        if let Some(value) = value {
+            self.field(name, value);
+        }
+    }
+
+    fn id<T, P>(&mut self, name: &'static str, pb: Option<P>)
+    where
+        T: FromProtobuf<P> + Display,
+    {
+        self.field_opt(name, pb.map(display_from_protobuf::<T, _>));
+    }
+
+    fn key(&mut self, name: &'static str, pb: Option<services::Key>) {
+        self.field_opt(
+            name,
+            pb.map(|pb| {
+                Key::from_protobuf(pb).map_or_else(|_| INVALID.to_owned(), |it| describe_key(&it))
+            }),
+        );
+    }
+
+    fn timestamp(&mut self, name: &'static str, pb: Option<services::Timestamp>) {
+        self.field_opt(name, pb.map(OffsetDateTime::from));
+    }
+
+    fn duration(&mut self, name: &'static str, pb: Option<services::Duration>) {
+        self.field_opt(name, pb.map(Duration::from));
+    }
+
+    fn memo(&mut self, name: &'static str, memo: &str) {
+        if !memo.is_empty() {
+            self.field(name, format!("{memo:?}"));
+        }
+    }
+
+    fn token_amount(
+        &mut self,
+        name: &'static str,
+        token: Option<&services::TokenId>,
+        amount: i128,
+    ) {
+        let decimals = token
+            .and_then(|it| TokenId::from_protobuf(it.clone()).ok())
+            .and_then(|it| self.decimals.get(&it).copied());
+
+        self.field(name, format_token_amount(amount, decimals));
+    }
+
+    fn serials(&mut self, name: &'static str, serials: &[i64]) {
+        if !serials.is_empty() {
+            self.field(name, join(serials));
+        }
+    }
+
+    fn staked_id(&mut self, node_id: Option<i64>, account_id: Option<services::AccountId>) {
+        self.field_opt("Staked node ID", node_id);
+        self.id::<AccountId, _>("Staked account ID", account_id);
+    }
+
+    fn balance_change(&mut self, account_id: AccountId, token_id: Option<TokenId>, amount: i64) {
+        let decimals = token_id.and_then(|it| self.decimals.get(&it).copied());
+
+        match self
+            .balance_changes
+            .iter_mut()
+            .find(|it| it.account_id == account_id && it.token_id == token_id)
+        {
+            Some(change) => change.amount = change.amount.saturating_add(amount),
+            None => {
+                self.balance_changes.push(BalanceChange { account_id, token_id, amount, decimals });
+            }
+        }
+    }
+
+    fn transfers(&mut self, body: services::CryptoTransferTransactionBody) {
+        for transfer in body.transfers.map(|it| it.account_amounts).unwrap_or_default() {
+            match transfer.account_id.and_then(|it| AccountId::from_protobuf(it).ok()) {
+                Some(account_id) => self.balance_change(account_id, None, transfer.amount),
+                None => self.field("Invalid transfer", transfer.amount),
+            }
+        }
+
+        for list in body.token_transfers {
+            let Some(token_id) = list.token.and_then(|it| TokenId::from_protobuf(it).ok()) else {
+                self.field("Invalid token transfer", INVALID);
+                continue;
+            };
+
+            if let Some(expected_decimals) = list.expected_decimals {
+                self.field("Expected decimals", format!("{token_id}: {expected_decimals}"));
+            }
+
+            for transfer in list.transfers {
+                match transfer.account_id.and_then(|it| AccountId::from_protobuf(it).ok()) {
+                    Some(account_id) => {
+                        self.balance_change(account_id, Some(token_id), transfer.amount);
+
+                        if let Some(decimals) = list.expected_decimals {
+                            self.balance_changes
+                                .iter_mut()
+                                .filter(|it| it.token_id == Some(token_id))
+                                .for_each(|it| it.decimals = Some(decimals));
+                        }
+                    }
+                    None => self.field("Invalid token transfer", transfer.amount),
+                }
+            }
+
+            for transfer in list.nft_transfers {
+                match TokenNftTransfer::from_protobuf(transfer, token_id) {
+                    Ok(transfer) => self.nft_transfers.push(transfer),
+                    Err(_) => self.field("Invalid NFT transfer", token_id),
+                }
+            }
+        }
+    }
+
+    // this is one big match over every kind of transaction.
+    #[allow(clippy::too_many_lines)]
+    fn explain_data(&mut self, data: services::transaction_body::Data) {
+        use services::contract_update_transaction_body::MemoField;
+        use services::crypto_update_transaction_body::ReceiverSigRequiredField;
+        use services::transaction_body::Data;
+
+        match data {
+            Data::CryptoTransfer(body) => {
+                self.transaction_type = "TransferTransaction";
+                self.transfers(body);
+            }
+            Data::CryptoCreateAccount(body) => {
+                self.transaction_type = "AccountCreateTransaction";
+                self.key("Key", body.key);
+                self.field("Initial balance", Hbar::from_tinybars(body.initial_balance as i64));
+                self.field("Receiver signature required", body.receiver_sig_required);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.memo("Account memo", &body.memo);
+                self.field(
+                    "Max automatic token associations",
+                    body.max_automatic_token_associations,
+                );
+                if !body.alias.is_empty() {
+                    self.field("Alias", hex::encode(&body.alias));
+                }
+                self.field("Decline staking reward", body.decline_reward);
+                let (node_id, account_id) = match body.staked_id {
+                    Some(services::crypto_create_transaction_body::StakedId::StakedNodeId(it)) => {
+                        (Some(it), None)
+                    }
+                    Some(services::crypto_create_transaction_body::StakedId::StakedAccountId(
+                        it,
+                    )) => (None, Some(it)),
+                    None => (None, None),
+                };
+                self.staked_id(node_id, account_id);
+            }
+            Data::CryptoUpdateAccount(body) => {
+                self.transaction_type = "AccountUpdateTransaction";
+                self.id::<AccountId, _>("Account", body.account_id_to_update);
+                self.key("New key", body.key);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.timestamp("Expiration time", body.expiration_time);
+                self.field_opt("Account memo", body.memo.map(|it| format!("{it:?}")));
+                self.field_opt(
+                    "Max automatic token associations",
+                    body.max_automatic_token_associations,
+                );
+                self.field_opt("Decline staking reward", body.decline_reward);
+                self.field_opt(
+                    "Receiver signature required",
+                    body.receiver_sig_required_field.map(|it| match it {
+                        ReceiverSigRequiredField::ReceiverSigRequired(it)
+                        | ReceiverSigRequiredField::ReceiverSigRequiredWrapper(it) => it,
+                    }),
+                );
+                let (node_id, account_id) = match body.staked_id {
+                    Some(services::crypto_update_transaction_body::StakedId::StakedNodeId(it)) => {
+                        (Some(it), None)
+                    }
+                    Some(services::crypto_update_transaction_body::StakedId::StakedAccountId(
+                        it,
+                    )) => (None, Some(it)),
+                    None => (None, None),
+                };
+                self.staked_id(node_id, account_id);
+            }
+            Data::CryptoDelete(body) => {
+                self.transaction_type = "AccountDeleteTransaction";
+                self.id::<AccountId, _>("Account", body.delete_account_id);
+                self.id::<AccountId, _>("Transfer remaining balance to", body.transfer_account_id);
+            }
+            Data::CryptoApproveAllowance(body) => {
+                self.transaction_type = "AccountAllowanceApproveTransaction";
+                for it in body.crypto_allowances {
+                    self.field(
+                        "Hbar allowance",
+                        format!(
+                            "{} -> {}: {}",
+                            display_from_protobuf_opt::<AccountId, _>(it.owner),
+                            display_from_protobuf_opt::<AccountId, _>(it.spender),
+                            Hbar::from_tinybars(it.amount)
+                        ),
+                    );
+                }
+                for it in body.token_allowances {
+                    let decimals = it
+                        .token_id
+                        .clone()
+                        .and_then(|it| TokenId::from_protobuf(it).ok())
+                        .and_then(|it| self.decimals.get(&it).copied());
+                    self.field(
+                        "Token allowance",
+                        format!(
+                            "{} -> {}: {} {}",
+                            display_from_protobuf_opt::<AccountId, _>(it.owner),
+                            display_from_protobuf_opt::<AccountId, _>(it.spender),
+                            format_token_amount(i128::from(it.amount), decimals),
+                            display_from_protobuf_opt::<TokenId, _>(it.token_id),
+                        ),
+                    );
+                }
+                for it in body.nft_allowances {
+                    let serials = match it.approved_for_all {
+                        Some(true) => "all serials".to_owned(),
+                        _ => format!("serials {}", join(&it.serial_numbers)),
+                    };
+                    self.field(
+                        "NFT allowance",
+                        format!(
+                            "{} -> {}: {} {serials}",
+                            display_from_protobuf_opt::<AccountId, _>(it.owner),
+                            display_from_protobuf_opt::<AccountId, _>(it.spender),
+                            display_from_protobuf_opt::<TokenId, _>(it.token_id),
+                        ),
+                    );
+                    if let Some(delegating_spender) = it.delegating_spender {
+                        self.field(
+                            "Delegating spender",
+                            display_from_protobuf::<AccountId, _>(delegating_spender),
+                        );
+                    }
+                }
+            }
+            Data::CryptoDeleteAllowance(body) => {
+                self.transaction_type = "AccountAllowanceDeleteTransaction";
+                for it in body.nft_allowances {
+                    self.field(
+                        "Remove NFT allowance",
+                        format!(
+                            "{}: {} serials {}",
+                            display_from_protobuf_opt::<AccountId, _>(it.owner),
+                            display_from_protobuf_opt::<TokenId, _>(it.token_id),
+                            join(&it.serial_numbers)
+                        ),
+                    );
+                }
+            }
+            Data::ContractCreateInstance(body) => {
+                self.transaction_type = "ContractCreateTransaction";
+                match body.initcode_source {
+                    Some(services::contract_create_transaction_body::InitcodeSource::FileId(
+                        it,
+                    )) => self.field("Bytecode file", display_from_protobuf::<FileId, _>(it)),
+                    Some(services::contract_create_transaction_body::InitcodeSource::Initcode(
+                        it,
+                    )) => self.field("Bytecode", format!("{} bytes", it.len())),
+                    None => {}
+                }
+                self.key("Admin key", body.admin_key);
+                self.field("Gas", body.gas);
+                self.field("Initial balance", Hbar::from_tinybars(body.initial_balance));
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.id::<AccountId, _>("Auto renew account", body.auto_renew_account_id);
+                self.field("Constructor parameters", hex::encode(&body.constructor_parameters));
+                self.memo("Contract memo", &body.memo);
+                self.field(
+                    "Max automatic token associations",
+                    body.max_automatic_token_associations,
+                );
+                self.field("Decline staking reward", body.decline_reward);
+                let (node_id, account_id) = match body.staked_id {
+                    Some(services::contract_create_transaction_body::StakedId::StakedNodeId(
+                        it,
+                    )) => (Some(it), None),
+                    Some(
+                        services::contract_create_transaction_body::StakedId::StakedAccountId(it),
+                    ) => (None, Some(it)),
+                    None => (None, None),
+                };
+                self.staked_id(node_id, account_id);
+            }
+            Data::ContractUpdateInstance(body) => {
+                self.transaction_type = "ContractUpdateTransaction";
+                self.id::<ContractId, _>("Contract", body.contract_id);
+                self.key("New admin key", body.admin_key);
+                self.timestamp("Expiration time", body.expiration_time);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.id::<AccountId, _>("Auto renew account", body.auto_renew_account_id);
+                self.field_opt(
+                    "Contract memo",
+                    body.memo_field.map(|it| match it {
+                        MemoField::Memo(it) | MemoField::MemoWrapper(it) => format!("{it:?}"),
+                    }),
+                );
+                self.field_opt(
+                    "Max automatic token associations",
+                    body.max_automatic_token_associations,
+                );
+                self.field_opt("Decline staking reward", body.decline_reward);
+                let (node_id, account_id) = match body.staked_id {
+                    Some(services::contract_update_transaction_body::StakedId::StakedNodeId(
+                        it,
+                    )) => (Some(it), None),
+                    Some(
+                        services::contract_update_transaction_body::StakedId::StakedAccountId(it),
+                    ) => (None, Some(it)),
+                    None => (None, None),
+                };
+                self.staked_id(node_id, account_id);
+            }
+            Data::ContractDeleteInstance(body) => {
+                self.transaction_type = "ContractDeleteTransaction";
+                self.id::<ContractId, _>("Contract", body.contract_id);
+                match body.obtainers {
+                    Some(
+                        services::contract_delete_transaction_body::Obtainers::TransferAccountId(
+                            it,
+                        ),
+                    ) => self.field(
+                        "Transfer remaining balance to",
+                        display_from_protobuf::<AccountId, _>(it),
+                    ),
+                    Some(
+                        services::contract_delete_transaction_body::Obtainers::TransferContractId(
+                            it,
+                        ),
+                    ) => self.field(
+                        "Transfer remaining balance to",
+                        display_from_protobuf::<ContractId, _>(it),
+                    ),
+                    None => {}
+                }
+            }
+            Data::ContractCall(body) => {
+                self.transaction_type = "ContractExecuteTransaction";
+                self.id::<ContractId, _>("Contract", body.contract_id);
+                self.field("Gas", body.gas);
+                self.field("Payable amount", Hbar::from_tinybars(body.amount));
+                self.field("Function parameters", hex::encode(&body.function_parameters));
+            }
+            Data::EthereumTransaction(body) => {
+                self.transaction_type = "EthereumTransaction";
+                self.field("Ethereum data", format!("{} bytes", body.ethereum_data.len()));
+                self.id::<FileId, _>("Call data file", body.call_data);
+                self.field("Max gas allowance", Hbar::from_tinybars(body.max_gas_allowance));
+            }
+            Data::FileCreate(body) => {
+                self.transaction_type = "FileCreateTransaction";
+                self.field_opt("Keys", body.keys.map(|it| describe_key_list(&it.keys, None)));
+                self.field("Contents", format!("{} bytes", body.contents.len()));
+                self.timestamp("Expiration time", body.expiration_time);
+                self.memo("File memo", &body.memo);
+            }
+            Data::FileUpdate(body) => {
+                self.transaction_type = "FileUpdateTransaction";
+                self.id::<FileId, _>("File", body.file_id);
+                self.field_opt("New keys", body.keys.map(|it| describe_key_list(&it.keys, None)));
+                self.field("Contents", format!("{} bytes", body.contents.len()));
+                self.timestamp("Expiration time", body.expiration_time);
+                self.field_opt("File memo", body.memo.map(|it| format!("{it:?}")));
+            }
+            Data::FileAppend(body) => {
+                self.transaction_type = "FileAppendTransaction";
+                self.id::<FileId, _>("File", body.file_id);
+            }
+            Data::FileDelete(body) => {
+                self.transaction_type = "FileDeleteTransaction";
+                self.id::<FileId, _>("File", body.file_id);
+            }
+            Data::ConsensusCreateTopic(body) => {
+                self.transaction_type = "TopicCreateTransaction";
+                self.key("Admin key", body.admin_key);
+                self.key("Submit key", body.submit_key);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.id::<AccountId, _>("Auto renew account", body.auto_renew_account);
+                self.memo("Topic memo", &body.memo);
+            }
+            Data::ConsensusUpdateTopic(body) => {
+                self.transaction_type = "TopicUpdateTransaction";
+                self.id::<TopicId, _>("Topic", body.topic_id);
+                self.key("New admin key", body.admin_key);
+                self.key("New submit key", body.submit_key);
+                self.timestamp("Expiration time", body.expiration_time);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.id::<AccountId, _>("Auto renew account", body.auto_renew_account);
+                self.field_opt("Topic memo", body.memo.map(|it| format!("{it:?}")));
+            }
+            Data::ConsensusDeleteTopic(body) => {
+                self.transaction_type = "TopicDeleteTransaction";
+                self.id::<TopicId, _>("Topic", body.topic_id);
+            }
+            Data::ConsensusSubmitMessage(body) => {
+                self.transaction_type = "TopicMessageSubmitTransaction";
+                self.id::<TopicId, _>("Topic", body.topic_id);
+            }
+            Data::UtilPrng(body) => {
+                self.transaction_type = "PrngTransaction";
+                if body.range > 0 {
+                    self.field("Range", body.range);
+                }
+            }
+            Data::ScheduleCreate(body) => {
+                self.transaction_type = "ScheduleCreateTransaction";
+                self.key("Admin key", body.admin_key);
+                self.id::<AccountId, _>("Payer", body.payer_account_id);
+                self.timestamp("Expiration time", body.expiration_time);
+                self.field("Wait for expiry", body.wait_for_expiry);
+                self.memo("Schedule memo", &body.memo);
+
+                if let Some(scheduled) = body.scheduled_transaction_body {
+                    match crate::schedule::scheduled_transaction_from_protobuf(scheduled) {
+                        Ok(transaction) => {
+                            self.scheduled = Some(transaction.explain_with_token_decimals(
+                                self.decimals.iter().map(|(k, v)| (*k, *v)),
+                            ));
+                        }
+                        Err(_) => self.field("Scheduled transaction", INVALID),
+                    }
+                }
+            }
+            Data::ScheduleSign(body) => {
+                self.transaction_type = "ScheduleSignTransaction";
+                self.id::<ScheduleId, _>("Schedule", body.schedule_id);
+            }
+            Data::ScheduleDelete(body) => {
+                self.transaction_type = "ScheduleDeleteTransaction";
+                self.id::<ScheduleId, _>("Schedule", body.schedule_id);
+            }
+            Data::TokenCreation(body) => {
+                self.transaction_type = "TokenCreateTransaction";
+                self.field("Name", format!("{:?}", body.name));
+                self.field("Symbol", format!("{:?}", body.symbol));
+                self.field(
+                    "Type",
+                    match services::TokenType::from_i32(body.token_type) {
+                        Some(services::TokenType::NonFungibleUnique) => "non-fungible unique",
+                        Some(services::TokenType::FungibleCommon) => "fungible common",
+                        None => INVALID,
+                    },
+                );
+                self.field("Decimals", body.decimals);
+                self.field(
+                    "Initial supply",
+                    format_token_amount(i128::from(body.initial_supply), Some(body.decimals)),
+                );
+                self.field(
+                    "Supply type",
+                    match services::TokenSupplyType::from_i32(body.supply_type) {
+                        Some(services::TokenSupplyType::Finite) => format!(
+                            "finite (max {})",
+                            format_token_amount(i128::from(body.max_supply), Some(body.decimals))
+                        ),
+                        Some(services::TokenSupplyType::Infinite) => "infinite".to_owned(),
+                        None => INVALID.to_owned(),
+                    },
+                );
+                self.id::<AccountId, _>("Treasury", body.treasury);
+                self.key("Admin key", body.admin_key);
+                self.key("KYC key", body.kyc_key);
+                self.key("Freeze key", body.freeze_key);
+                self.key("Wipe key", body.wipe_key);
+                self.key("Supply key", body.supply_key);
+                self.key("Fee schedule key", body.fee_schedule_key);
+                self.key("Pause key", body.pause_key);
+                self.field("Freeze default", body.freeze_default);
+                self.timestamp("Expiration time", body.expiry);
+                self.id::<AccountId, _>("Auto renew account", body.auto_renew_account);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.memo("Token memo", &body.memo);
+                if !body.custom_fees.is_empty() {
+                    self.field("Custom fees", body.custom_fees.len());
+                }
+            }
+            Data::TokenUpdate(body) => {
+                self.transaction_type = "TokenUpdateTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+                if !body.name.is_empty() {
+                    self.field("New name", format!("{:?}", body.name));
+                }
+                if !body.symbol.is_empty() {
+                    self.field("New symbol", format!("{:?}", body.symbol));
+                }
+                self.id::<AccountId, _>("New treasury", body.treasury);
+                self.key("New admin key", body.admin_key);
+                self.key("New KYC key", body.kyc_key);
+                self.key("New freeze key", body.freeze_key);
+                self.key("New wipe key", body.wipe_key);
+                self.key("New supply key", body.supply_key);
+                self.key("New fee schedule key", body.fee_schedule_key);
+                self.key("New pause key", body.pause_key);
+                self.timestamp("Expiration time", body.expiry);
+                self.id::<AccountId, _>("Auto renew account", body.auto_renew_account);
+                self.duration("Auto renew period", body.auto_renew_period);
+                self.field_opt("Token memo", body.memo.map(|it| format!("{it:?}")));
+            }
+            Data::TokenDeletion(body) => {
+                self.transaction_type = "TokenDeleteTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+            }
+            Data::TokenMint(body) => {
+                self.transaction_type = "TokenMintTransaction";
+                if body.amount > 0 {
+                    self.token_amount("Amount", body.token.as_ref(), i128::from(body.amount));
+                }
+                if !body.metadata.is_empty() {
+                    self.field("NFTs", body.metadata.len());
+                }
+                self.id::<TokenId, _>("Token", body.token);
+            }
+            Data::TokenBurn(body) => {
+                self.transaction_type = "TokenBurnTransaction";
+                if body.amount > 0 {
+                    self.token_amount("Amount", body.token.as_ref(), i128::from(body.amount));
+                }
+                self.serials("Serials", &body.serial_numbers);
+                self.id::<TokenId, _>("Token", body.token);
+            }
+            Data::TokenWipe(body) => {
+                self.transaction_type = "TokenWipeTransaction";
+                if body.amount > 0 {
+                    self.token_amount("Amount", body.token.as_ref(), i128::from(body.amount));
+                }
+                self.serials("Serials", &body.serial_numbers);
+                self.id::<TokenId, _>("Token", body.token);
+                self.id::<AccountId, _>("Account", body.account);
+            }
+            Data::TokenFeeScheduleUpdate(body) => {
+                self.transaction_type = "TokenFeeScheduleUpdateTransaction";
+                self.id::<TokenId, _>("Token", body.token_id);
+                self.field("Custom fees", body.custom_fees.len());
+            }
+            Data::TokenAssociate(body) => {
+                self.transaction_type = "TokenAssociateTransaction";
+                self.id::<AccountId, _>("Account", body.account);
+                self.field("Tokens", join_from_protobuf::<TokenId, _>(body.tokens));
+            }
+            Data::TokenDissociate(body) => {
+                self.transaction_type = "TokenDissociateTransaction";
+                self.id::<AccountId, _>("Account", body.account);
+                self.field("Tokens", join_from_protobuf::<TokenId, _>(body.tokens));
+            }
+            Data::TokenFreeze(body) => {
+                self.transaction_type = "TokenFreezeTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+                self.id::<AccountId, _>("Account", body.account);
+            }
+            Data::TokenUnfreeze(body) => {
+                self.transaction_type = "TokenUnfreezeTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+                self.id::<AccountId, _>("Account", body.account);
+            }
+            Data::TokenGrantKyc(body) => {
+                self.transaction_type = "TokenGrantKycTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+                self.id::<AccountId, _>("Account", body.account);
+            }
+            Data::TokenRevokeKyc(body) => {
+                self.transaction_type = "TokenRevokeKycTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+                self.id::<AccountId, _>("Account", body.account);
+            }
+            Data::TokenPause(body) => {
+                self.transaction_type = "TokenPauseTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+            }
+            Data::TokenUnpause(body) => {
+                self.transaction_type = "TokenUnpauseTransaction";
+                self.id::<TokenId, _>("Token", body.token);
+            }
+            Data::SystemDelete(body) => {
+                self.transaction_type = "SystemDeleteTransaction";
+                match body.id {
+                    Some(services::system_delete_transaction_body::Id::FileId(it)) => {
+                        self.field("File", display_from_protobuf::<FileId, _>(it));
+                    }
+                    Some(services::system_delete_transaction_body::Id::ContractId(it)) => {
+                        self.field("Contract", display_from_protobuf::<ContractId, _>(it));
+                    }
+                    None => {}
+                }
+                self.field_opt("Expiration time", body.expiration_time.map(OffsetDateTime::from));
+            }
+            Data::SystemUndelete(body) => {
+                self.transaction_type = "SystemUndeleteTransaction";
+                match body.id {
+                    Some(services::system_undelete_transaction_body::Id::FileId(it)) => {
+                        self.field("File", display_from_protobuf::<FileId, _>(it));
+                    }
+                    Some(services::system_undelete_transaction_body::Id::ContractId(it)) => {
+                        self.field("Contract", display_from_protobuf::<ContractId, _>(it));
+                    }
+                    None => {}
+                }
+            }
+            Data::Freeze(body) => {
+                self.transaction_type = "FreezeTransaction";
+                self.field_opt(
+                    "Freeze type",
+                    services::FreezeType::from_i32(body.freeze_type).map(|it| format!("{it:?}")),
+                );
+                self.timestamp("Start time", body.start_time);
+                self.id::<FileId, _>("Update file", body.update_file);
+                if !body.file_hash.is_empty() {
+                    self.field("File hash", hex::encode(&body.file_hash));
+                }
+            }
+            // the SDK doesn't create any other kinds of transactions.
+            _ => {}
+        }
+    }
+}
+
+fn display_from_protobuf<T, P>(pb: P) -> String
+where
+    T: FromProtobuf<P> + Display,
+{
+    T::from_protobuf(pb).map_or_else(|_| INVALID.to_owned(), |it| it.to_string())
+}
+
+fn display_from_protobuf_opt<T, P>(pb: Option<P>) -> String
+where
+    T: FromProtobuf<P> + Display,
+{
+    pb.map_or_else(|| INVALID.to_owned(), display_from_protobuf::<T, _>)
+}
+
+fn join_from_protobuf<T, P>(pbs: Vec<P>) -> String
+where
+    T: FromProtobuf<P> + Display,
+{
+    join(&pbs.into_iter().map(display_from_protobuf::<T, _>).collect::<Vec<_>>())
+}
+
+fn join<T: Display>(items: &[T]) -> String {
+    items.iter().map(ToString::to_string).collect::<Vec<_>>().join(", ")
+}
+
+fn describe_key(key: &Key) -> String {
+    match key {
+        Key::Single(key) => key.to_string(),
+        Key::ContractId(id) => format!("contract {id}"),
+        Key::DelegateContractId(id) => format!("delegatable contract {id}"),
+        Key::KeyList(list) => {
+            let keys: Vec<_> = list.iter().map(describe_key).collect();
+
+            match list.threshold {
+                _ if keys.is_empty() => "empty key list".to_owned(),
+                Some(threshold) => format!("{threshold} of [{}]", keys.join(", ")),
+                None => format!("all of [{}]", keys.join(", ")),
+            }
+        }
+    }
+}
+
+fn describe_key_list(keys: &[services::Key], threshold: Option<u32>) -> String {
+    match crate::KeyList::from_protobuf(services::KeyList { keys: keys.to_vec() }) {
+        Ok(mut list) => {
+            list.threshold = threshold;
+            describe_key(&Key::KeyList(list))
+        }
+        Err(_) => INVALID.to_owned(),
+    }
+}
+
+/// Formats `amount` of the smallest denomination of a token with `decimals` applied.
+pub(crate) fn format_token_amount(amount: i128, decimals: Option<u32>) -> String {
+    let Some(scale) = decimals.filter(|it| *it > 0).and_then(|it| 10_u128.checked_pow(it)) else {
+        return amount.to_string();
+    };
+
+    // `decimals` is at most 38 here, so the cast is lossless.
+    let width = decimals.unwrap_or_default() as usize;
+
+    let sign = if amount < 0 { "-" } else { "" };
+    let amount = amount.unsigned_abs();
+
+    format!("{sign}{}.{:0width$}", amount / scale, amount % scale)
+}
+
+#[cfg(test)]
+mod tests {
+    use expect_test::expect;
+
+    use super::format_token_amount;
+    use crate::transaction::test_helpers::{
+        unused_private_key,
+        TEST_NODE_ACCOUNT_IDS,
+        TEST_TX_ID,
+    };
+    use crate::{
+        AccountId,
+        AnyTransaction,
+        Hbar,
+        PrivateKey,
+        TokenId,
+        TokenMintTransaction,
+        TransferTransaction,
+    };
+
+    #[test]
+    fn format_amounts() {
+        assert_eq!(format_token_amount(150, Some(2)), "1.50");
+        assert_eq!(format_token_amount(-5, Some(3)), "-0.005");
+        assert_eq!(format_token_amount(150, Some(0)), "150");
+        assert_eq!(format_token_amount(150, None), "150");
+    }
+
+    #[test]
+    fn explain_transfer() {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(2))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::new(-1))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::new(-1))
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 2), 150, 2)
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 101), -150, 2)
+            .transaction_memo("payout")
+            .freeze()
+            .unwrap();
+
+        let explanation = tx.explain();
+
+        assert_eq!(explanation.transaction_type, "TransferTransaction");
+        assert_eq!(explanation.transaction_id, Some(TEST_TX_ID));
+        assert_eq!(explanation.node_account_ids, TEST_NODE_ACCOUNT_IDS);
+        assert_eq!(explanation.signers, [unused_private_key().public_key()]);
+        assert_eq!(explanation.balance_changes.len(), 4);
+
+        expect![[r#"
+            TransferTransaction
+              Transaction ID: 0.0.5006@1554158542.0
+              Nodes: 0.0.5005, 0.0.5006
+              Max transaction fee: 2 ℏ
+              Memo: "payout"
+              Valid: 2019-04-01 22:42:22.0 +00:00:00 until 2019-04-01 22:44:22.0 +00:00:00
+              Signers:
+                302a300506032b6570032100e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7
+              Balance changes:
+                0.0.2: 2 ℏ
+                0.0.101: -2 ℏ
+                0.0.2 0.0.5: 1.50
+                0.0.101 0.0.5: -1.50
+              Expected decimals: 0.0.5: 2
+        "#]]
+        .assert_eq(&explanation.to_string());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize() {
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 2), 150, 2)
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 101), -150, 2)
+            .freeze()
+            .unwrap();
+
+        let json = serde_json::to_value(tx.explain()).unwrap();
+
+        assert_eq!(json["transactionType"], "TransferTransaction");
+        assert_eq!(
+            json["balanceChanges"][0],
+            serde_json::json!({
+                "accountId": "0.0.2",
+                "tokenId": "0.0.5",
+                "amount": "150",
+                "decimals": 2,
+            })
+        );
+    }
+
+    #[test]
+    fn explain_mint_with_decimals() {
+        let mut tx = TokenMintTransaction::new_for_tests();
+
+        tx.token_id(TokenId::new(0, 0, 5)).amount(1_500_000).freeze().unwrap();
+
+        let explanation = tx.explain_with_token_decimals([(TokenId::new(0, 0, 5), 6)]);
+
+        assert_eq!(explanation.transaction_type, "TokenMintTransaction");
+        assert_eq!(explanation.field("Amount"), Some("1.500000"));
+        assert_eq!(explanation.field("Token"), Some("0.0.5"));
+    }
+
+    #[test]
+    fn explain_from_bytes_resolves_shortened_prefixes() -> crate::Result<()> {
+        let key1 = PrivateKey::generate_ed25519();
+        let key2 = PrivateKey::generate_ed25519();
+
+        let mut tx = TransferTransaction::new_for_tests();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(1))
+            .hbar_transfer(AccountId::new(0, 0, 101), Hbar::new(-1))
+            .freeze()?
+            .sign(key1.clone())
+            .sign(key2.clone());
+
+        let mut tx = AnyTransaction::from_bytes(&tx.to_bytes()?)?;
+
+        tx.sign(key1.clone());
+
+        let explanation = tx.explain();
+
+        // `key2` isn't known to this transaction, so its (shortened) prefix can't be resolved.
+        assert_eq!(explanation.signers, [key1.public_key()]);
+        assert_eq!(explanation.unknown_signatures.len(), 2);
+        assert!(explanation
+            .unknown_signatures
+            .iter()
+            .any(|prefix| key2.public_key().to_bytes_raw().starts_with(prefix)));
+
+        Ok(())
+    }
+}
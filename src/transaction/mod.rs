@@ -34,6 +34,11 @@ use triomphe::Arc;
 
 use crate::downcast::DowncastOwned;
 use crate::execute::execute;
+use crate::fee_estimator::request_type;
+use crate::protobuf::{
+    strip_fields,
+    unknown_fields,
+};
 use crate::signer::AnySigner;
 use crate::{
     AccountId,
@@ -43,6 +48,7 @@ use crate::{
     Operator,
     PrivateKey,
     PublicKey,
+    RequestType,
     ScheduleCreateTransaction,
     TransactionHash,
     TransactionId,
@@ -51,30 +57,54 @@ use crate::{
 };
 
 mod any;
+mod body_bytes;
 mod chunked;
 mod execute;
+mod explain;
 mod protobuf;
+mod resume;
+mod size;
 mod source;
 #[cfg(test)]
 mod tests;
+mod validate;
+mod window;
 
 pub use any::AnyTransaction;
 pub(crate) use any::AnyTransactionData;
+pub use body_bytes::TransactionBodyBytes;
 pub(crate) use chunked::{
     ChunkData,
     ChunkInfo,
     ChunkedTransactionData,
 };
+pub use execute::SignaturePrefixMode;
 pub(crate) use execute::{
     TransactionData,
     TransactionExecute,
     TransactionExecuteChunked,
 };
+pub use explain::{
+    BalanceChange,
+    ExplanationField,
+    TransactionExplanation,
+};
 pub(crate) use protobuf::{
     ToSchedulableTransactionDataProtobuf,
     ToTransactionDataProtobuf,
 };
+pub use resume::{
+    ChunkProgress,
+    ChunkReceipt,
+};
+pub(crate) use size::MAX_TRANSACTION_SIZE;
 pub(crate) use source::TransactionSources;
+pub use validate::TransactionViolation;
+pub(crate) use validate::{
+    Violations,
+    MAX_BATCH_SIZE,
+};
+pub use window::TransactionWindows;
 
 const DEFAULT_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(120);
 
@@ -89,6 +119,8 @@ pub struct Transaction<D> {
 }
 
 #[derive(Debug, Default, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub(crate) struct TransactionBody<D> {
     pub(crate) data: D,
 
@@ -102,11 +134,16 @@ pub(crate) struct TransactionBody<D> {
 
     pub(crate) transaction_id: Option<TransactionId>,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) operator: Option<Arc<Operator>>,
 
+    #[cfg_attr(feature = "serde", serde(skip))]
     pub(crate) is_frozen: bool,
 
     pub(crate) regenerate_transaction_id: Option<bool>,
+
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub(crate) signature_prefix_mode: SignaturePrefixMode,
 }
 
 impl<D> Default for Transaction<D>
@@ -125,6 +162,7 @@ where
                 operator: None,
                 is_frozen: false,
                 regenerate_transaction_id: None,
+                signature_prefix_mode: SignaturePrefixMode::default(),
             },
             signers: Vec::new(),
             sources: None,
@@ -141,6 +179,27 @@ where
     }
 }
 
+// note: only the body is (de)serialized, signatures are not, use `to_bytes` for signed transactions.
+#[cfg(feature = "serde")]
+impl<D> serde::Serialize for Transaction<D>
+where
+    D: serde::Serialize,
+{
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.body.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, D> serde::Deserialize<'de> for Transaction<D>
+where
+    D: serde::Deserialize<'de>,
+{
+    fn deserialize<De: serde::Deserializer<'de>>(deserializer: De) -> Result<Self, De::Error> {
+        TransactionBody::deserialize(deserializer).map(|body| Self::from_parts(body, Vec::new()))
+    }
+}
+
 impl<D> Transaction<D>
 where
     D: Default,
@@ -173,7 +232,7 @@ impl<D> Transaction<D> {
     }
 
     fn signed_sources(&self) -> Option<Cow<'_, TransactionSources>> {
-        self.sources().map(|it| it.sign_with(&self.signers))
+        self.sources().map(|it| it.sign_with(&self.signers, self.body.signature_prefix_mode))
     }
 
     /// # Panics
@@ -316,6 +375,24 @@ impl<D> Transaction<D> {
         self.signers.push(signer);
         self
     }
+
+    /// Returns how the public keys of signers are written to this transaction's signature maps.
+    #[must_use]
+    pub fn get_signature_prefix_mode(&self) -> SignaturePrefixMode {
+        self.body.signature_prefix_mode
+    }
+
+    /// Sets how the public keys of signers are written to this transaction's signature maps.
+    ///
+    /// By default the shortest unique prefix of each key is used.
+    ///
+    /// Since signature maps aren't part of the signed body, this can be changed after freezing,
+    /// however prefixes that have already been shortened (IE, by [`add_signature`](Self::add_signature)) stay short.
+    pub fn signature_prefix_mode(&mut self, mode: SignaturePrefixMode) -> &mut Self {
+        self.body.signature_prefix_mode = mode;
+
+        self
+    }
 }
 
 impl<D: ChunkedTransactionData> Transaction<D> {
@@ -346,7 +423,27 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     pub fn chunk_size(&mut self, size: usize) -> &mut Self {
         let Some(size) = NonZeroUsize::new(size) else { panic!("Cannot set chunk-size to zero") };
 
-        self.data_mut().chunk_data_mut().chunk_size = size;
+        let chunk_data = self.data_mut().chunk_data_mut();
+        chunk_data.chunk_size = size;
+        chunk_data.auto_chunk_size_signers = None;
+
+        self
+    }
+
+    /// Returns the number of signers the chunk size is picked for, if it's picked automatically.
+    #[must_use]
+    pub fn get_auto_chunk_size(&self) -> Option<usize> {
+        self.data().chunk_data().auto_chunk_size_signers
+    }
+
+    /// Picks the chunk size automatically when the transaction is frozen.
+    ///
+    /// The chunk size picked is the largest one where every chunk stays within the network's size limit,
+    /// once signed by up to `expected_signers` signers in total (including the payer).
+    ///
+    /// Setting [`chunk_size`](Self::chunk_size) explicitly turns this off.
+    pub fn auto_chunk_size(&mut self, expected_signers: usize) -> &mut Self {
+        self.data_mut().chunk_data_mut().auto_chunk_size_signers = Some(expected_signers);
 
         self
     }
@@ -370,7 +467,7 @@ impl<D: ChunkedTransactionData> Transaction<D> {
     }
 }
 
-impl<D: ValidateChecksums> Transaction<D> {
+impl<D: ValidateChecksums + TransactionData + ToTransactionDataProtobuf> Transaction<D> {
     /// Freeze the transaction so that no further modifications can be made.
     ///
     /// # Errors
@@ -386,6 +483,10 @@ impl<D: ValidateChecksums> Transaction<D> {
     ///
     /// # Errors
     /// - [`Error::FreezeUnsetNodeAccountIds`] if no [`node_account_ids`](Self::node_account_ids) were set and `client.is_none()`.
+    /// - [`Error::TransactionValidation`] if `client` has [`auto_validate_transactions`](Client::auto_validate_transactions)
+    ///   enabled and the transaction fails [`validate`](Self::validate).
+    /// - [`Error::TransactionTooLarge`] if the transaction isn't chunked and is already larger than the network accepts,
+    ///   or if no [`auto_chunk_size`](Self::auto_chunk_size) makes every chunk fit.
     ///
     /// # Panics
     /// - If `node_account_ids` is explicitly set to empty (IE: `tx.node_account_ids([]).freeze_with(None)`).
@@ -398,6 +499,10 @@ impl<D: ValidateChecksums> Transaction<D> {
         }
         let client: Option<&Client> = client.into();
 
+        if client.map_or(false, Client::auto_validate_transactions) {
+            self.validate()?;
+        }
+
         let node_account_ids = match &self.body.node_account_ids {
             // the clone here is the lesser of two evils.
             Some(it) => {
@@ -431,6 +536,10 @@ impl<D: ValidateChecksums> Transaction<D> {
         self.body.node_account_ids = Some(node_account_ids);
         self.body.max_transaction_fee = max_transaction_fee;
         self.body.operator = operator;
+
+        self.resolve_auto_chunk_size()?;
+        self.check_size()?;
+
         self.body.is_frozen = true;
 
         if let Some(client) = client {
@@ -474,6 +583,17 @@ impl<D: TransactionExecute> Transaction<D> {
     /// # Panics
     /// - If `!self.is_frozen()`
     fn make_transaction_list(&self) -> crate::Result<Vec<services::Transaction>> {
+        Ok(self.chunk_infos()?.iter().map(|it| self.make_request_inner(it).0).collect())
+    }
+
+    /// Returns the [`ChunkInfo`] of every request in `self`'s `TransactionList`, in order.
+    ///
+    /// # Errors
+    /// - If the transaction needs multiple chunks, or has no explicit transaction ID *and* `self.operator` is not set.
+    ///
+    /// # Panics
+    /// - If `!self.is_frozen()`
+    fn chunk_infos(&self) -> crate::Result<Vec<ChunkInfo>> {
         assert!(self.is_frozen());
 
         let operator = || self.body.operator.as_ref().ok_or(Error::NoPayerAccountOrTransactionId);
@@ -487,7 +607,7 @@ impl<D: TransactionExecute> Transaction<D> {
         let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
         let node_account_ids = self.body.node_account_ids.as_deref().unwrap();
 
-        let mut transaction_list = Vec::with_capacity(used_chunks * node_account_ids.len());
+        let mut chunk_infos = Vec::with_capacity(used_chunks * node_account_ids.len());
 
         // Note: This ordering is *important*,
         // there's no documentation for it but `TransactionList` is sorted by chunk number,
@@ -499,19 +619,17 @@ impl<D: TransactionExecute> Transaction<D> {
             };
 
             for node_account_id in node_account_ids.iter().copied() {
-                let chunk_info = ChunkInfo {
+                chunk_infos.push(ChunkInfo {
                     current: chunk,
                     total: used_chunks,
                     initial_transaction_id,
                     current_transaction_id,
                     node_account_id,
-                };
-
-                transaction_list.push(self.make_request_inner(&chunk_info).0);
+                });
             }
         }
 
-        Ok(transaction_list)
+        Ok(chunk_infos)
     }
 
     pub(crate) fn make_sources(&self) -> crate::Result<Cow<'_, TransactionSources>> {
@@ -566,7 +684,8 @@ impl<D: TransactionExecute> Transaction<D> {
         // this is the only check that is for correctness rather than debugability.
         assert!(sources.transactions().len() == 1);
 
-        let sources = sources.sign_with(std::slice::from_ref(signer));
+        let sources =
+            sources.sign_with(std::slice::from_ref(signer), self.body.signature_prefix_mode);
 
         // hack: I don't care about perf here.
         let ret = signer.sign(&sources.signed_transactions()[0].body_bytes);
@@ -697,16 +816,61 @@ where
         self.execute_with_optional_timeout(client, None).await
     }
 
+    /// Does everything that needs to happen before the first request of an execution is made, ending with `self` frozen.
+    pub(crate) async fn prepare_execute(&mut self, client: &Client) -> crate::Result<()> {
+        self.apply_max_transaction_fee_policy(client).await?;
+
+        // `freeze_with` validates unfrozen transactions itself.
+        if self.is_frozen() {
+            if client.auto_validate_transactions() {
+                self.validate()?;
+            }
+
+            // signatures might've been added since freezing.
+            self.check_size()?;
+        }
+
+        // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
+        self.freeze_with(Some(client))?;
+
+        Ok(())
+    }
+
+    /// Sets the max transaction fee from `client`'s [`MaxTransactionFeePolicy`](crate::MaxTransactionFeePolicy),
+    /// unless `self` is frozen or already has one.
+    async fn apply_max_transaction_fee_policy(&mut self, client: &Client) -> crate::Result<()> {
+        if self.is_frozen() || self.body.max_transaction_fee.is_some() {
+            return Ok(());
+        }
+
+        let Some(policy) = client.max_transaction_fee_policy() else {
+            return Ok(());
+        };
+
+        let estimator = client.fee_estimator().await?;
+
+        // the max transaction fee is charged per chunk, so only one chunk is estimated.
+        match estimator.estimate_chunk(self) {
+            Ok(estimate) => {
+                self.body.max_transaction_fee = Some(policy.max_transaction_fee(&estimate))
+            }
+            // no prices means no estimate, so the usual default is used.
+            Err(Error::FeeScheduleMissing(_)) => {}
+            Err(e) => return Err(e),
+        }
+
+        Ok(())
+    }
+
     pub(crate) async fn execute_with_optional_timeout(
         &mut self,
         client: &Client,
         timeout: Option<std::time::Duration>,
     ) -> crate::Result<TransactionResponse> {
-        // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
-        self.freeze_with(Some(client))?;
+        self.prepare_execute(client).await?;
 
         if let Some(sources) = self.sources() {
-            return self::execute::SourceTransaction::new(self, sources)
+            return self::execute::SourceTransaction::new(self, sources, client.lazy_signing())
                 .execute(client, timeout)
                 .await;
         }
@@ -820,12 +984,11 @@ where
         client: &Client,
         timeout_per_chunk: Option<std::time::Duration>,
     ) -> crate::Result<Vec<TransactionResponse>> {
-        // it's fine to call freeze while already frozen, so, let `freeze_with` handle the freeze check.
-        self.freeze_with(Some(client))?;
+        self.prepare_execute(client).await?;
 
         // fixme: dedup this with `execute_with_optional_timeout`
         if let Some(sources) = self.sources() {
-            return self::execute::SourceTransaction::new(self, sources)
+            return self::execute::SourceTransaction::new(self, sources, client.lazy_signing())
                 .execute_all(client, timeout_per_chunk)
                 .await;
         }
@@ -842,12 +1005,21 @@ where
     }
 }
 
-// these impls are on `AnyTransaction`, but they're here instead of in `any` because actually implementing them is only possible here.
-impl AnyTransaction {
+impl<D> Transaction<D>
+where
+    AnyTransactionData: DowncastOwned<D>,
+{
+    /// Parse a transaction from protobuf encoded bytes, as produced by [`to_bytes`](Self::to_bytes).
+    ///
+    /// Use [`AnyTransaction::from_bytes`] to parse a transaction whose type isn't known ahead of time.
+    ///
     /// # Examples
     /// ```
     /// # fn main() -> hedera::Result<()> {
-    /// use hedera::AnyTransaction;
+    /// use hedera::{
+    ///     AnyTransaction,
+    ///     TransferTransaction,
+    /// };
     /// let bytes = hex::decode(concat!(
     ///     "0a522a500a4c0a120a0c0885c8879e0610a8bdd9840312021865120218061880",
     ///     "94ebdc0322020877320c686920686173686772617068721a0a180a0a0a021802",
@@ -856,14 +1028,15 @@ impl AnyTransaction {
     ///     "20686173686772617068721a0a180a0a0a021802108088debe010a0a0a021865",
     ///     "10ff87debe011200"
     /// )).unwrap();
-    /// let tx = AnyTransaction::from_bytes(&bytes)?;
-    /// # let _ = tx;
+    /// let tx = TransferTransaction::from_bytes(&bytes)?;
+    /// let any = AnyTransaction::from_bytes(&bytes)?;
+    /// # let _ = (tx, any);
     /// # Ok(())
     /// # }
     /// ```
     /// # Errors
     /// - [`Error::FromProtobuf`] if a valid transaction cannot be parsed from the bytes.
-    #[allow(deprecated)]
+    /// - [`Error::TransactionTypeMismatch`] if the bytes are for a different kind of transaction.
     pub fn from_bytes(bytes: &[u8]) -> crate::Result<Self> {
         let list =
             hedera_proto::sdk::TransactionList::decode(bytes).map_err(Error::from_protobuf)?;
@@ -874,6 +1047,35 @@ impl AnyTransaction {
             list.transaction_list
         };
 
+        Self::from_transaction_list(list)
+    }
+
+    /// Parse a transaction from a single protobuf encoded `SignedTransaction`.
+    ///
+    /// # Errors
+    /// - [`Error::FromProtobuf`] if a valid transaction cannot be parsed from the bytes.
+    /// - [`Error::TransactionTypeMismatch`] if the bytes are for a different kind of transaction.
+    pub fn from_signed_transaction_bytes(bytes: &[u8]) -> crate::Result<Self> {
+        Self::from_transaction_list(Vec::from([services::Transaction {
+            signed_transaction_bytes: bytes.to_vec(),
+            ..services::Transaction::default()
+        }]))
+    }
+
+    pub(super) fn from_transaction_list(list: Vec<services::Transaction>) -> crate::Result<Self> {
+        let (transaction, request_type) = AnyTransaction::decode_transaction_list(list)?;
+
+        transaction.downcast().map_err(|_| Error::TransactionTypeMismatch(request_type))
+    }
+}
+
+// these impls are on `AnyTransaction`, but they're here instead of in `any` because actually implementing them is only possible here.
+impl AnyTransaction {
+    /// Returns the transaction, along with the kind of transaction it is.
+    #[allow(deprecated)]
+    fn decode_transaction_list(
+        list: Vec<services::Transaction>,
+    ) -> crate::Result<(Self, RequestType)> {
         let sources = TransactionSources::new(list)?;
 
         let transaction_bodies: Result<Vec<_>, _> = sources
@@ -897,6 +1099,37 @@ impl AnyTransaction {
             }
         }
 
+        // `pb_transaction_body_eq` can only compare the fields this SDK knows about,
+        // so compare the rest as they were encoded.
+        {
+            let mut first_unknown_fields = None;
+
+            for chunk in sources.chunks() {
+                let bodies: Result<Vec<_>, _> = chunk
+                    .signed_transactions()
+                    .iter()
+                    .map(|it| strip_fields(&it.body_bytes, &[TRANSACTION_BODY_NODE_ACCOUNT_ID_TAG]))
+                    .collect();
+
+                let bodies = bodies?;
+
+                // bodies for the same chunk should only differ by node.
+                if bodies.iter().any(|it| it != &bodies[0]) {
+                    return Err(Error::from_protobuf("transaction parts unexpectedly unequal"));
+                }
+
+                let unknown_fields = unknown_fields::<services::TransactionBody>(
+                    &chunk.signed_transactions()[0].body_bytes,
+                )?;
+
+                if *first_unknown_fields.get_or_insert_with(|| unknown_fields.clone())
+                    != unknown_fields
+                {
+                    return Err(Error::from_protobuf("transaction parts unexpectedly unequal"));
+                }
+            }
+        }
+
         // todo: reuse work
         let transaction_data = {
             let data: Result<_, _> = sources
@@ -912,6 +1145,8 @@ impl AnyTransaction {
             data?
         };
 
+        let request_type = request_type(&transaction_data[0]);
+
         // note: this creates the transaction in a frozen state.
         let mut res = Self::from_protobuf(transaction_bodies[0].clone(), transaction_data)?;
 
@@ -919,11 +1154,15 @@ impl AnyTransaction {
         res.body.node_account_ids = Some(sources.node_ids().to_vec());
         res.sources = Some(sources);
 
-        Ok(res)
+        Ok((res, request_type))
     }
 }
 
+const TRANSACTION_BODY_NODE_ACCOUNT_ID_TAG: u32 = 2;
+
 /// Returns `true` if lhs == rhs other than `transaction_id` and `node_account_id`, `false` otherwise.
+///
+/// Note: fields this SDK doesn't know about aren't compared.
 #[allow(deprecated)]
 fn pb_transaction_body_eq(
     lhs: &services::TransactionBody,
@@ -1029,6 +1268,7 @@ where
             operator,
             is_frozen,
             regenerate_transaction_id,
+            signature_prefix_mode,
         } = body;
 
         // not a `map().map_err()` because ownership.
@@ -1044,6 +1284,7 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    signature_prefix_mode,
                 },
                 signers,
                 sources,
@@ -1060,6 +1301,7 @@ where
                     operator,
                     is_frozen,
                     regenerate_transaction_id,
+                    signature_prefix_mode,
                 },
                 signers,
                 sources,
@@ -0,0 +1,410 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use async_stream::stream;
+use futures_core::Stream;
+
+use super::chunked::{
+    ChunkView,
+    FirstChunkView,
+};
+use super::execute::SourceTransaction;
+use super::{
+    ChunkData,
+    TransactionExecuteChunked,
+};
+use crate::execute::execute;
+use crate::{
+    Client,
+    Error,
+    Transaction,
+    TransactionId,
+    TransactionReceipt,
+    TransactionReceiptQuery,
+    TransactionResponse,
+};
+
+/// How far the execution of a chunked transaction has gotten.
+///
+/// Returned in [`Error::ChunkedExecution`] so that [`Transaction::resume_chunks`] can continue
+/// from the first unfinished chunk, without resubmitting the chunks before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct ChunkProgress {
+    total_chunks: usize,
+    next_chunk: usize,
+    initial_transaction_id: Option<TransactionId>,
+    pending_transaction_id: Option<TransactionId>,
+}
+
+impl ChunkProgress {
+    fn new(total_chunks: usize) -> Self {
+        Self {
+            total_chunks,
+            next_chunk: 0,
+            initial_transaction_id: None,
+            pending_transaction_id: None,
+        }
+    }
+
+    /// Returns the number of chunks in the transaction.
+    #[must_use]
+    pub fn total_chunks(&self) -> usize {
+        self.total_chunks
+    }
+
+    /// Returns the index of the first chunk that hasn't finished, every chunk before it has a successful receipt.
+    #[must_use]
+    pub fn next_chunk(&self) -> usize {
+        self.next_chunk
+    }
+
+    /// Returns `true` if every chunk has finished.
+    #[must_use]
+    pub fn is_complete(&self) -> bool {
+        self.next_chunk >= self.total_chunks
+    }
+
+    /// Returns the ID of the first chunk's transaction, once it has been submitted.
+    #[must_use]
+    pub fn initial_transaction_id(&self) -> Option<TransactionId> {
+        self.initial_transaction_id
+    }
+
+    /// Returns the ID of the transaction that [`next_chunk`](Self::next_chunk) was submitted with, if its receipt wasn't received.
+    ///
+    /// When resuming, this receipt is fetched first, and the chunk is only submitted again
+    /// if the network has no receipt for it.
+    /// If the chunk reached consensus but failed, resuming fails with that [`Error::ReceiptStatus`].
+    #[must_use]
+    pub fn pending_transaction_id(&self) -> Option<TransactionId> {
+        self.pending_transaction_id
+    }
+
+    /// Forgets the [`pending_transaction_id`](Self::pending_transaction_id), so that the chunk is submitted again.
+    ///
+    /// Receipts are only kept by the network for a few minutes,
+    /// so this is needed to resume long after a failure, but should only be done when the pending transaction
+    /// is known to have not reached consensus (for instance, by checking a mirror node),
+    /// otherwise the chunk might be applied twice.
+    pub fn resubmit_pending(&mut self) -> &mut Self {
+        self.pending_transaction_id = None;
+        self
+    }
+
+    fn finish_chunk(&mut self) {
+        self.next_chunk += 1;
+        self.pending_transaction_id = None;
+    }
+
+    fn fail(&self, error: Error) -> Error {
+        Error::ChunkedExecution { source: Box::new(error), progress: Box::new(self.clone()) }
+    }
+}
+
+/// The receipt of a single chunk, from [`Transaction::execute_chunks`].
+#[derive(Debug, Clone)]
+pub struct ChunkReceipt {
+    /// The index of the chunk.
+    pub chunk: usize,
+
+    /// The number of chunks in the transaction.
+    pub total_chunks: usize,
+
+    /// The ID of the transaction the chunk was submitted with.
+    pub transaction_id: TransactionId,
+
+    /// The chunk's receipt.
+    pub receipt: TransactionReceipt,
+
+    progress: ChunkProgress,
+}
+
+impl ChunkReceipt {
+    /// Returns the progress of the transaction right after this chunk finished.
+    #[must_use]
+    pub fn progress(&self) -> &ChunkProgress {
+        &self.progress
+    }
+}
+
+impl<D> Transaction<D>
+where
+    D: TransactionExecuteChunked,
+{
+    /// Execute every chunk of this transaction, one at a time, yielding each chunk's receipt as soon as it's received.
+    ///
+    /// The stream ends after the last chunk or the first error.
+    /// A chunk only counts as finished once its receipt is received, so failures to get a receipt are errors too.
+    ///
+    /// Errors from submitting (or getting the receipt of) a chunk are [`Error::ChunkedExecution`],
+    /// which can be resumed with [`resume_chunks`](Self::resume_chunks),
+    /// any error before the first chunk is submitted is returned as is.
+    pub fn execute_chunks<'a>(
+        &'a mut self,
+        client: &'a Client,
+    ) -> impl Stream<Item = crate::Result<ChunkReceipt>> + 'a {
+        self.chunk_stream(client, None)
+    }
+
+    /// Continue executing this transaction from `progress`, see [`execute_chunks`](Self::execute_chunks).
+    ///
+    /// `self` must have the same chunks as the transaction `progress` came from,
+    /// either the same transaction or one built (or parsed) from the same contents with the same chunk size.
+    ///
+    /// If `self` has a different number of chunks than `progress`, the stream yields an [`Error::ChunkedExecution`]
+    /// caused by [`Error::ChunkCountMismatch`] (with `progress` unchanged) and ends.
+    pub fn resume_chunks<'a>(
+        &'a mut self,
+        client: &'a Client,
+        progress: ChunkProgress,
+    ) -> impl Stream<Item = crate::Result<ChunkReceipt>> + 'a {
+        self.chunk_stream(client, Some(progress))
+    }
+
+    fn chunk_stream<'a>(
+        &'a mut self,
+        client: &'a Client,
+        progress: Option<ChunkProgress>,
+    ) -> impl Stream<Item = crate::Result<ChunkReceipt>> + 'a {
+        stream! {
+            if let Err(e) = self.prepare_execute(client).await {
+                yield Err(e);
+                return;
+            }
+
+            let transaction = &*self;
+
+            let sources = transaction
+                .sources()
+                .map(|sources| SourceTransaction::new(transaction, sources, client.lazy_signing()));
+
+            let total_chunks = match &sources {
+                Some(sources) => sources.chunks_len(),
+                None => transaction.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks),
+            };
+
+            let mut progress = match progress {
+                Some(progress) if progress.total_chunks != total_chunks => {
+                    let expected = progress.total_chunks;
+
+                    let error = Error::ChunkCountMismatch { expected, actual: total_chunks };
+
+                    yield Err(progress.fail(error));
+                    return;
+                }
+                Some(progress) => progress,
+                None => ChunkProgress::new(total_chunks),
+            };
+
+            if let Some(pending) = progress.pending_transaction_id {
+                match pending_receipt(client, pending).await {
+                    Ok(Some(receipt)) => {
+                        let chunk = progress.next_chunk;
+                        progress.finish_chunk();
+
+                        yield Ok(ChunkReceipt {
+                            chunk,
+                            total_chunks,
+                            transaction_id: pending,
+                            receipt,
+                            progress: progress.clone(),
+                        });
+                    }
+
+                    Ok(None) => progress.pending_transaction_id = None,
+
+                    Err(e) => {
+                        yield Err(progress.fail(e));
+                        return;
+                    }
+                }
+            }
+
+            while !progress.is_complete() {
+                let chunk = progress.next_chunk;
+
+                let response = match &sources {
+                    Some(sources) => sources.execute_chunk(client, chunk, None).await,
+                    None => transaction.execute_chunk(client, chunk, total_chunks, &progress).await,
+                };
+
+                let response = match response {
+                    Ok(response) => response,
+                    Err(e) => {
+                        yield Err(progress.fail(e));
+                        return;
+                    }
+                };
+
+                if chunk == 0 {
+                    progress.initial_transaction_id = Some(response.transaction_id);
+                }
+
+                progress.pending_transaction_id = Some(response.transaction_id);
+
+                let receipt = match response.get_receipt_query().execute(client).await {
+                    Ok(receipt) => receipt,
+                    Err(e) => {
+                        yield Err(progress.fail(e));
+                        return;
+                    }
+                };
+
+                progress.finish_chunk();
+
+                yield Ok(ChunkReceipt {
+                    chunk,
+                    total_chunks,
+                    transaction_id: response.transaction_id,
+                    receipt,
+                    progress: progress.clone(),
+                });
+            }
+        }
+    }
+
+    async fn execute_chunk(
+        &self,
+        client: &Client,
+        chunk: usize,
+        total_chunks: usize,
+        progress: &ChunkProgress,
+    ) -> crate::Result<TransactionResponse> {
+        match progress.initial_transaction_id {
+            Some(initial_transaction_id) if chunk > 0 => {
+                execute(
+                    client,
+                    &ChunkView {
+                        transaction: self,
+                        initial_transaction_id,
+                        current_chunk: chunk,
+                        total_chunks,
+                    },
+                    None,
+                )
+                .await
+            }
+
+            _ => execute(client, &FirstChunkView { transaction: self, total_chunks }, None).await,
+        }
+    }
+}
+
+/// Returns the receipt of a chunk that was submitted as `transaction_id`, or `None` if the network has no receipt for it.
+///
+/// A chunk that reached consensus and failed is an error: submitting it again would only be rejected as a duplicate,
+/// hiding the actual failure.
+async fn pending_receipt(
+    client: &Client,
+    transaction_id: TransactionId,
+) -> crate::Result<Option<TransactionReceipt>> {
+    let receipt = TransactionReceiptQuery::new()
+        .transaction_id(transaction_id)
+        .validate_status(true)
+        .execute(client)
+        .await;
+
+    match receipt {
+        Ok(receipt) => Ok(Some(receipt)),
+        Err(e) if e.is_receipt_not_found() => Ok(None),
+        Err(e) => Err(e),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+    use futures_util::StreamExt;
+
+    use super::ChunkProgress;
+    use crate::{
+        Client,
+        Error,
+        PrivateKey,
+        TopicMessageSubmitTransaction,
+        TransactionId,
+    };
+
+    #[test]
+    fn progress() {
+        let mut progress = ChunkProgress::new(3);
+
+        assert_eq!(progress.next_chunk(), 0);
+        assert!(!progress.is_complete());
+
+        progress.initial_transaction_id = Some(TransactionId::generate(5006.into()));
+        progress.pending_transaction_id = progress.initial_transaction_id;
+        progress.finish_chunk();
+
+        assert_eq!(progress.next_chunk(), 1);
+        assert_eq!(progress.pending_transaction_id(), None);
+
+        progress.finish_chunk();
+        progress.finish_chunk();
+
+        assert!(progress.is_complete());
+    }
+
+    #[test]
+    fn fail() {
+        let mut progress = ChunkProgress::new(3);
+        progress.finish_chunk();
+
+        let error = progress.fail(Error::NoPayerAccountOrTransactionId);
+
+        assert_eq!(
+            error.to_string(),
+            format!("chunk 2 of 3 failed: {}", Error::NoPayerAccountOrTransactionId)
+        );
+
+        assert_matches!(error, Error::ChunkedExecution { progress: it, .. } if *it == progress);
+    }
+
+    #[tokio::test]
+    async fn resume_with_different_chunks() {
+        let client = Client::for_testnet();
+        client.set_operator(101.into(), PrivateKey::generate_ed25519());
+
+        let mut tx = TopicMessageSubmitTransaction::new();
+
+        tx.topic_id(314)
+            .message(vec![0xab; 5000])
+            .transaction_id(TransactionId::generate(101.into()))
+            .node_account_ids([6.into()])
+            .freeze_with(&client)
+            .unwrap();
+
+        let progress = ChunkProgress::new(3);
+
+        let stream = tx.resume_chunks(&client, progress.clone());
+        futures_util::pin_mut!(stream);
+
+        assert_matches!(
+            stream.next().await,
+            Some(Err(Error::ChunkedExecution { source, progress: it }))
+                if matches!(*source, Error::ChunkCountMismatch { expected: 3, actual: 5 })
+                    && *it == progress
+        );
+
+        assert!(stream.next().await.is_none());
+    }
+}
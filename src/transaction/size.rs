@@ -0,0 +1,193 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::num::NonZeroUsize;
+
+use hedera_proto::services;
+use prost::Message;
+
+use super::protobuf::ToTransactionDataProtobuf;
+use super::{
+    ChunkData,
+    TransactionData,
+};
+use crate::{
+    Error,
+    Transaction,
+};
+
+/// The largest transaction the network accepts, in bytes.
+pub(crate) const MAX_TRANSACTION_SIZE: usize = 6144;
+
+/// How many more bytes a later chunk's transaction ID can take than the one used to size it.
+///
+/// Those IDs are generated when the chunk is submitted, and the nanoseconds of a timestamp are a varint of 1 to 5 bytes.
+const CHUNK_TRANSACTION_ID_SLACK: usize = 4;
+
+/// The smallest chunk size [`auto_chunk_size`](Transaction::auto_chunk_size) picks.
+///
+/// Below this a transaction is mostly signatures, and it's better to fail than to submit a huge number of chunks.
+const MIN_AUTO_CHUNK_SIZE: usize = 256;
+
+fn transaction_size(signed_transaction: &services::SignedTransaction) -> usize {
+    services::Transaction {
+        signed_transaction_bytes: signed_transaction.encode_to_vec(),
+        ..services::Transaction::default()
+    }
+    .encoded_len()
+}
+
+impl<D> Transaction<D>
+where
+    D: TransactionData + ToTransactionDataProtobuf,
+{
+    /// Returns the size, in bytes, of this transaction as it would be submitted to a node, with the signatures it has now.
+    ///
+    /// For chunked transactions this is the size of the first chunk, see [`size_bytes_per_chunk`](Self::size_bytes_per_chunk).
+    ///
+    /// If the transaction isn't frozen, anything [`freeze_with`](Self::freeze_with) would fill in is assumed to be of a typical size,
+    /// and if there's no operator, the payer is assumed to sign with a single ed25519 key.
+    ///
+    /// # Errors
+    /// - [`Error::ChunkIndexOutOfRange`] if the transaction has no chunks.
+    pub fn size_bytes(&self) -> crate::Result<usize> {
+        self.projected_size_bytes(0)
+    }
+
+    /// Returns the size, in bytes, of each chunk of this transaction as it would be submitted to a node, with the signatures it has now.
+    ///
+    /// See [`size_bytes`](Self::size_bytes) for the assumptions made about transactions that aren't frozen.
+    ///
+    /// # Errors
+    /// - [`Error::ChunkIndexOutOfRange`] if the transaction has fewer chunks than it reports.
+    pub fn size_bytes_per_chunk(&self) -> crate::Result<Vec<usize>> {
+        self.projected_size_bytes_per_chunk(0)
+    }
+
+    /// Returns the size, in bytes, that this transaction will have once it's signed by `expected_signers` signers in total.
+    ///
+    /// Signers that haven't signed yet are assumed to have the largest kind of key,
+    /// so this is an upper bound as long as no more than `expected_signers` sign the transaction.
+    ///
+    /// For chunked transactions this is the size of the first chunk,
+    /// see [`projected_size_bytes_per_chunk`](Self::projected_size_bytes_per_chunk).
+    ///
+    /// # Errors
+    /// - [`Error::ChunkIndexOutOfRange`] if the transaction has no chunks.
+    pub fn projected_size_bytes(&self, expected_signers: usize) -> crate::Result<usize> {
+        Ok(transaction_size(&self.project_signed_transaction(0, expected_signers)?))
+    }
+
+    /// Returns the size, in bytes, that each chunk of this transaction will have once it's signed by `expected_signers` signers in total.
+    ///
+    /// See [`projected_size_bytes`](Self::projected_size_bytes).
+    ///
+    /// # Errors
+    /// - [`Error::ChunkIndexOutOfRange`] if the transaction has fewer chunks than it reports.
+    pub fn projected_size_bytes_per_chunk(
+        &self,
+        expected_signers: usize,
+    ) -> crate::Result<Vec<usize>> {
+        let used_chunks = match self.sources() {
+            Some(sources) => sources.chunks_len(),
+            None => self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks),
+        };
+
+        (0..used_chunks)
+            .map(|chunk| {
+                Ok(transaction_size(&self.project_signed_transaction(chunk, expected_signers)?))
+            })
+            .collect()
+    }
+
+    /// Errors if `self` isn't chunked and is already too large for the network to accept.
+    pub(crate) fn check_size(&self) -> crate::Result<()> {
+        if self.data().maybe_chunk_data().is_some() {
+            return Ok(());
+        }
+
+        match self.size_bytes()? {
+            size if size > MAX_TRANSACTION_SIZE => {
+                Err(Error::TransactionTooLarge { size, limit: MAX_TRANSACTION_SIZE })
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Picks the largest chunk size where every chunk fits in a transaction, if [`auto_chunk_size`](Self::auto_chunk_size) was requested.
+    ///
+    /// Errors with [`Error::TransactionTooLarge`] if that chunk size would be smaller than [`MIN_AUTO_CHUNK_SIZE`],
+    /// or would need more than [`max_chunks`](Self::max_chunks) chunks.
+    pub(super) fn resolve_auto_chunk_size(&mut self) -> crate::Result<()> {
+        let Some((expected_signers, data_len, max_chunks)) = self
+            .data()
+            .maybe_chunk_data()
+            .and_then(|it| Some((it.auto_chunk_size_signers?, it.data.len(), it.max_chunks)))
+        else {
+            return Ok(());
+        };
+
+        let mut chunk_size = data_len.clamp(1, MAX_TRANSACTION_SIZE);
+
+        loop {
+            let size = self.set_chunk_size_and_measure(chunk_size, expected_signers)?;
+
+            if size <= MAX_TRANSACTION_SIZE {
+                break;
+            }
+
+            // every byte removed from a chunk removes at least one byte from the transaction.
+            match chunk_size.checked_sub(size - MAX_TRANSACTION_SIZE) {
+                Some(it) if it >= MIN_AUTO_CHUNK_SIZE => chunk_size = it,
+                _ if chunk_size > MIN_AUTO_CHUNK_SIZE => chunk_size = MIN_AUTO_CHUNK_SIZE,
+                _ => return Err(Error::TransactionTooLarge { size, limit: MAX_TRANSACTION_SIZE }),
+            }
+        }
+
+        let used_chunks = self.data().maybe_chunk_data().map_or(1, ChunkData::used_chunks);
+
+        if used_chunks > max_chunks {
+            // report the size the chunks would need to be to fit in `max_chunks`.
+            let max_chunks = max_chunks.max(1);
+            let chunk_size = ((data_len + max_chunks - 1) / max_chunks).max(1);
+            let size = self.set_chunk_size_and_measure(chunk_size, expected_signers)?;
+
+            return Err(Error::TransactionTooLarge { size, limit: MAX_TRANSACTION_SIZE });
+        }
+
+        Ok(())
+    }
+
+    /// Sets the chunk size to `chunk_size` and returns the size of the largest chunk once signed by `expected_signers` signers.
+    fn set_chunk_size_and_measure(
+        &mut self,
+        chunk_size: usize,
+        expected_signers: usize,
+    ) -> crate::Result<usize> {
+        // `chunk_size` is never zero.
+        self.body.data.maybe_chunk_data_mut().unwrap().chunk_size =
+            NonZeroUsize::new(chunk_size).unwrap();
+
+        let largest =
+            self.projected_size_bytes_per_chunk(expected_signers)?.into_iter().max().unwrap_or(0);
+
+        Ok(largest + CHUNK_TRANSACTION_ID_SLACK)
+    }
+}
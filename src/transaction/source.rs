@@ -25,8 +25,13 @@ use hedera_proto::services;
 use once_cell::sync::OnceCell;
 use prost::Message;
 
-use crate::protobuf::FromProtobuf;
+use crate::key::has_valid_signature;
+use crate::protobuf::{
+    strip_fields,
+    FromProtobuf,
+};
 use crate::signer::AnySigner;
+use crate::transaction::SignaturePrefixMode;
 use crate::{
     AccountId,
     Error,
@@ -66,12 +71,30 @@ impl<'a> SourceChunk<'a> {
     pub(crate) fn transaction_hashes(&self) -> &'a [TransactionHash] {
         &self.map.transaction_hashes()[self.range()]
     }
+
+    /// See [`TransactionSources::signed_request`], `index` is relative to this chunk.
+    pub(crate) fn signed_request(
+        &self,
+        index: usize,
+        signers: &[AnySigner],
+        prefix_mode: SignaturePrefixMode,
+    ) -> services::Transaction {
+        self.map.signed_request(self.range().start + index, signers, prefix_mode)
+    }
 }
 
+const SIGNED_TRANSACTION_BODY_BYTES_TAG: u32 = 1;
+const SIGNED_TRANSACTION_SIG_MAP_TAG: u32 = 2;
+
 #[derive(Default, Clone)]
 pub struct TransactionSources {
     signed_transactions: Box<[services::SignedTransaction]>,
 
+    /// The fields of each signed transaction that aren't `body_bytes` or `sig_map`, exactly as they were encoded.
+    ///
+    /// These are kept so that re-encoding a signed transaction (IE, after adding a signature) doesn't drop fields from newer protobufs.
+    extra_fields: Box<[Vec<u8>]>,
+
     transactions: OnceCell<Vec<services::Transaction>>,
 
     /// offset of each chunk into `transactions`/`signed_transactions`
@@ -109,6 +132,18 @@ impl TransactionSources {
 
         let signed_transactions = signed_transactions?;
 
+        let extra_fields: Result<Vec<_>, _> = transactions
+            .iter()
+            .map(|it| {
+                strip_fields(
+                    &it.signed_transaction_bytes,
+                    &[SIGNED_TRANSACTION_BODY_BYTES_TAG, SIGNED_TRANSACTION_SIG_MAP_TAG],
+                )
+            })
+            .collect();
+
+        let extra_fields = extra_fields?;
+
         // ensure all signers (if any) are consistent for all signed transactions.
         // this doesn't compare or validate the signatures,
         // instead it ensures that all signatures in the first signed transation exist in *all* transactions and none extra exist.
@@ -219,6 +254,7 @@ impl TransactionSources {
 
         Ok(Self {
             signed_transactions: signed_transactions.into_boxed_slice(),
+            extra_fields: extra_fields.into_boxed_slice(),
             transactions: OnceCell::with_value(transactions),
             chunks,
             transaction_ids,
@@ -227,7 +263,11 @@ impl TransactionSources {
         })
     }
 
-    pub(crate) fn sign_with(&self, signers: &[AnySigner]) -> Cow<'_, Self> {
+    pub(crate) fn sign_with(
+        &self,
+        signers: &[AnySigner],
+        prefix_mode: SignaturePrefixMode,
+    ) -> Cow<'_, Self> {
         if signers.is_empty() {
             return Cow::Borrowed(self);
         }
@@ -235,15 +275,15 @@ impl TransactionSources {
         let mut signed_transactions = Cow::Borrowed(&self.signed_transactions);
 
         for signer in signers {
-            let pk = signer.public_key().to_bytes_raw();
-
             // we need the first signed transaction for its signature list so that we know if we need to skip a given signer.
-            if signed_transactions
-                .first()
-                .as_ref()
-                .and_then(|it| it.sig_map.as_ref())
-                .map_or(false, |it| it.sig_pair.iter().any(|it| pk.starts_with(&it.pub_key_prefix)))
-            {
+            // prefixes may be shortened, so a matching prefix alone doesn't mean that `signer` has already signed.
+            if signed_transactions.first().map_or(false, |it| {
+                has_valid_signature(
+                    &signer.public_key(),
+                    &it.body_bytes,
+                    it.sig_map.as_ref().map_or(&[], |it| &it.sig_pair),
+                )
+            }) {
                 continue;
             }
 
@@ -259,14 +299,70 @@ impl TransactionSources {
         match signed_transactions {
             // if it's still borrowed then no signatures have been added (all signers are duplicates).
             Cow::Borrowed(_) => Cow::Borrowed(self),
-            Cow::Owned(signed_transactions) => Cow::Owned(Self {
-                signed_transactions,
-                transactions: OnceCell::new(),
-                chunks: self.chunks.clone(),
-                transaction_ids: self.transaction_ids.clone(),
-                node_ids: self.node_ids.clone(),
-                transaction_hashes: self.transaction_hashes.clone(),
-            }),
+            Cow::Owned(mut signed_transactions) => {
+                for sig_map in signed_transactions.iter_mut().filter_map(|it| it.sig_map.as_mut()) {
+                    prefix_mode.apply(&mut sig_map.sig_pair);
+                }
+
+                Cow::Owned(Self {
+                    signed_transactions,
+                    extra_fields: self.extra_fields.clone(),
+                    transactions: OnceCell::new(),
+                    chunks: self.chunks.clone(),
+                    transaction_ids: self.transaction_ids.clone(),
+                    node_ids: self.node_ids.clone(),
+                    transaction_hashes: self.transaction_hashes.clone(),
+                })
+            }
+        }
+    }
+
+    /// Returns the request for the transaction at `index`, signed by each of `signers` that hasn't signed it yet.
+    ///
+    /// Unlike [`sign_with`](Self::sign_with) this only signs a single transaction.
+    pub(crate) fn signed_request(
+        &self,
+        index: usize,
+        signers: &[AnySigner],
+        prefix_mode: SignaturePrefixMode,
+    ) -> services::Transaction {
+        let mut signed_transaction = Cow::Borrowed(&self.signed_transactions[index]);
+
+        for signer in signers {
+            let public_key = signer.public_key();
+
+            if has_valid_signature(
+                &public_key,
+                &signed_transaction.body_bytes,
+                signed_transaction.sig_map.as_ref().map_or(&[], |it| &it.sig_pair),
+            ) {
+                continue;
+            }
+
+            let signed_transaction = signed_transaction.to_mut();
+            let sig_pair =
+                super::execute::SignaturePair::from(signer.sign(&signed_transaction.body_bytes));
+
+            signed_transaction
+                .sig_map
+                .get_or_insert_with(services::SignatureMap::default)
+                .sig_pair
+                .push(sig_pair.into_protobuf());
+        }
+
+        match signed_transaction {
+            Cow::Borrowed(_) => self.transactions()[index].clone(),
+            Cow::Owned(mut signed_transaction) => {
+                if let Some(sig_map) = &mut signed_transaction.sig_map {
+                    prefix_mode.apply(&mut sig_map.sig_pair);
+                }
+
+                services::Transaction {
+                    signed_transaction_bytes: self
+                        .encode_signed_transaction(index, &signed_transaction),
+                    ..Default::default()
+                }
+            }
         }
     }
 
@@ -274,14 +370,27 @@ impl TransactionSources {
         self.transactions.get_or_init(|| {
             self.signed_transactions
                 .iter()
-                .map(|it| services::Transaction {
-                    signed_transaction_bytes: it.encode_to_vec(),
+                .enumerate()
+                .map(|(index, it)| services::Transaction {
+                    signed_transaction_bytes: self.encode_signed_transaction(index, it),
                     ..Default::default()
                 })
                 .collect()
         })
     }
 
+    /// Encodes `signed_transaction` as the one at `index`, keeping any fields it had that this SDK doesn't know about.
+    fn encode_signed_transaction(
+        &self,
+        index: usize,
+        signed_transaction: &services::SignedTransaction,
+    ) -> Vec<u8> {
+        let mut bytes = signed_transaction.encode_to_vec();
+        bytes.extend_from_slice(&self.extra_fields[index]);
+
+        bytes
+    }
+
     pub(crate) fn signed_transactions(&self) -> &[services::SignedTransaction] {
         &self.signed_transactions
     }
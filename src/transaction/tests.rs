@@ -1,15 +1,29 @@
 use std::collections::HashSet;
+use std::sync::atomic::{
+    AtomicUsize,
+    Ordering,
+};
 
 use assert_matches::assert_matches;
+use hedera_proto::services;
 use hex_literal::hex;
+use prost::Message;
 use time::OffsetDateTime;
 
-use crate::transaction::AnyTransactionData;
+use crate::transaction::{
+    AnyTransactionData,
+    SignaturePrefixMode,
+};
 use crate::{
+    AccountId,
     AnyTransaction,
     Client,
+    Error,
+    FileAppendTransaction,
+    FileId,
     Hbar,
     PrivateKey,
+    RequestType,
     TopicMessageSubmitTransaction,
     TransactionId,
     TransferTransaction,
@@ -119,3 +133,455 @@ async fn chunked_to_from_bytes() -> crate::Result<()> {
 
     Ok(())
 }
+
+fn sig_pair(pub_key_prefix: &[u8]) -> services::SignaturePair {
+    services::SignaturePair { pub_key_prefix: pub_key_prefix.to_vec(), signature: None }
+}
+
+/// Returns a 32 byte key that starts with `prefix`.
+fn key_with_prefix(prefix: &[u8]) -> Vec<u8> {
+    let mut key = prefix.to_vec();
+    key.resize(32, 0xff);
+    key
+}
+
+#[test]
+fn signature_prefix_mode_shortest() {
+    let mut pairs = [
+        sig_pair(&key_with_prefix(&[0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0x01])),
+        sig_pair(&key_with_prefix(&[0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0x02])),
+        sig_pair(&key_with_prefix(&[0xcd, 0x01])),
+    ];
+
+    SignaturePrefixMode::Shortest.apply(&mut pairs);
+
+    let prefixes: Vec<_> = pairs.iter().map(|it| it.pub_key_prefix.as_slice()).collect();
+    assert_eq!(
+        prefixes,
+        [
+            &[0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0x01][..],
+            &[0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0xab, 0x02],
+            &[0xcd, 0x01, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff]
+        ]
+    );
+}
+
+#[test]
+fn signature_prefix_mode_single_signer() {
+    let mut pairs =
+        [sig_pair(&hex!("e0c8ec2758a5879ffac226a13c0c516b799e72e35141a0dd828f94d37988a4b7"))];
+
+    SignaturePrefixMode::Shortest.apply(&mut pairs);
+
+    assert_eq!(pairs[0].pub_key_prefix, hex!("e0c8ec2758a5879f"));
+}
+
+#[test]
+fn signature_prefix_mode_full_key() {
+    let mut pairs = [sig_pair(&[0xab, 0x01, 0x02]), sig_pair(&[0xab, 0x02, 0x03])];
+
+    SignaturePrefixMode::FullKey.apply(&mut pairs);
+
+    assert_eq!(pairs[0].pub_key_prefix, [0xab, 0x01, 0x02]);
+    assert_eq!(pairs[1].pub_key_prefix, [0xab, 0x02, 0x03]);
+}
+
+#[test]
+fn signature_prefix_mode_signatures_verify() -> crate::Result<()> {
+    let key1 = PrivateKey::generate_ed25519();
+    let key2 = PrivateKey::generate_ed25519();
+
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .sign(key1.clone())
+        .sign(key2.clone());
+
+    let bytes = tx.to_bytes()?;
+
+    let mut tx2 = AnyTransaction::from_bytes(&bytes)?;
+
+    key1.public_key().verify_transaction(&mut tx2)?;
+    key2.public_key().verify_transaction(&mut tx2)?;
+
+    Ok(())
+}
+
+#[test]
+fn signature_prefix_mode_non_chunked() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+
+    let mut tx = frozen_transfer()?;
+    tx.signature_prefix_mode(SignaturePrefixMode::FullKey).sign(key.clone());
+
+    assert_eq!(tx.get_signature_prefix_mode(), SignaturePrefixMode::FullKey);
+
+    let list = hedera_proto::sdk::TransactionList::decode(tx.to_bytes()?.as_slice()).unwrap();
+
+    for transaction in list.transaction_list {
+        let signed =
+            services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).unwrap();
+
+        assert_eq!(
+            signed.sig_map.unwrap().sig_pair[0].pub_key_prefix,
+            key.public_key().to_bytes_raw()
+        );
+    }
+
+    Ok(())
+}
+
+#[test]
+fn co_sign_from_bytes() -> crate::Result<()> {
+    // the public keys of `key1` and `key3` both start with `0xce`, `key2`'s starts with `0x6b`.
+    let key1 = PrivateKey::from_bytes_ed25519(&hex!(
+        "0100000000000000000000000000000000000000000000000000000000000000"
+    ))?;
+    let key2 = PrivateKey::from_bytes_ed25519(&hex!(
+        "0200000000000000000000000000000000000000000000000000000000000000"
+    ))?;
+    let key3 = PrivateKey::from_bytes_ed25519(&hex!(
+        "7f00000000000000000000000000000000000000000000000000000000000000"
+    ))?;
+
+    let bytes = frozen_transfer()?.sign(key1.clone()).sign(key2.clone()).to_bytes()?;
+
+    // `key3` signs later, after the prefixes of `key1` and `key2` have been written.
+    let bytes = AnyTransaction::from_bytes(&bytes)?.sign(key3.clone()).to_bytes()?;
+
+    let list = hedera_proto::sdk::TransactionList::decode(bytes.as_slice()).unwrap();
+
+    for transaction in list.transaction_list {
+        let signed =
+            services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).unwrap();
+
+        let prefixes: Vec<_> =
+            signed.sig_map.unwrap().sig_pair.into_iter().map(|it| it.pub_key_prefix).collect();
+
+        assert_eq!(prefixes.len(), 3);
+
+        // the network rejects signature maps where one prefix is a prefix of another.
+        for (index, prefix) in prefixes.iter().enumerate() {
+            for (other_index, other) in prefixes.iter().enumerate() {
+                assert!(index == other_index || !other.starts_with(prefix));
+            }
+        }
+    }
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+
+    key1.public_key().verify_transaction(&mut tx)?;
+    key2.public_key().verify_transaction(&mut tx)?;
+    key3.public_key().verify_transaction(&mut tx)?;
+
+    Ok(())
+}
+
+#[test]
+fn size_bytes_matches_to_bytes() -> crate::Result<()> {
+    let mut tx = TransferTransaction::new();
+
+    let bytes = tx
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into()])
+        .freeze()?
+        .sign(PrivateKey::generate_ed25519())
+        .to_bytes()?;
+
+    let size =
+        hedera_proto::sdk::TransactionList::decode(bytes.as_slice()).unwrap().transaction_list[0]
+            .encoded_len();
+
+    let tx = AnyTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(tx.size_bytes()?, size);
+    assert_eq!(tx.size_bytes_per_chunk()?, [size]);
+    assert_eq!(tx.projected_size_bytes(1)?, size);
+    assert!(tx.projected_size_bytes(3)? > size);
+    assert_matches!(
+        tx.project_signed_transaction(1, 0),
+        Err(Error::ChunkIndexOutOfRange { index: 1, chunks: 1 })
+    );
+
+    Ok(())
+}
+
+#[test]
+fn freeze_too_large() {
+    let mut tx = TransferTransaction::new();
+
+    for num in 0..400 {
+        tx.hbar_transfer(AccountId::new(0, 0, 1000 + num), Hbar::from_tinybars(1));
+    }
+
+    tx.transaction_id(TransactionId::generate(101.into())).node_account_ids([6.into()]);
+
+    let res = tx.freeze();
+
+    assert_matches!(
+        res,
+        Err(crate::Error::TransactionTooLarge { size, limit: 6144 }) if size > 6144
+    );
+    assert!(!tx.is_frozen());
+}
+
+#[test]
+fn auto_chunk_size() -> crate::Result<()> {
+    let mut tx = FileAppendTransaction::new();
+
+    tx.file_id(FileId::new(0, 0, 1000))
+        .contents(vec![0xab; 20_000])
+        .auto_chunk_size(3)
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into()])
+        .freeze()?;
+
+    assert_eq!(tx.get_auto_chunk_size(), Some(3));
+    assert!(tx.get_chunk_size() > 4096);
+
+    let sizes = tx.projected_size_bytes_per_chunk(3)?;
+
+    assert_eq!(sizes.len(), 4);
+    assert!(sizes.iter().all(|it| *it <= 6144));
+
+    Ok(())
+}
+
+#[test]
+fn auto_chunk_size_too_many_chunks() {
+    let mut tx = FileAppendTransaction::new();
+
+    tx.file_id(FileId::new(0, 0, 1000))
+        .contents(vec![0xab; 20_000])
+        .max_chunks(2)
+        .auto_chunk_size(3)
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into()]);
+
+    assert_matches!(
+        tx.freeze(),
+        Err(Error::TransactionTooLarge { size, limit: 6144 }) if size > 10_000
+    );
+}
+
+#[test]
+fn auto_chunk_size_too_small() {
+    let mut tx = FileAppendTransaction::new();
+
+    tx.file_id(FileId::new(0, 0, 1000))
+        .contents(vec![0xab; 20_000])
+        .max_chunks(1000)
+        .auto_chunk_size(100)
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into()]);
+
+    assert_matches!(tx.freeze(), Err(Error::TransactionTooLarge { limit: 6144, .. }));
+}
+
+#[test]
+fn lazy_signing_signs_a_single_node() -> crate::Result<()> {
+    let signatures = std::sync::Arc::new(AtomicUsize::new(0));
+    let key = PrivateKey::generate_ed25519();
+    let public_key = key.public_key();
+
+    let bytes = TransferTransaction::new()
+        .hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId::generate(101.into()))
+        .node_account_ids([6.into(), 7.into(), 8.into()])
+        .freeze()?
+        .to_bytes()?;
+
+    let mut tx = AnyTransaction::from_bytes(&bytes)?;
+
+    tx.sign_with(public_key, {
+        let signatures = std::sync::Arc::clone(&signatures);
+        move |message| {
+            signatures.fetch_add(1, Ordering::Relaxed);
+            key.sign(message)
+        }
+    });
+
+    // measuring the transaction doesn't sign it.
+    let _ = tx.size_bytes()?;
+    assert_eq!(signatures.load(Ordering::Relaxed), 0);
+
+    let sources = tx.sources().unwrap();
+    let request = sources.signed_request(1, &tx.signers, tx.get_signature_prefix_mode());
+
+    assert_eq!(signatures.load(Ordering::Relaxed), 1);
+
+    let signed =
+        services::SignedTransaction::decode(request.signed_transaction_bytes.as_slice()).unwrap();
+
+    assert_eq!(signed.body_bytes, sources.signed_transactions()[1].body_bytes);
+    assert!(crate::key::has_valid_signature(
+        &public_key,
+        &signed.body_bytes,
+        &signed.sig_map.unwrap().sig_pair
+    ));
+
+    Ok(())
+}
+
+fn frozen_transfer() -> crate::Result<TransferTransaction> {
+    let mut tx = TransferTransaction::new();
+
+    tx.hbar_transfer(2.into(), Hbar::new(2))
+        .hbar_transfer(101.into(), Hbar::new(-2))
+        .transaction_id(TransactionId {
+            account_id: 101.into(),
+            valid_start: OffsetDateTime::now_utc(),
+            nonce: None,
+            scheduled: false,
+        })
+        .node_account_ids([6.into(), 7.into()])
+        .freeze()?;
+
+    Ok(tx)
+}
+
+#[test]
+fn typed_from_bytes() -> crate::Result<()> {
+    let bytes = frozen_transfer()?.to_bytes()?;
+
+    let tx = TransferTransaction::from_bytes(&bytes)?;
+
+    assert_eq!(tx.get_node_account_ids(), Some([6.into(), 7.into()].as_slice()));
+
+    assert_matches!(
+        FileAppendTransaction::from_bytes(&bytes),
+        Err(Error::TransactionTypeMismatch(RequestType::CryptoTransfer))
+    );
+
+    Ok(())
+}
+
+#[test]
+fn from_signed_transaction_bytes() -> crate::Result<()> {
+    let bytes = frozen_transfer()?.to_bytes()?;
+
+    let list = hedera_proto::sdk::TransactionList::decode(bytes.as_slice()).unwrap();
+
+    let tx = TransferTransaction::from_signed_transaction_bytes(
+        &list.transaction_list[1].signed_transaction_bytes,
+    )?;
+
+    assert_eq!(tx.get_node_account_ids(), Some([7.into()].as_slice()));
+
+    Ok(())
+}
+
+#[test]
+fn body_bytes_round_trip() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+    let tx = frozen_transfer()?;
+
+    let bodies = tx.to_body_bytes()?;
+
+    assert_eq!(bodies.len(), 2);
+    assert_eq!(bodies[0].node_account_id, 6.into());
+    assert_eq!(bodies[1].node_account_id, 7.into());
+    assert_eq!(bodies[0].transaction_id, tx.get_transaction_id().unwrap());
+
+    // sign the way an external signer would: over the raw body bytes.
+    let signed = bodies.into_iter().map(|body| {
+        let signature = key.sign(&body.body_bytes);
+        (body.body_bytes, [(key.public_key(), signature)])
+    });
+
+    let mut tx2 = TransferTransaction::from_body_bytes(signed)?;
+
+    key.public_key().verify_transaction(&mut tx2)?;
+    assert_eq!(tx2.to_body_bytes()?, tx.to_body_bytes()?);
+
+    Ok(())
+}
+
+/// Adds a field that this SDK doesn't know about to the bodies of `bytes` (for which `add_to_body` returns true),
+/// and to every signed transaction.
+fn add_unknown_fields(
+    bytes: &[u8],
+    add_to_body: impl Fn(usize) -> bool,
+) -> hedera_proto::sdk::TransactionList {
+    let mut list = hedera_proto::sdk::TransactionList::decode(bytes).unwrap();
+
+    for (index, transaction) in list.transaction_list.iter_mut().enumerate() {
+        let mut signed =
+            services::SignedTransaction::decode(&*transaction.signed_transaction_bytes).unwrap();
+
+        if add_to_body(index) {
+            prost::encoding::uint64::encode(9999, &1, &mut signed.body_bytes);
+        }
+
+        transaction.signed_transaction_bytes = signed.encode_to_vec();
+        prost::encoding::uint64::encode(15, &1, &mut transaction.signed_transaction_bytes);
+    }
+
+    list
+}
+
+#[test]
+fn sign_preserves_unknown_fields() -> crate::Result<()> {
+    let key = PrivateKey::generate_ed25519();
+    let original = add_unknown_fields(&frozen_transfer()?.to_bytes()?, |_| true);
+
+    let mut tx = TransferTransaction::from_bytes(&original.encode_to_vec())?;
+    tx.sign(key.clone());
+
+    let signed = hedera_proto::sdk::TransactionList::decode(tx.to_bytes()?.as_slice()).unwrap();
+
+    for (original, signed) in original.transaction_list.iter().zip(&signed.transaction_list) {
+        let original_signed =
+            services::SignedTransaction::decode(&*original.signed_transaction_bytes).unwrap();
+        let new_signed =
+            services::SignedTransaction::decode(&*signed.signed_transaction_bytes).unwrap();
+
+        assert_eq!(new_signed.body_bytes, original_signed.body_bytes);
+        assert_eq!(
+            crate::protobuf::strip_fields(&signed.signed_transaction_bytes, &[1, 2])?,
+            crate::protobuf::strip_fields(&original.signed_transaction_bytes, &[1, 2])?,
+        );
+    }
+
+    let mut tx2 = TransferTransaction::from_bytes(&tx.to_bytes()?)?;
+    key.public_key().verify_transaction(&mut tx2)?;
+
+    Ok(())
+}
+
+#[test]
+fn from_bytes_mismatched_unknown_fields() -> crate::Result<()> {
+    let list = add_unknown_fields(&frozen_transfer()?.to_bytes()?, |index| index == 1);
+
+    assert_matches!(
+        TransferTransaction::from_bytes(&list.encode_to_vec()),
+        Err(Error::FromProtobuf(_))
+    );
+
+    Ok(())
+}
+
+#[test]
+#[should_panic(expected = "transaction is immutable")]
+fn from_bytes_is_immutable() {
+    let bytes = frozen_transfer().unwrap().to_bytes().unwrap();
+
+    TransferTransaction::from_bytes(&bytes).unwrap().transaction_memo("hi");
+}
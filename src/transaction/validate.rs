@@ -0,0 +1,349 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fmt::{
+    Display,
+    Formatter,
+};
+
+use time::Duration;
+
+use super::TransactionData;
+use crate::{
+    Error,
+    Status,
+    TokenId,
+    Transaction,
+};
+
+/// The maximum length of any memo, in bytes.
+pub(crate) const MAX_MEMO_LEN: usize = 100;
+
+/// The maximum number of entries in a batch, such as NFT serials in a `TokenMintTransaction`.
+pub(crate) const MAX_BATCH_SIZE: usize = 10;
+
+/// The maximum number of custom fees a token may have.
+pub(crate) const MAX_CUSTOM_FEES: usize = 10;
+
+/// The maximum length of a token's name or symbol, in bytes.
+const MAX_TOKEN_TEXT_LEN: usize = 100;
+
+/// The maximum number of allowances in a single transaction.
+const MAX_ALLOWANCES: usize = 20;
+
+const MIN_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(15);
+const MAX_TRANSACTION_VALID_DURATION: Duration = Duration::seconds(180);
+
+const MIN_AUTO_RENEW_PERIOD: Duration = Duration::seconds(2_592_000);
+const MAX_AUTO_RENEW_PERIOD: Duration = Duration::seconds(8_000_001);
+
+/// A limit enforced by the network that a transaction doesn't satisfy.
+///
+/// See [`Transaction::validate`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TransactionViolation {
+    /// The status the network would respond with.
+    pub status: Status,
+
+    /// A human readable description of the violation.
+    pub message: String,
+}
+
+impl Display for TransactionViolation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}: {}", self.status, self.message)
+    }
+}
+
+/// Collects [`TransactionViolation`]s.
+#[derive(Debug, Default)]
+pub struct Violations(Vec<TransactionViolation>);
+
+impl Violations {
+    pub(crate) fn push(&mut self, status: Status, message: impl Into<String>) {
+        self.0.push(TransactionViolation { status, message: message.into() });
+    }
+
+    /// Checks that `memo` is acceptable as the value of the memo field named `field`.
+    pub(crate) fn memo(&mut self, field: &str, memo: &str) {
+        if memo.len() > MAX_MEMO_LEN {
+            self.push(
+                Status::MemoTooLong,
+                format!("{field} is {} bytes, the maximum is {MAX_MEMO_LEN}", memo.len()),
+            );
+        }
+
+        if memo.contains('\0') {
+            self.push(Status::InvalidZeroByteInString, format!("{field} contains a zero byte"));
+        }
+    }
+
+    /// Checks the name of a token, an empty `name` is left for the caller to check.
+    pub(crate) fn token_name(&mut self, name: &str) {
+        self.token_text("token name", name, Status::TokenNameTooLong);
+    }
+
+    /// Checks the symbol of a token, an empty `symbol` is left for the caller to check.
+    pub(crate) fn token_symbol(&mut self, symbol: &str) {
+        self.token_text("token symbol", symbol, Status::TokenSymbolTooLong);
+    }
+
+    fn token_text(&mut self, field: &str, text: &str, too_long: Status) {
+        if text.len() > MAX_TOKEN_TEXT_LEN {
+            self.push(
+                too_long,
+                format!("{field} is {} bytes, the maximum is {MAX_TOKEN_TEXT_LEN}", text.len()),
+            );
+        }
+
+        if text.contains('\0') {
+            self.push(Status::InvalidZeroByteInString, format!("{field} contains a zero byte"));
+        }
+    }
+
+    pub(crate) fn auto_renew_period(&mut self, period: Option<Duration>) {
+        let (min, max) = (MIN_AUTO_RENEW_PERIOD, MAX_AUTO_RENEW_PERIOD);
+
+        match period {
+            Some(period) if !(min..=max).contains(&period) => self.push(
+                Status::AutorenewDurationNotInRange,
+                format!("auto renew period of {period} is outside of {min}..={max}"),
+            ),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn batch_size(&mut self, what: &str, len: usize) {
+        if len > MAX_BATCH_SIZE {
+            self.push(
+                Status::BatchSizeLimitExceeded,
+                format!("{len} {what} given, the maximum is {MAX_BATCH_SIZE}"),
+            );
+        }
+    }
+
+    pub(crate) fn allowances(&mut self, len: usize) {
+        match len {
+            0 => self.push(Status::EmptyAllowances, "no allowances given"),
+            len if len > MAX_ALLOWANCES => self.push(
+                Status::MaxAllowancesExceeded,
+                format!("{len} allowances given, the maximum is {MAX_ALLOWANCES}"),
+            ),
+            _ => {}
+        }
+    }
+
+    pub(crate) fn unique_token_ids(&mut self, token_ids: &[TokenId]) {
+        let mut seen = HashSet::with_capacity(token_ids.len());
+
+        for token_id in token_ids {
+            if !seen.insert(token_id) {
+                self.push(
+                    Status::TokenIdRepeatedInTokenList,
+                    format!("token {token_id} appears more than once"),
+                );
+            }
+        }
+    }
+
+    pub(crate) fn custom_fees(&mut self, len: usize) {
+        if len > MAX_CUSTOM_FEES {
+            self.push(
+                Status::CustomFeesListTooLong,
+                format!("{len} custom fees given, the maximum is {MAX_CUSTOM_FEES}"),
+            );
+        }
+    }
+}
+
+impl<D: TransactionData> Transaction<D> {
+    /// Checks this transaction against limits the network enforces, without contacting the network.
+    ///
+    /// This catches mistakes that would otherwise cost a pre-check round trip (or a fee),
+    /// such as transfers that don't sum to zero or memos that are too long.
+    ///
+    /// Passing validation does *not* guarantee that the network will accept the transaction.
+    ///
+    /// Validation can be run automatically by [`freeze_with`](Self::freeze_with) and [`execute`](Self::execute),
+    /// see [`Client::set_auto_validate_transactions`](crate::Client::set_auto_validate_transactions).
+    ///
+    /// # Errors
+    /// - [`Error::TransactionValidation`] with every violation found.
+    pub fn validate(&self) -> crate::Result<()> {
+        let mut violations = Violations::default();
+
+        violations.memo("transaction memo", &self.body.transaction_memo);
+
+        let (min, max) = (MIN_TRANSACTION_VALID_DURATION, MAX_TRANSACTION_VALID_DURATION);
+
+        match self.body.transaction_valid_duration {
+            Some(duration) if !(min..=max).contains(&duration) => violations.push(
+                Status::InvalidTransactionDuration,
+                format!("transaction valid duration of {duration} is outside of {min}..={max}"),
+            ),
+            _ => {}
+        }
+
+        if self.body.transaction_id.map_or(false, |it| it.scheduled) {
+            violations.push(
+                Status::TransactionIdFieldNotAllowed,
+                "transaction ID must not be marked as scheduled",
+            );
+        }
+
+        self.data().validate_data(&mut violations);
+
+        match violations.0.is_empty() {
+            true => Ok(()),
+            false => Err(Error::TransactionValidation { violations: violations.0 }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use crate::{
+        AccountId,
+        Error,
+        Hbar,
+        Status,
+        TokenCreateTransaction,
+        TokenId,
+        TokenMintTransaction,
+        TokenType,
+        TransferTransaction,
+    };
+
+    fn statuses(result: crate::Result<()>) -> Vec<Status> {
+        let violations =
+            assert_matches!(result, Err(Error::TransactionValidation { violations }) => violations);
+
+        violations.into_iter().map(|it| it.status).collect()
+    }
+
+    #[test]
+    fn valid_transfer() {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(-1))
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 2), 10, 2)
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 3), -10, 2);
+
+        tx.validate().unwrap();
+    }
+
+    #[test]
+    fn unbalanced_transfer() {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(1))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::new(-2))
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 2), 10);
+
+        assert_eq!(
+            statuses(tx.validate()),
+            [Status::InvalidAccountAmounts, Status::TransfersNotZeroSumForToken]
+        );
+    }
+
+    #[test]
+    fn overflowing_transfer() {
+        let mut tx = TransferTransaction::new();
+
+        // these wrap around to zero when summed as `i64`.
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::from_tinybars(i64::MAX))
+            .hbar_transfer(AccountId::new(0, 0, 3), Hbar::from_tinybars(i64::MAX))
+            .hbar_transfer(AccountId::new(0, 0, 4), Hbar::from_tinybars(2))
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 2), i64::MIN)
+            .token_transfer(TokenId::new(0, 0, 5), AccountId::new(0, 0, 3), i64::MIN);
+
+        assert_eq!(
+            statuses(tx.validate()),
+            [Status::InvalidAccountAmounts, Status::TransfersNotZeroSumForToken]
+        );
+    }
+
+    #[test]
+    fn repeated_account_and_decimals_mismatch() {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(1))
+            .hbar_transfer(AccountId::new(0, 0, 2), Hbar::new(-1))
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 2), 10, 2)
+            .token_transfer_with_decimals(TokenId::new(0, 0, 5), AccountId::new(0, 0, 3), -10, 3);
+
+        assert_eq!(
+            statuses(tx.validate()),
+            [Status::AccountRepeatedInAccountAmounts, Status::UnexpectedTokenDecimals]
+        );
+    }
+
+    #[test]
+    fn too_many_transfers() {
+        let mut tx = TransferTransaction::new();
+
+        for num in 1..=11 {
+            tx.hbar_transfer(AccountId::new(0, 0, num), Hbar::from_tinybars(1));
+        }
+
+        tx.hbar_transfer(AccountId::new(0, 0, 100), Hbar::from_tinybars(-11));
+
+        assert_eq!(statuses(tx.validate()), [Status::TransferListSizeLimitExceeded]);
+    }
+
+    #[test]
+    fn memo_and_duration() {
+        let mut tx = TransferTransaction::new();
+
+        tx.transaction_memo("a".repeat(101))
+            .transaction_valid_duration(time::Duration::seconds(181));
+
+        assert_eq!(
+            statuses(tx.validate()),
+            [Status::MemoTooLong, Status::InvalidTransactionDuration]
+        );
+    }
+
+    #[test]
+    fn token_create() {
+        let mut tx = TokenCreateTransaction::new();
+
+        tx.name("").symbol("F").token_type(TokenType::NonFungibleUnique).decimals(2);
+
+        assert_eq!(
+            statuses(tx.validate()),
+            [Status::MissingTokenName, Status::InvalidTokenDecimals, Status::TokenHasNoSupplyKey]
+        );
+    }
+
+    #[test]
+    fn token_mint_batch() {
+        let mut tx = TokenMintTransaction::new();
+
+        tx.token_id(TokenId::new(0, 0, 5)).metadata(vec![vec![1]; 11]);
+
+        assert_eq!(statuses(tx.validate()), [Status::BatchSizeLimitExceeded]);
+    }
+}
@@ -0,0 +1,265 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use time::{
+    Duration,
+    OffsetDateTime,
+};
+
+use super::{
+    ToTransactionDataProtobuf,
+    TransactionData,
+    DEFAULT_TRANSACTION_VALID_DURATION,
+};
+use crate::{
+    AccountId,
+    PrivateKey,
+    Transaction,
+    TransactionId,
+    ValidateChecksums,
+};
+
+/// Frozen copies of the same transaction, each with its own transaction ID, and so each valid during a different window of time.
+///
+/// Since a transaction is only valid for [`transaction_valid_duration`](Transaction::transaction_valid_duration) after its valid start,
+/// this allows signing a transaction well ahead of time (IE, with an air-gapped wallet):
+/// every copy is signed at once, and later the copy whose window is open gets submitted.
+///
+/// **Only one copy may ever be submitted.**
+/// Each copy has its own transaction ID, so the network doesn't treat them as duplicates of each other:
+/// every copy that's submitted during its window executes, and does the same thing again.
+/// Once a copy has been submitted, the rest should be discarded
+/// (even if it failed, since it may still reach consensus until its window closes).
+///
+/// Created with [`Transaction::freeze_windows`].
+#[derive(Clone)]
+pub struct TransactionWindows<D> {
+    transactions: Vec<Transaction<D>>,
+}
+
+impl<D> TransactionWindows<D> {
+    /// Create windows from existing frozen copies of a transaction, IE, copies that were parsed from bytes after being signed.
+    #[must_use]
+    pub fn new(transactions: impl IntoIterator<Item = Transaction<D>>) -> Self {
+        Self { transactions: transactions.into_iter().collect() }
+    }
+
+    /// Returns the copies of the transaction, in the order they were generated.
+    #[must_use]
+    pub fn transactions(&self) -> &[Transaction<D>] {
+        &self.transactions
+    }
+
+    /// Returns the copies of the transaction, in the order they were generated.
+    pub fn transactions_mut(&mut self) -> &mut [Transaction<D>] {
+        &mut self.transactions
+    }
+
+    /// Returns the copies of the transaction, in the order they were generated.
+    #[must_use]
+    pub fn into_transactions(self) -> Vec<Transaction<D>> {
+        self.transactions
+    }
+
+    /// Sign every copy of the transaction with the given `private_key`.
+    pub fn sign(&mut self, private_key: PrivateKey) -> &mut Self {
+        for transaction in &mut self.transactions {
+            transaction.sign(private_key.clone());
+        }
+
+        self
+    }
+
+    /// Returns the copy whose window is open `at` the given time.
+    ///
+    /// If several windows overlap, the one that opened last (and so stays open the longest) is returned.
+    ///
+    /// Only one copy may ever be submitted, see [`TransactionWindows`].
+    #[must_use]
+    pub fn open_at(&self, at: OffsetDateTime) -> Option<&Transaction<D>> {
+        self.open_index_at(at).map(|index| &self.transactions[index])
+    }
+
+    /// Returns the copy whose window is open `at` the given time.
+    ///
+    /// If several windows overlap, the one that opened last (and so stays open the longest) is returned.
+    pub fn open_at_mut(&mut self, at: OffsetDateTime) -> Option<&mut Transaction<D>> {
+        self.open_index_at(at).map(|index| &mut self.transactions[index])
+    }
+
+    fn open_index_at(&self, at: OffsetDateTime) -> Option<usize> {
+        self.transactions
+            .iter()
+            .enumerate()
+            .filter_map(|(index, transaction)| {
+                let valid_start = transaction.get_transaction_id()?.valid_start;
+                let valid_duration = transaction
+                    .get_transaction_valid_duration()
+                    .unwrap_or(DEFAULT_TRANSACTION_VALID_DURATION);
+
+                (valid_start <= at && at < valid_start + valid_duration)
+                    .then_some((valid_start, index))
+            })
+            .max()
+            .map(|(_, index)| index)
+    }
+}
+
+impl<D: ValidateChecksums + TransactionData + ToTransactionDataProtobuf> Transaction<D> {
+    /// Generate `count` frozen copies of `self`, paid for by `payer_account_id`,
+    /// whose valid starts are `interval` apart beginning at `first_valid_start`.
+    ///
+    /// For the windows to cover a span of time without gaps, `interval` shouldn't be longer than
+    /// the [`transaction_valid_duration`](Self::transaction_valid_duration).
+    ///
+    /// Every copy executes if it's submitted, so only one of them may ever be submitted.
+    ///
+    /// # Examples
+    /// ```
+    /// # fn main() -> hedera::Result<()> {
+    /// use hedera::TransferTransaction;
+    /// use time::{Duration, OffsetDateTime};
+    ///
+    /// let mut template = TransferTransaction::new();
+    /// template
+    ///     .hbar_transfer(2.into(), hedera::Hbar::new(1))
+    ///     .hbar_transfer(1001.into(), hedera::Hbar::new(-1))
+    ///     .node_account_ids([3.into()]);
+    ///
+    /// // one window every 2 minutes, for the next 24 hours.
+    /// let windows = template.freeze_windows(
+    ///     1001.into(),
+    ///     OffsetDateTime::now_utc(),
+    ///     Duration::minutes(2),
+    ///     24 * 30,
+    /// )?;
+    /// # assert_eq!(windows.transactions().len(), 720);
+    /// # Ok(())
+    /// # }
+    /// ```
+    ///
+    /// # Errors
+    /// - [`Error::FreezeUnsetNodeAccountIds`](crate::Error::FreezeUnsetNodeAccountIds) if no [`node_account_ids`](Self::node_account_ids) were set.
+    /// - If [`freeze`](Self::freeze) would error for any of the copies.
+    ///
+    /// # Panics
+    /// - If `self` is frozen.
+    pub fn freeze_windows(
+        &self,
+        payer_account_id: AccountId,
+        first_valid_start: OffsetDateTime,
+        interval: Duration,
+        count: usize,
+    ) -> crate::Result<TransactionWindows<D>> {
+        self.require_not_frozen();
+
+        let mut transactions = Vec::with_capacity(count);
+        let mut valid_start = first_valid_start;
+
+        for _ in 0..count {
+            let mut transaction = self.clone();
+
+            transaction
+                .transaction_id(TransactionId {
+                    account_id: payer_account_id,
+                    valid_start,
+                    nonce: None,
+                    scheduled: false,
+                })
+                .freeze()?;
+
+            transactions.push(transaction);
+            valid_start += interval;
+        }
+
+        Ok(TransactionWindows { transactions })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use time::{
+        Duration,
+        OffsetDateTime,
+    };
+
+    use crate::{
+        Hbar,
+        PrivateKey,
+        TransferTransaction,
+    };
+
+    fn template() -> TransferTransaction {
+        let mut tx = TransferTransaction::new();
+
+        tx.hbar_transfer(2.into(), Hbar::new(1))
+            .hbar_transfer(101.into(), Hbar::new(-1))
+            .node_account_ids([3.into()]);
+
+        tx
+    }
+
+    #[test]
+    fn freeze_windows() -> crate::Result<()> {
+        let start = OffsetDateTime::now_utc();
+
+        let windows = template().freeze_windows(101.into(), start, Duration::minutes(2), 3)?;
+
+        let valid_starts: Vec<_> = windows
+            .transactions()
+            .iter()
+            .map(|it| it.get_transaction_id().unwrap().valid_start)
+            .collect();
+
+        assert_eq!(
+            valid_starts,
+            [start, start + Duration::minutes(2), start + Duration::minutes(4)]
+        );
+
+        assert!(windows.transactions().iter().all(|it| it.is_frozen()));
+
+        Ok(())
+    }
+
+    #[test]
+    fn open_at() -> crate::Result<()> {
+        let start = OffsetDateTime::now_utc();
+        let mut tx = template();
+        tx.transaction_valid_duration(Duration::minutes(3));
+
+        let mut windows = tx.freeze_windows(101.into(), start, Duration::minutes(2), 3)?;
+        windows.sign(PrivateKey::generate_ed25519());
+
+        let open_start =
+            |at| windows.open_at(at).map(|it| it.get_transaction_id().unwrap().valid_start);
+
+        assert_eq!(open_start(start - Duration::seconds(1)), None);
+        assert_eq!(open_start(start), Some(start));
+        // both the first and second window are open, the second stays open longer.
+        assert_eq!(open_start(start + Duration::minutes(2)), Some(start + Duration::minutes(2)));
+        assert_eq!(
+            open_start(start + Duration::minutes(6) + Duration::seconds(59)),
+            Some(start + Duration::minutes(4))
+        );
+        assert_eq!(open_start(start + Duration::minutes(7)), None);
+
+        Ok(())
+    }
+}
@@ -34,6 +34,7 @@ use crate::{
     PublicKey,
     ScheduleId,
     Tinybar,
+    TokenAmount,
     TokenAssociation,
     TokenId,
     TokenNftTransfer,
@@ -45,6 +46,8 @@ use crate::{
 /// The complete record for a transaction on Hedera that has reached consensus.
 /// Response from [`TransactionRecordQuery`][crate::TransactionRecordQuery].
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 pub struct TransactionRecord {
     /// The status (reach consensus, or failed, or is unknown) and the ID of
     /// any new account/file/instance created.
@@ -52,6 +55,7 @@ pub struct TransactionRecord {
 
     /// The hash of the Transaction that executed (not the hash of any Transaction that failed for
     /// having a duplicate TransactionID).
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub transaction_hash: Vec<u8>,
 
     /// The consensus timestamp.
@@ -66,6 +70,7 @@ pub struct TransactionRecord {
     pub transfers: Vec<Transfer>,
 
     /// All fungible token transfers as a result of this transaction.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount_nested_map"))]
     pub token_transfers: HashMap<TokenId, HashMap<AccountId, i64>>,
 
     /// All NFT Token transfers as a result of this transaction.
@@ -108,14 +113,11 @@ pub struct TransactionRecord {
 
     /// The keccak256 hash of the ethereumData. This field will only be populated for
     /// `EthereumTransaction`.
-    #[cfg_attr(feature = "ffi", serde(with = "serde_with::As::<serde_with::base64::Base64>"))]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex"))]
     pub ethereum_hash: Vec<u8>,
 
     /// In the record of a PRNG transaction with no output range, a pseudorandom 384-bit string.
-    #[cfg_attr(
-        feature = "ffi",
-        serde(with = "serde_with::As::<Option<serde_with::base64::Base64>>")
-    )]
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::hex_opt"))]
     pub prng_bytes: Option<Vec<u8>>,
 
     /// In the record of a PRNG transaction with an output range, the output of a PRNG
@@ -143,6 +145,30 @@ impl TransactionRecord {
         ToProtobuf::to_bytes(self)
     }
 
+    /// Returns the fungible token transfers of this record as [`TokenAmount`]s.
+    ///
+    /// Only tokens that have an entry in `decimals` (for example, from [`TokenInfo::decimals`](crate::TokenInfo::decimals)) are included.
+    #[must_use]
+    pub fn token_amounts(
+        &self,
+        decimals: &HashMap<TokenId, u32>,
+    ) -> HashMap<TokenId, HashMap<AccountId, TokenAmount>> {
+        self.token_transfers
+            .iter()
+            .filter_map(|(token_id, transfers)| {
+                let decimals = *decimals.get(token_id)?;
+                let transfers = transfers
+                    .iter()
+                    .map(|(account_id, amount)| {
+                        (*account_id, TokenAmount::from_lowest_denomination(*amount, decimals))
+                    })
+                    .collect();
+
+                Some((*token_id, transfers))
+            })
+            .collect()
+    }
+
     fn from_protobuf(
         record: services::TransactionRecord,
         duplicates: Vec<Self>,
@@ -348,6 +374,7 @@ mod tests {
         Hbar,
         PrivateKey,
         ScheduleId,
+        TokenAmount,
         TokenAssociation,
         TokenId,
         TokenNftTransfer,
@@ -1233,4 +1260,22 @@ mod tests {
 
         assert_eq!(a.to_protobuf(), b.to_protobuf());
     }
+
+    #[test]
+    fn token_amounts() {
+        let record = make_record(None, Some(4));
+        assert!(record.token_amounts(&HashMap::new()).is_empty());
+        assert!(record.token_amounts(&HashMap::from([(TokenId::new(5, 5, 5), 2)])).is_empty());
+
+        assert_eq!(
+            record.token_amounts(&HashMap::from([(TokenId::new(6, 6, 6), 2)])),
+            HashMap::from([(
+                TokenId::new(6, 6, 6),
+                HashMap::from([(
+                    AccountId::new(1, 1, 1),
+                    TokenAmount::from_lowest_denomination(4, 2)
+                )]),
+            )])
+        );
+    }
 }
@@ -0,0 +1,872 @@
+/*
+ * ‌
+ * Hedera Rust SDK
+ * ​
+ * Copyright (C) 2022 - 2023 Hedera Hashgraph, LLC
+ * ​
+ * Licensed under the Apache License, Version 2.0 (the "License");
+ * you may not use this file except in compliance with the License.
+ * You may obtain a copy of the License at
+ *
+ *      http://www.apache.org/licenses/LICENSE-2.0
+ *
+ * Unless required by applicable law or agreed to in writing, software
+ * distributed under the License is distributed on an "AS IS" BASIS,
+ * WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+ * See the License for the specific language governing permissions and
+ * limitations under the License.
+ * ‍
+ */
+
+use std::collections::HashMap;
+
+use crate::pending::{
+    PendingLedger,
+    Submitter,
+};
+use crate::signer::AnySigner;
+use crate::transfer_transaction::MAX_TRANSFERS;
+use crate::{
+    AccountId,
+    Client,
+    Error,
+    Hbar,
+    PrivateKey,
+    PublicKey,
+    TokenAmount,
+    TokenId,
+    TransactionId,
+    TransactionReceipt,
+    TransferTransaction,
+};
+
+/// The most recipients a single transfer list can pay, leaving room for the sender.
+const MAX_RECIPIENTS: usize = MAX_TRANSFERS - 1;
+
+/// A single payment in a [`TransferDistribution`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DistributionEntry {
+    /// The account being paid.
+    pub recipient: AccountId,
+
+    /// The token being paid, or `None` for hbar.
+    pub token_id: Option<TokenId>,
+
+    /// The amount being paid, in tinybars or the lowest denomination of the token.
+    pub amount: i64,
+
+    /// The decimals the token is expected to have, if known.
+    pub decimals: Option<u32>,
+}
+
+/// A payment that a [`TransferDistribution`] submitted to the network.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DistributionRecord {
+    /// The account being paid.
+    pub recipient: AccountId,
+
+    /// The token being paid, or `None` for hbar.
+    pub token_id: Option<TokenId>,
+
+    /// The amount being paid, in tinybars or the lowest denomination of the token.
+    pub amount: i64,
+
+    /// The ID of the transaction that carried the payment.
+    pub transaction_id: TransactionId,
+}
+
+/// Tracks which payments of a [`TransferDistribution`] have been made.
+///
+/// Payments are identified by their recipient and token (or hbar), so executing a distribution
+/// again with the same ledger skips everyone who has already been paid.
+///
+/// A payment whose transaction has been submitted, but whose outcome isn't known yet, is [pending](Self::pending),
+/// and isn't retried until its receipt is found by a later execution or [`clear_pending`](Self::clear_pending) is called.
+///
+/// Persist the ledger (with the `serde` feature it can be serialized) after every execution, successful or not.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
+pub struct DistributionLedger {
+    paid: Vec<DistributionRecord>,
+    pending: Vec<DistributionRecord>,
+}
+
+impl DistributionLedger {
+    /// Create a new, empty `DistributionLedger`.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the payments that are known to have succeeded.
+    #[must_use]
+    pub fn paid(&self) -> &[DistributionRecord] {
+        &self.paid
+    }
+
+    /// Returns the payments that were submitted, but whose outcome isn't known yet.
+    #[must_use]
+    pub fn pending(&self) -> &[DistributionRecord] {
+        &self.pending
+    }
+
+    /// Returns `true` if `recipient` has been paid in `token_id` (or hbar, for `None`).
+    #[must_use]
+    pub fn is_paid(&self, recipient: AccountId, token_id: Option<TokenId>) -> bool {
+        self.paid.iter().any(|it| it.recipient == recipient && it.token_id == token_id)
+    }
+
+    /// Forget the pending payments of `transaction_id`, so that they're retried by the next execution.
+    ///
+    /// Only call this once it's certain that the transaction didn't reach consensus,
+    /// or its recipients will be paid twice.
+    pub fn clear_pending(&mut self, transaction_id: TransactionId) -> &mut Self {
+        self.pending.retain(|it| it.transaction_id != transaction_id);
+
+        self
+    }
+
+    fn contains(&self, recipient: AccountId, token_id: Option<TokenId>) -> bool {
+        self.is_paid(recipient, token_id)
+            || self.pending.iter().any(|it| it.recipient == recipient && it.token_id == token_id)
+    }
+
+    fn pending_transaction_ids(&self) -> Vec<TransactionId> {
+        let mut ids: Vec<_> = self.pending.iter().map(|it| it.transaction_id).collect();
+        ids.dedup();
+
+        ids
+    }
+
+    fn begin(&mut self, transaction_id: TransactionId, batch: &[Segment]) {
+        let records = batch.iter().flat_map(|it| &it.entries).map(|it| DistributionRecord {
+            recipient: it.recipient,
+            token_id: it.token_id,
+            amount: it.amount,
+            transaction_id,
+        });
+
+        self.pending.extend(records);
+    }
+
+    fn confirm(&mut self, transaction_id: TransactionId) {
+        let (paid, pending): (Vec<_>, Vec<_>) =
+            self.pending.drain(..).partition(|it| it.transaction_id == transaction_id);
+
+        self.pending = pending;
+        self.paid.extend(paid);
+    }
+}
+
+impl PendingLedger for DistributionLedger {
+    fn confirm_pending(&mut self, transaction_id: TransactionId, _receipt: &TransactionReceipt) {
+        self.confirm(transaction_id);
+    }
+
+    fn forget_pending(&mut self, transaction_id: TransactionId) {
+        self.clear_pending(transaction_id);
+    }
+}
+
+/// Pay out hbar and fungible tokens from one account to any number of recipients.
+///
+/// The payments are split into as few [`TransferTransaction`]s as the network's limits on
+/// balance adjustments per transaction allow, with each transaction debiting the sender by exactly the amount it pays out.
+/// For distributions of a single asset the number of transactions is minimal,
+/// mixes of tokens are packed first-fit and may use slightly more.
+///
+/// Progress is tracked in a [`DistributionLedger`], which makes execution resumable:
+/// if an execution fails part way through, executing again with the same ledger skips everyone who has been paid.
+#[derive(Debug)]
+pub struct TransferDistribution {
+    sender: AccountId,
+    entries: Vec<DistributionEntry>,
+    max_in_flight: usize,
+    max_transaction_fee: Option<Hbar>,
+    node_account_ids: Option<Vec<AccountId>>,
+    signer: Option<AnySigner>,
+}
+
+impl TransferDistribution {
+    /// Create a new `TransferDistribution` paying out from `sender`.
+    #[must_use]
+    pub fn new(sender: AccountId) -> Self {
+        Self {
+            sender,
+            entries: Vec::new(),
+            max_in_flight: 1,
+            max_transaction_fee: None,
+            node_account_ids: None,
+            signer: None,
+        }
+    }
+
+    /// Returns the account the payments are made from.
+    #[must_use]
+    pub fn get_sender(&self) -> AccountId {
+        self.sender
+    }
+
+    /// Returns the payments to make.
+    #[must_use]
+    pub fn get_entries(&self) -> &[DistributionEntry] {
+        &self.entries
+    }
+
+    /// Adds a payment.
+    ///
+    /// Payments to the same recipient in the same token (or hbar) are combined.
+    pub fn entry(&mut self, entry: DistributionEntry) -> &mut Self {
+        self.entries.push(entry);
+
+        self
+    }
+
+    /// Adds the given payments.
+    pub fn entries(&mut self, entries: impl IntoIterator<Item = DistributionEntry>) -> &mut Self {
+        self.entries.extend(entries);
+
+        self
+    }
+
+    /// Adds a payment of `amount` hbar to `recipient`.
+    pub fn hbar_payment(&mut self, recipient: AccountId, amount: Hbar) -> &mut Self {
+        self.entry(DistributionEntry {
+            recipient,
+            token_id: None,
+            amount: amount.to_tinybars(),
+            decimals: None,
+        })
+    }
+
+    /// Adds a payment of `amount` of `token_id` to `recipient`.
+    ///
+    /// `amount` is in the lowest denomination of the token.
+    pub fn token_payment(
+        &mut self,
+        token_id: TokenId,
+        recipient: AccountId,
+        amount: i64,
+    ) -> &mut Self {
+        self.entry(DistributionEntry {
+            recipient,
+            token_id: Some(token_id),
+            amount,
+            decimals: None,
+        })
+    }
+
+    /// Adds a payment of `amount` of `token_id` to `recipient`, ensuring that the token has the decimals of `amount`.
+    pub fn token_amount_payment(
+        &mut self,
+        token_id: TokenId,
+        recipient: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self.entry(DistributionEntry {
+            recipient,
+            token_id: Some(token_id),
+            amount: amount.to_lowest_denomination(),
+            decimals: Some(amount.decimals()),
+        })
+    }
+
+    /// Returns the maximum number of transactions that may be waiting for their receipts at once.
+    #[must_use]
+    pub fn get_max_in_flight(&self) -> usize {
+        self.max_in_flight
+    }
+
+    /// Sets the maximum number of transactions that may be waiting for their receipts at once.
+    ///
+    /// Defaults to `1`, which submits each transaction only once the previous one has reached consensus.
+    ///
+    /// # Panics
+    /// - If `max_in_flight` is `0`.
+    pub fn max_in_flight(&mut self, max_in_flight: usize) -> &mut Self {
+        assert!(max_in_flight > 0, "max_in_flight must be greater than 0");
+        self.max_in_flight = max_in_flight;
+
+        self
+    }
+
+    /// Returns the maximum transaction fee of each transaction.
+    #[must_use]
+    pub fn get_max_transaction_fee(&self) -> Option<Hbar> {
+        self.max_transaction_fee
+    }
+
+    /// Sets the maximum transaction fee of each transaction.
+    pub fn max_transaction_fee(&mut self, fee: Hbar) -> &mut Self {
+        self.max_transaction_fee = Some(fee);
+
+        self
+    }
+
+    /// Returns the node account IDs the transactions are submitted to.
+    #[must_use]
+    pub fn get_node_account_ids(&self) -> Option<&[AccountId]> {
+        self.node_account_ids.as_deref()
+    }
+
+    /// Sets the node account IDs the transactions are submitted to.
+    pub fn node_account_ids(
+        &mut self,
+        node_account_ids: impl IntoIterator<Item = AccountId>,
+    ) -> &mut Self {
+        self.node_account_ids = Some(node_account_ids.into_iter().collect());
+
+        self
+    }
+
+    /// Sets the key of the sender, if it isn't the client's operator.
+    pub fn sign(&mut self, key: PrivateKey) -> &mut Self {
+        self.signer = Some(AnySigner::PrivateKey(key));
+
+        self
+    }
+
+    /// Sets the signer of the sender, if it isn't the client's operator.
+    pub fn sign_with<F: Fn(&[u8]) -> Vec<u8> + Send + Sync + 'static>(
+        &mut self,
+        public_key: PublicKey,
+        signer: F,
+    ) -> &mut Self {
+        self.signer = Some(AnySigner::arbitrary(Box::new(public_key), signer));
+
+        self
+    }
+
+    /// Returns the transactions that would pay everyone that `ledger` has no record of.
+    ///
+    /// # Errors
+    /// - [`Error::TransferDistribution`] if a payment isn't positive, is to the sender,
+    ///   overflows when combined with another payment to the same recipient,
+    ///   or expects different decimals than another payment of the same token.
+    pub fn plan(&self, ledger: &DistributionLedger) -> crate::Result<Vec<TransferTransaction>> {
+        Ok(self.batches(ledger)?.iter().map(|it| self.make_transaction(it)).collect())
+    }
+
+    /// Pays everyone that `ledger` has no record of, recording the progress in `ledger`.
+    ///
+    /// Payments left pending by an earlier execution are resolved first.
+    ///
+    /// # Errors
+    /// - [`Error::TransferDistribution`] if the distribution can't be [planned](Self::plan).
+    /// - [`Error::ReceiptStatus`] if one of the transactions fails.
+    /// - Any error from submitting a transaction or querying its receipt.
+    pub async fn execute(
+        &self,
+        client: &Client,
+        ledger: &mut DistributionLedger,
+    ) -> crate::Result<()> {
+        self.execute_with_optional_timeout(client, ledger, None).await
+    }
+
+    /// Pays everyone that `ledger` has no record of, recording the progress in `ledger`.
+    ///
+    /// Payments left pending by an earlier execution are resolved first.
+    ///
+    /// # Errors
+    /// - [`Error::TransferDistribution`] if the distribution can't be [planned](Self::plan).
+    /// - [`Error::ReceiptStatus`] if one of the transactions fails.
+    /// - Any error from submitting a transaction or querying its receipt.
+    pub async fn execute_with_timeout(
+        &self,
+        client: &Client,
+        ledger: &mut DistributionLedger,
+        timeout_per_transaction: std::time::Duration,
+    ) -> crate::Result<()> {
+        self.execute_with_optional_timeout(client, ledger, Some(timeout_per_transaction)).await
+    }
+
+    async fn execute_with_optional_timeout(
+        &self,
+        client: &Client,
+        ledger: &mut DistributionLedger,
+        timeout_per_transaction: Option<std::time::Duration>,
+    ) -> crate::Result<()> {
+        let pending = ledger.pending_transaction_ids();
+
+        let mut submitter =
+            Submitter::new(client, ledger, self.max_in_flight, timeout_per_transaction);
+
+        submitter.resolve(pending).await?;
+
+        for batch in self.batches(submitter.ledger())? {
+            let mut transaction = self.make_transaction(&batch);
+
+            if let Some(node_account_ids) = &self.node_account_ids {
+                transaction.node_account_ids(node_account_ids.iter().copied());
+            }
+
+            if let Some(signer) = &self.signer {
+                transaction.sign_signer(signer.clone());
+            }
+
+            submitter
+                .submit(&mut transaction, |ledger, transaction_id| {
+                    ledger.begin(transaction_id, &batch)
+                })
+                .await?;
+        }
+
+        submitter.finish().await
+    }
+
+    /// Combines the payments that `ledger` has no record of and splits them into transactions.
+    fn batches(&self, ledger: &DistributionLedger) -> crate::Result<Vec<Vec<Segment>>> {
+        let mut combined: Vec<DistributionEntry> = Vec::new();
+        let mut indices: HashMap<(AccountId, Option<TokenId>), usize> = HashMap::new();
+        let mut decimals: HashMap<TokenId, u32> = HashMap::new();
+
+        for entry in &self.entries {
+            let error = |reason| Error::TransferDistribution {
+                recipient: Box::new(entry.recipient),
+                reason,
+            };
+
+            if entry.amount <= 0 {
+                return Err(error("the amount must be positive"));
+            }
+
+            if entry.recipient == self.sender {
+                return Err(error("the recipient is the sender"));
+            }
+
+            if let (Some(token_id), Some(new)) = (entry.token_id, entry.decimals) {
+                if *decimals.entry(token_id).or_insert(new) != new {
+                    return Err(error("the token is expected to have different decimals"));
+                }
+            }
+
+            if ledger.contains(entry.recipient, entry.token_id) {
+                continue;
+            }
+
+            match indices.get(&(entry.recipient, entry.token_id)) {
+                Some(&index) => {
+                    let existing = &mut combined[index];
+                    existing.amount = existing
+                        .amount
+                        .checked_add(entry.amount)
+                        .ok_or_else(|| error("the combined payments to the recipient overflow"))?;
+                }
+
+                None => {
+                    indices.insert((entry.recipient, entry.token_id), combined.len());
+                    combined.push(*entry);
+                }
+            }
+        }
+
+        // group the payments by asset, keeping the order in which each asset first appears.
+        let mut assets: Vec<(Option<TokenId>, Vec<DistributionEntry>)> = Vec::new();
+        for entry in combined {
+            match assets.iter_mut().find(|(token_id, _)| *token_id == entry.token_id) {
+                Some((_, entries)) => entries.push(entry),
+                None => assets.push((entry.token_id, Vec::from([entry]))),
+            }
+        }
+
+        let mut hbar_segments = Vec::new();
+        let mut token_segments = Vec::new();
+
+        for (token_id, entries) in assets {
+            let decimals = token_id.and_then(|it| decimals.get(&it).copied());
+            let segments = split_into_segments(token_id, decimals, entries);
+
+            match token_id {
+                None => hbar_segments = segments,
+                Some(_) => token_segments.extend(segments),
+            }
+        }
+
+        // first-fit decreasing: a segment of `MAX_RECIPIENTS` fills a transaction on its own,
+        // so this is really packing the remainders of each token together.
+        token_segments.sort_by_key(|it| std::cmp::Reverse(it.cost()));
+
+        let mut token_batches: Vec<(usize, Vec<Segment>)> = Vec::new();
+
+        for segment in token_segments {
+            let cost = segment.cost();
+
+            // the sender can only appear once per token in a transaction.
+            let batch = token_batches.iter_mut().find(|(used, segments)| {
+                used + cost <= MAX_TRANSFERS
+                    && segments.iter().all(|it| it.token_id != segment.token_id)
+            });
+
+            match batch {
+                Some((used, segments)) => {
+                    *used += cost;
+                    segments.push(segment);
+                }
+
+                None => token_batches.push((cost, Vec::from([segment]))),
+            }
+        }
+
+        // hbar and token transfers are limited separately, so each transaction can carry one of each.
+        let len = hbar_segments.len().max(token_batches.len());
+        let mut hbar_segments = hbar_segments.into_iter();
+        let mut token_batches = token_batches.into_iter();
+
+        let batches = (0..len)
+            .map(|_| {
+                let mut batch: Vec<_> = hbar_segments.next().into_iter().collect();
+                batch.extend(token_batches.next().into_iter().flat_map(|(_, it)| it));
+
+                batch
+            })
+            .collect();
+
+        Ok(batches)
+    }
+
+    fn make_transaction(&self, batch: &[Segment]) -> TransferTransaction {
+        let mut transaction = TransferTransaction::new();
+
+        for segment in batch {
+            let mut transfer = |account_id, amount| match (segment.token_id, segment.decimals) {
+                (None, _) => {
+                    transaction.hbar_transfer(account_id, Hbar::from_tinybars(amount));
+                }
+
+                (Some(token_id), None) => {
+                    transaction.token_transfer(token_id, account_id, amount);
+                }
+
+                (Some(token_id), Some(decimals)) => {
+                    transaction
+                        .token_transfer_with_decimals(token_id, account_id, amount, decimals);
+                }
+            };
+
+            transfer(self.sender, -segment.total);
+
+            for entry in &segment.entries {
+                transfer(entry.recipient, entry.amount);
+            }
+        }
+
+        if let Some(fee) = self.max_transaction_fee {
+            transaction.max_transaction_fee(fee);
+        }
+
+        transaction
+    }
+}
+
+/// Payments of a single asset that go in the same transaction.
+#[derive(Debug)]
+struct Segment {
+    token_id: Option<TokenId>,
+    decimals: Option<u32>,
+    entries: Vec<DistributionEntry>,
+
+    /// The sum of the amounts of `entries`, which the sender is debited.
+    total: i64,
+}
+
+impl Segment {
+    /// The number of balance adjustments this segment needs, including the sender's.
+    fn cost(&self) -> usize {
+        self.entries.len() + 1
+    }
+}
+
+/// Splits payments of a single asset into as few segments as possible.
+fn split_into_segments(
+    token_id: Option<TokenId>,
+    decimals: Option<u32>,
+    entries: Vec<DistributionEntry>,
+) -> Vec<Segment> {
+    let mut segments: Vec<Segment> = Vec::new();
+
+    for entry in entries {
+        let total = segments
+            .last()
+            .filter(|it| it.entries.len() < MAX_RECIPIENTS)
+            .and_then(|it| it.total.checked_add(entry.amount));
+
+        match (segments.last_mut(), total) {
+            (Some(segment), Some(total)) => {
+                segment.total = total;
+                segment.entries.push(entry);
+            }
+
+            _ => segments.push(Segment {
+                token_id,
+                decimals,
+                entries: Vec::from([entry]),
+                total: entry.amount,
+            }),
+        }
+    }
+
+    segments
+}
+
+#[cfg(test)]
+mod tests {
+    use assert_matches::assert_matches;
+
+    use super::Segment;
+    use crate::transaction::test_helpers::TEST_TX_ID;
+    use crate::{
+        AccountId,
+        DistributionEntry,
+        DistributionLedger,
+        Error,
+        Hbar,
+        TokenAmount,
+        TokenId,
+        TransferDistribution,
+        TransferTransaction,
+    };
+
+    const SENDER: AccountId = AccountId::new(0, 0, 1001);
+    const TOKEN_A: TokenId = TokenId::new(0, 0, 2001);
+    const TOKEN_B: TokenId = TokenId::new(0, 0, 2002);
+    const TOKEN_C: TokenId = TokenId::new(0, 0, 2003);
+
+    fn recipient(num: u64) -> AccountId {
+        AccountId::new(0, 0, 5000 + num)
+    }
+
+    /// Checks that `transactions` are within the network's limits and balanced.
+    fn assert_valid(transactions: &[TransferTransaction]) {
+        for transaction in transactions {
+            let hbar_transfers = transaction.get_hbar_transfers();
+            assert!(hbar_transfers.len() <= 10);
+            assert_eq!(hbar_transfers.values().copied().sum::<Hbar>(), Hbar::ZERO);
+
+            let token_transfers = transaction.get_token_transfers();
+            assert!(token_transfers.values().map(|it| it.len()).sum::<usize>() <= 10);
+
+            for transfers in token_transfers.values() {
+                assert_eq!(transfers.values().sum::<i64>(), 0);
+                assert!(transfers[&SENDER] < 0);
+            }
+        }
+    }
+
+    #[test]
+    fn plan_hbar() {
+        let mut distribution = TransferDistribution::new(SENDER);
+
+        for num in 0..20 {
+            distribution.hbar_payment(recipient(num), Hbar::from_tinybars(100));
+        }
+
+        let transactions = distribution.plan(&DistributionLedger::new()).unwrap();
+
+        assert_eq!(transactions.len(), 3);
+        assert_valid(&transactions);
+
+        assert_eq!(transactions[0].get_hbar_transfers()[&SENDER], Hbar::from_tinybars(-900));
+        assert_eq!(transactions[2].get_hbar_transfers()[&SENDER], Hbar::from_tinybars(-200));
+    }
+
+    #[test]
+    fn plan_tokens() {
+        let mut distribution = TransferDistribution::new(SENDER);
+
+        for num in 0..12 {
+            distribution.token_payment(TOKEN_A, recipient(num), 5);
+        }
+
+        for num in 0..3 {
+            distribution.token_payment(TOKEN_B, recipient(num), 5);
+        }
+
+        for num in 0..4 {
+            distribution.token_amount_payment(
+                TOKEN_C,
+                recipient(num),
+                TokenAmount::from_lowest_denomination(5, 2),
+            );
+        }
+
+        let transactions = distribution.plan(&DistributionLedger::new()).unwrap();
+
+        // 10 adjustments for the first 9 recipients of `TOKEN_A`, then 4 + 4 + 5 for the rest.
+        assert_eq!(transactions.len(), 3);
+        assert_valid(&transactions);
+
+        for transaction in &transactions {
+            if transaction.get_token_transfers().contains_key(&TOKEN_C) {
+                assert_eq!(transaction.get_token_decimals().get(&TOKEN_C), Some(&2));
+            }
+        }
+    }
+
+    #[test]
+    fn plan_hbar_and_tokens_share_transactions() {
+        let mut distribution = TransferDistribution::new(SENDER);
+
+        for num in 0..9 {
+            distribution.hbar_payment(recipient(num), Hbar::new(1));
+            distribution.token_payment(TOKEN_A, recipient(num), 1);
+        }
+
+        let transactions = distribution.plan(&DistributionLedger::new()).unwrap();
+
+        assert_eq!(transactions.len(), 1);
+        assert_valid(&transactions);
+    }
+
+    #[test]
+    fn plan_combines_payments() {
+        let mut distribution = TransferDistribution::new(SENDER);
+
+        distribution
+            .hbar_payment(recipient(1), Hbar::from_tinybars(100))
+            .hbar_payment(recipient(1), Hbar::from_tinybars(50))
+            .token_payment(TOKEN_A, recipient(1), 7);
+
+        let transactions = distribution.plan(&DistributionLedger::new()).unwrap();
+
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].get_hbar_transfers()[&recipient(1)], Hbar::from_tinybars(150));
+        assert_eq!(transactions[0].get_token_transfers()[&TOKEN_A][&recipient(1)], 7);
+    }
+
+    #[test]
+    fn plan_splits_overflowing_totals() {
+        let mut distribution = TransferDistribution::new(SENDER);
+
+        distribution.token_payment(TOKEN_A, recipient(1), i64::MAX).token_payment(
+            TOKEN_A,
+            recipient(2),
+            1,
+        );
+
+        let transactions = distribution.plan(&DistributionLedger::new()).unwrap();
+
+        assert_eq!(transactions.len(), 2);
+        assert_valid(&transactions);
+    }
+
+    #[test]
+    fn plan_skips_ledger() {
+        let mut distribution = TransferDistribution::new(SENDER);
+
+        for num in 0..12 {
+            distribution.hbar_payment(recipient(num), Hbar::from_tinybars(100));
+        }
+
+        let mut ledger = DistributionLedger::new();
+
+        let paid = [Segment {
+            token_id: None,
+            decimals: None,
+            entries: (0..9)
+                .map(|num| DistributionEntry {
+                    recipient: recipient(num),
+                    token_id: None,
+                    amount: 100,
+                    decimals: None,
+                })
+                .collect(),
+            total: 900,
+        }];
+
+        ledger.begin(TEST_TX_ID, &paid);
+        assert_eq!(ledger.pending().len(), 9);
+
+        // pending payments aren't retried either.
+        assert_eq!(distribution.plan(&ledger).unwrap()[0].get_hbar_transfers().len(), 4);
+
+        ledger.confirm(TEST_TX_ID);
+        assert!(ledger.pending().is_empty());
+        assert_eq!(ledger.paid().len(), 9);
+        assert!(ledger.is_paid(recipient(0), None));
+        assert!(!ledger.is_paid(recipient(0), Some(TOKEN_A)));
+        assert!(!ledger.is_paid(recipient(10), None));
+
+        let transactions = distribution.plan(&ledger).unwrap();
+        assert_eq!(transactions.len(), 1);
+        assert_eq!(transactions[0].get_hbar_transfers()[&SENDER], Hbar::from_tinybars(-300));
+    }
+
+    #[test]
+    fn clear_pending() {
+        let mut distribution = TransferDistribution::new(SENDER);
+        distribution.hbar_payment(recipient(1), Hbar::new(1));
+
+        let mut ledger = DistributionLedger::new();
+        let batches = distribution.batches(&ledger).unwrap();
+        ledger.begin(TEST_TX_ID, &batches[0]);
+
+        assert!(distribution.plan(&ledger).unwrap().is_empty());
+
+        ledger.clear_pending(TEST_TX_ID);
+
+        assert_eq!(distribution.plan(&ledger).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn plan_invalid() {
+        let plan =
+            |entry| TransferDistribution::new(SENDER).entry(entry).plan(&DistributionLedger::new());
+
+        let entry = DistributionEntry {
+            recipient: recipient(1),
+            token_id: None,
+            amount: 1,
+            decimals: None,
+        };
+
+        assert_matches!(
+            plan(DistributionEntry { amount: 0, ..entry }),
+            Err(Error::TransferDistribution { .. })
+        );
+        assert_matches!(
+            plan(DistributionEntry { recipient: SENDER, ..entry }),
+            Err(Error::TransferDistribution { .. })
+        );
+
+        let mut distribution = TransferDistribution::new(SENDER);
+        distribution
+            .token_amount_payment(
+                TOKEN_A,
+                recipient(1),
+                TokenAmount::from_lowest_denomination(1, 2),
+            )
+            .token_amount_payment(
+                TOKEN_A,
+                recipient(2),
+                TokenAmount::from_lowest_denomination(1, 3),
+            );
+
+        assert_matches!(
+            distribution.plan(&DistributionLedger::new()),
+            Err(Error::TransferDistribution { reason, .. }) if reason.contains("decimals")
+        );
+
+        let mut distribution = TransferDistribution::new(SENDER);
+        distribution.token_payment(TOKEN_A, recipient(1), i64::MAX).token_payment(
+            TOKEN_A,
+            recipient(1),
+            1,
+        );
+
+        assert_matches!(
+            distribution.plan(&DistributionLedger::new()),
+            Err(Error::TransferDistribution { .. })
+        );
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_in_flight_zero() {
+        TransferDistribution::new(SENDER).max_in_flight(0);
+    }
+}
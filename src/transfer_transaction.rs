@@ -18,7 +18,10 @@
  * ‍
  */
 
-use std::collections::HashMap;
+use std::collections::{
+    HashMap,
+    HashSet,
+};
 use std::ops::Not;
 
 use hedera_proto::services;
@@ -34,6 +37,8 @@ use crate::transaction::{
     ToTransactionDataProtobuf,
     TransactionData,
     TransactionExecute,
+    Violations,
+    MAX_BATCH_SIZE,
 };
 use crate::{
     AccountId,
@@ -41,7 +46,9 @@ use crate::{
     Error,
     Hbar,
     NftId,
+    Status,
     ToProtobuf,
+    TokenAmount,
     TokenId,
     TokenNftTransfer,
     Transaction,
@@ -61,6 +68,8 @@ use crate::{
 pub type TransferTransaction = Transaction<TransferTransactionData>;
 
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 pub struct TransferTransactionData {
     transfers: Vec<Transfer>,
@@ -68,12 +77,15 @@ pub struct TransferTransactionData {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 struct Transfer {
     /// The account involved in the transfer.
     account_id: AccountId,
 
     /// The value of the transfer.
+    #[cfg_attr(feature = "serde", serde(with = "crate::serde_impls::amount"))]
     amount: i64,
 
     /// If this is an approved transfer.
@@ -81,6 +93,8 @@ struct Transfer {
 }
 
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde_derive::Serialize, serde_derive::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "camelCase"))]
 #[cfg_attr(test, derive(Eq, PartialEq))]
 struct TokenTransfer {
     token_id: TokenId,
@@ -90,6 +104,10 @@ struct TokenTransfer {
     nft_transfers: Vec<TokenNftTransfer>,
 
     expected_decimals: Option<u32>,
+
+    /// Decimals previously passed for this token that were replaced by different `expected_decimals`.
+    #[cfg_attr(feature = "serde", serde(default, skip_serializing_if = "Vec::is_empty"))]
+    conflicting_decimals: Vec<u32>,
 }
 
 impl TransferTransaction {
@@ -134,12 +152,21 @@ impl TransferTransaction {
         let data = self.data_mut();
 
         if let Some(tt) = data.token_transfers.iter_mut().find(|tt| tt.token_id == token_id) {
+            // the last decimals passed win, a disagreement is only recorded for validation.
+            if let (Some(previous), Some(new)) = (tt.expected_decimals, expected_decimals) {
+                if previous != new {
+                    tt.conflicting_decimals.push(previous);
+                }
+            }
+
             tt.expected_decimals = expected_decimals;
+
             tt.transfers.push(transfer);
         } else {
             data.token_transfers.push(TokenTransfer {
                 token_id,
                 expected_decimals,
+                conflicting_decimals: Vec::new(),
                 nft_transfers: Vec::new(),
                 transfers: vec![transfer],
             });
@@ -201,6 +228,44 @@ impl TransferTransaction {
         self._token_transfer(token_id, account_id, amount, true, Some(expected_decimals))
     }
 
+    /// Add a non-approved token transfer of a [`TokenAmount`] to the transaction.
+    ///
+    /// This is equivalent to [`token_transfer_with_decimals`](Self::token_transfer_with_decimals),
+    /// using the decimals of `amount` as the expected decimals.
+    pub fn token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self._token_transfer(
+            token_id,
+            account_id,
+            amount.to_lowest_denomination(),
+            false,
+            Some(amount.decimals()),
+        )
+    }
+
+    /// Add an approved token transfer of a [`TokenAmount`] to the transaction.
+    ///
+    /// This is equivalent to [`approved_token_transfer_with_decimals`](Self::approved_token_transfer_with_decimals),
+    /// using the decimals of `amount` as the expected decimals.
+    pub fn approved_token_amount_transfer(
+        &mut self,
+        token_id: TokenId,
+        account_id: AccountId,
+        amount: TokenAmount,
+    ) -> &mut Self {
+        self._token_transfer(
+            token_id,
+            account_id,
+            amount.to_lowest_denomination(),
+            true,
+            Some(amount.decimals()),
+        )
+    }
+
     /// Returns all the token transfers associated associated with this transaction.
     pub fn get_token_transfers(&self) -> HashMap<TokenId, HashMap<AccountId, i64>> {
         use std::collections::hash_map::Entry;
@@ -255,6 +320,7 @@ impl TransferTransaction {
             data.token_transfers.push(TokenTransfer {
                 token_id,
                 expected_decimals: None,
+                conflicting_decimals: Vec::new(),
                 transfers: Vec::new(),
                 nft_transfers: vec![transfer],
             });
@@ -293,6 +359,26 @@ impl TransferTransaction {
     }
 }
 
+impl TransferTransactionData {
+    /// Returns every hbar (`None`) and fungible token transfer, in the order they were added.
+    pub(crate) fn fungible_transfers(
+        &self,
+    ) -> impl Iterator<Item = (Option<TokenId>, AccountId, i64)> + '_ {
+        let hbar = self.transfers.iter().map(|it| (None, it.account_id, it.amount));
+
+        let tokens = self.token_transfers.iter().flat_map(|list| {
+            list.transfers.iter().map(move |it| (Some(list.token_id), it.account_id, it.amount))
+        });
+
+        hbar.chain(tokens)
+    }
+
+    /// Returns every NFT transfer, in the order they were added.
+    pub(crate) fn nft_transfers(&self) -> impl Iterator<Item = &TokenNftTransfer> + '_ {
+        self.token_transfers.iter().flat_map(|it| &it.nft_transfers)
+    }
+}
+
 impl TransactionExecute for TransferTransactionData {
     // noinspection DuplicatedCode
     fn execute(
@@ -304,7 +390,104 @@ impl TransactionExecute for TransferTransactionData {
     }
 }
 
-impl TransactionData for TransferTransactionData {}
+impl TransactionData for TransferTransactionData {
+    fn validate_data(&self, violations: &mut Violations) {
+        validate_transfers(violations, "hbar transfers", &self.transfers);
+
+        // summed as `i128` so that large amounts can't overflow.
+        let sum: i128 = self.transfers.iter().map(|it| i128::from(it.amount)).sum();
+        if sum != 0 {
+            violations.push(
+                Status::InvalidAccountAmounts,
+                format!("hbar transfers sum to {sum} tinybars instead of zero"),
+            );
+        }
+
+        if self.transfers.len() > MAX_TRANSFERS {
+            violations.push(
+                Status::TransferListSizeLimitExceeded,
+                format!(
+                    "{} hbar transfers given, the maximum is {MAX_TRANSFERS}",
+                    self.transfers.len()
+                ),
+            );
+        }
+
+        for tt in &self.token_transfers {
+            let token_id = tt.token_id;
+
+            let sum: i128 = tt.transfers.iter().map(|it| i128::from(it.amount)).sum();
+            if sum != 0 {
+                violations.push(
+                    Status::TransfersNotZeroSumForToken,
+                    format!("transfers of token {token_id} sum to {sum} instead of zero"),
+                );
+            }
+
+            validate_transfers(
+                violations,
+                &format!("transfers of token {token_id}"),
+                &tt.transfers,
+            );
+
+            if let (Some(&previous), Some(expected)) =
+                (tt.conflicting_decimals.first(), tt.expected_decimals)
+            {
+                violations.push(
+                    Status::UnexpectedTokenDecimals,
+                    format!(
+                        "token {token_id} is expected to have both {previous} and {expected} decimals"
+                    ),
+                );
+            }
+
+            for nft in &tt.nft_transfers {
+                if nft.sender == nft.receiver {
+                    violations.push(
+                        Status::AccountRepeatedInAccountAmounts,
+                        format!(
+                            "NFT {token_id}/{} is transferred from {} to itself",
+                            nft.serial, nft.sender
+                        ),
+                    );
+                }
+            }
+        }
+
+        let token_transfers: usize = self.token_transfers.iter().map(|it| it.transfers.len()).sum();
+        if token_transfers > MAX_TRANSFERS {
+            violations.push(
+                Status::TokenTransferListSizeLimitExceeded,
+                format!("{token_transfers} token transfers given, the maximum is {MAX_TRANSFERS}"),
+            );
+        }
+
+        let nft_transfers: usize =
+            self.token_transfers.iter().map(|it| it.nft_transfers.len()).sum();
+        if nft_transfers > MAX_BATCH_SIZE {
+            violations.push(
+                Status::BatchSizeLimitExceeded,
+                format!("{nft_transfers} NFT transfers given, the maximum is {MAX_BATCH_SIZE}"),
+            );
+        }
+    }
+}
+
+/// The maximum number of hbar (or fungible token) adjustments in a single transfer.
+pub(crate) const MAX_TRANSFERS: usize = 10;
+
+fn validate_transfers(violations: &mut Violations, what: &str, transfers: &[Transfer]) {
+    let mut seen = HashSet::with_capacity(transfers.len());
+
+    for transfer in transfers {
+        if !seen.insert(transfer.account_id) {
+            violations.push(
+                Status::AccountRepeatedInAccountAmounts,
+                format!("{} appears more than once in {what}", transfer.account_id),
+            );
+        }
+    }
+}
 
 impl ValidateChecksums for TransferTransactionData {
     fn validate_checksums(&self, ledger_id: &RefLedgerId) -> Result<(), Error> {
@@ -360,6 +543,7 @@ impl FromProtobuf<services::TokenTransferList> for TokenTransfer {
                 .map(|pb| TokenNftTransfer::from_protobuf(pb, token_id))
                 .collect::<Result<Vec<_>, _>>()?,
             expected_decimals: pb.expected_decimals,
+            conflicting_decimals: Vec::new(),
         })
     }
 }
@@ -445,6 +629,8 @@ impl ToProtobuf for TransferTransactionData {
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use expect_test::expect;
 
     use crate::transaction::test_helpers::{
@@ -455,6 +641,7 @@ mod tests {
         AccountId,
         AnyTransaction,
         Hbar,
+        TokenAmount,
         TokenId,
         TransferTransaction,
     };
@@ -860,5 +1047,32 @@ mod tests {
 
         tx.token_transfer_with_decimals(TOKEN, AccountId::new(0, 0, 7), -100, 5);
         assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&5));
+
+        // the last decimals win, even if they conflict.
+        tx.token_transfer_with_decimals(TOKEN, AccountId::new(0, 0, 9), 0, 3);
+        assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&3));
+    }
+
+    #[test]
+    fn token_amount_transfer() {
+        let mut tx = TransferTransaction::new();
+        const TOKEN: TokenId = TokenId::new(0, 0, 5);
+
+        tx.token_amount_transfer(
+            TOKEN,
+            AccountId::new(0, 0, 8),
+            TokenAmount::parse("1.5", 2).unwrap(),
+        )
+        .approved_token_amount_transfer(
+            TOKEN,
+            AccountId::new(0, 0, 7),
+            TokenAmount::parse("-1.5", 2).unwrap(),
+        );
+
+        assert_eq!(tx.get_token_decimals().get(&TOKEN), Some(&2));
+        assert_eq!(
+            tx.get_token_transfers()[&TOKEN],
+            HashMap::from([(AccountId::new(0, 0, 8), 150), (AccountId::new(0, 0, 7), -150)])
+        );
     }
 }